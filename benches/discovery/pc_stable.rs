@@ -142,4 +142,44 @@ pub mod categorical {
             });
         }
     }
+
+    pub mod precompute_marginals {
+        use causal_hub::prelude::*;
+        use criterion::{BenchmarkId, Criterion};
+        use rand::SeedableRng;
+        use rand_xoshiro::Xoshiro256PlusPlus;
+
+        // Set ChiSquared significance level
+        const ALPHA: f64 = 0.05;
+
+        // PC-Stable `alarm` benchmark, with and without precomputed marginal tests
+        pub fn alarm(c: &mut Criterion) {
+            // Initialize benchmark group.
+            let mut group =
+                c.benchmark_group("discovery::pc_stable::categorical::precompute_marginals::alarm");
+
+            // Initialize random number generator.
+            let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+            // Load reference model.
+            let model: CategoricalBN = BIF::read("./tests/assets/bif/alarm.bif").unwrap().into();
+            // Sample data set from reference model.
+            let d = model.sample(&mut rng, 1_000);
+
+            // Create ChiSquared conditional independence test
+            let test = ChiSquared::new(&d).with_significance_level(ALPHA);
+
+            // Repeat with and without precomputed marginal tests.
+            for precompute_marginals in [false, true] {
+                // Create PC-Stable functor
+                let pcs = PCStable::new(&test).with_precompute_marginals(precompute_marginals);
+
+                // Benchmark
+                group.bench_with_input(
+                    BenchmarkId::from_parameter(precompute_marginals),
+                    &precompute_marginals,
+                    |b, _| b.iter(|| pcs.call().meek_procedure_until_3()),
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,59 @@
+use causal_hub::prelude::*;
+use ndarray::prelude::*;
+use rand::SeedableRng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+// Generate the pattern of a large random DAG.
+fn large_pattern() -> PDGraph {
+    // Initialize random number generator.
+    let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+    // Generate a large random DAG.
+    let g = DiGraph::random_dag((0..500).map(|x| x.to_string()), 0.01, Some(4), &mut rng);
+
+    // Initialize the marked matrix of the pattern.
+    let mut m = Array2::<i8>::zeros((g.order(), g.order()));
+    // For each edge of the DAG ...
+    for (x, y) in E!(g) {
+        // ... check if it is part of a v-structure ...
+        let is_v = Pa!(g, y).any(|w| w != x && !g.is_adjacent_by_index(w, x));
+        // ... and mark it accordingly.
+        m[[x, y]] = if is_v { 1 } else { 2 };
+        m[[y, x]] = if is_v { -1 } else { 2 };
+    }
+
+    // Construct the pattern.
+    PDGraph::from_marked_matrix(L!(g), m)
+}
+
+pub mod meek_procedure_until_3 {
+    use causal_hub::prelude::*;
+    use criterion::Criterion;
+
+    // Random CPDAG `large` benchmark
+    pub fn large(c: &mut Criterion) {
+        // Construct the pattern.
+        let h = super::large_pattern();
+
+        // Benchmark
+        c.bench_function("graphs::meek_rules::meek_procedure_until_3::large", |b| {
+            b.iter(|| h.clone().meek_procedure_until_3())
+        });
+    }
+}
+
+pub mod par_meek_procedure_until_3 {
+    use causal_hub::prelude::*;
+    use criterion::Criterion;
+
+    // Random CPDAG `large` benchmark
+    pub fn large(c: &mut Criterion) {
+        // Construct the pattern.
+        let h = super::large_pattern();
+
+        // Benchmark
+        c.bench_function(
+            "graphs::meek_rules::par_meek_procedure_until_3::large",
+            |b| b.iter(|| h.clone().par_meek_procedure_until_3()),
+        );
+    }
+}
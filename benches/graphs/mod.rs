@@ -0,0 +1 @@
+pub mod meek_rules;
@@ -2,6 +2,7 @@ use criterion::{criterion_group, criterion_main};
 
 mod data;
 mod discovery;
+mod graphs;
 mod models;
 mod stats;
 
@@ -19,16 +20,22 @@ criterion_group!(
     discovery::pc_stable::categorical::par_call::asia,
     discovery::pc_stable::categorical::par_call::cancer,
     discovery::pc_stable::categorical::par_call::survey,
+    discovery::pc_stable::categorical::precompute_marginals::alarm,
     discovery::hill_climbing::categorical::call::asia,
     discovery::hill_climbing::categorical::call::alarm,
     discovery::hill_climbing::categorical::par_call::asia,
     discovery::hill_climbing::categorical::par_call::alarm,
     discovery::hill_climbing::zinb::call::dummy,
+    // Graphs benchmarks.
+    graphs::meek_rules::meek_procedure_until_3::large,
+    graphs::meek_rules::par_meek_procedure_until_3::large,
     // Models benchmarks.
     models::bayesian_network::sample::asia,
     models::bayesian_network::sample::alarm,
     models::bayesian_network::par_sample::asia,
     models::bayesian_network::par_sample::alarm,
+    models::bayesian_network::predict::alarm,
+    models::bayesian_network::par_predict::alarm,
     // Statistics benchmarks.
     stats::log_likelihood::marginal::asia,
     stats::log_likelihood::marginal::alarm,
@@ -36,6 +43,7 @@ criterion_group!(
     stats::akaike_information_criterion::call::alarm,
     stats::bayesian_information_criterion::call::asia,
     stats::bayesian_information_criterion::call::alarm,
+    stats::precision_matrix::from::ecoli70,
 );
 
 criterion_main!(benches);
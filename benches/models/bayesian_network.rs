@@ -89,3 +89,91 @@ pub mod par_sample {
         driver(c, "alarm");
     }
 }
+
+pub mod predict {
+
+    use causal_hub::prelude::*;
+    use criterion::{BenchmarkId, Criterion, Throughput};
+    use rand::SeedableRng;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+
+    fn driver(c: &mut Criterion, model: &str, target: &str) {
+        // Initialize benchmark group.
+        let mut group =
+            c.benchmark_group(format!("models::bayesian_network::predict::{model}").as_str());
+
+        // Initialize random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        // Load reference model.
+        let model: CategoricalBN = BIF::read(format!("./tests/assets/bif/{model}.bif").as_str())
+            .unwrap()
+            .into();
+
+        // Repeat for different sample sizes.
+        for sample_size in [1_000, 10_000, 100_000].iter() {
+            // Sample data set from reference model.
+            let data_set = model.par_sample(&mut rng, *sample_size);
+            // Set input dimension.
+            group.throughput(Throughput::Elements(*sample_size as u64));
+            // Benchmark function.
+            group.bench_with_input(
+                BenchmarkId::from_parameter(sample_size),
+                &data_set,
+                |b, data_set| {
+                    b.iter(|| {
+                        // Predict target from the other variables.
+                        let _ = model.predict(data_set, target);
+                    })
+                },
+            );
+        }
+    }
+
+    pub fn alarm(c: &mut Criterion) {
+        driver(c, "alarm", "LVFAILURE");
+    }
+}
+
+pub mod par_predict {
+
+    use causal_hub::prelude::*;
+    use criterion::{BenchmarkId, Criterion, Throughput};
+    use rand::SeedableRng;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+
+    fn driver(c: &mut Criterion, model: &str, target: &str) {
+        // Initialize benchmark group.
+        let mut group =
+            c.benchmark_group(format!("models::bayesian_network::par_predict::{model}").as_str());
+
+        // Initialize random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        // Load reference model.
+        let model: CategoricalBN = BIF::read(format!("./tests/assets/bif/{model}.bif").as_str())
+            .unwrap()
+            .into();
+
+        // Repeat for different sample sizes.
+        for sample_size in [1_000, 10_000, 100_000].iter() {
+            // Sample data set from reference model.
+            let data_set = model.par_sample(&mut rng, *sample_size);
+            // Set input dimension.
+            group.throughput(Throughput::Elements(*sample_size as u64));
+            // Benchmark function.
+            group.bench_with_input(
+                BenchmarkId::from_parameter(sample_size),
+                &data_set,
+                |b, data_set| {
+                    b.iter(|| {
+                        // Predict target from the other variables.
+                        let _ = model.par_predict(data_set, target);
+                    })
+                },
+            );
+        }
+    }
+
+    pub fn alarm(c: &mut Criterion) {
+        driver(c, "alarm", "LVFAILURE");
+    }
+}
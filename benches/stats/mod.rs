@@ -1,3 +1,4 @@
 pub mod akaike_information_criterion;
 pub mod bayesian_information_criterion;
 pub mod log_likelihood;
+pub mod precision_matrix;
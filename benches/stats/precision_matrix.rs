@@ -0,0 +1,22 @@
+pub mod from {
+    use causal_hub::{polars::prelude::*, prelude::*};
+    use criterion::{black_box, Criterion};
+
+    // Precision matrix `ecoli70` benchmark
+    pub fn ecoli70(c: &mut Criterion) {
+        // Load data set.
+        let d = CsvReader::from_path("./tests/assets/ecoli70.csv")
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = GaussianDataMatrix::from(d);
+
+        // Compute covariance matrix.
+        let sigma = CovarianceMatrix::from(&d);
+
+        // Benchmark
+        c.bench_function("stats::precision_matrix::from::ecoli70", |b| {
+            b.iter(|| PrecisionMatrix::from(black_box(sigma.clone())))
+        });
+    }
+}
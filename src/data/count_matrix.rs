@@ -10,22 +10,48 @@ pub struct MarginalCountMatrix {
 }
 
 impl MarginalCountMatrix {
+    #[inline]
+    pub(crate) fn eval(shape: (usize,), d: ArrayView2<u8>, x: usize) -> Array1<usize> {
+        // Allocate count matrix.
+        let mut n = Array1::zeros(shape);
+        // Fill count matrix.
+        for row in d.rows() {
+            // Increment at given index.
+            n[row[x] as usize] += 1;
+        }
+
+        n
+    }
+
     /// Build new count matrix with given data matrix and indices.
     #[inline]
     pub fn new(d: &CategoricalDataMatrix, x: usize) -> Self {
         // Get cardinalities.
         let cards = d.cardinality();
+        // Set count matrix shape.
+        let shape = (cards[x] as usize,);
+
+        // Count the given observations.
+        let n = Self::eval(shape, d.data().view(), x);
 
+        Self { n }
+    }
+
+    /// Build new count matrix with given data matrix and indices in parallel.
+    #[inline]
+    pub fn par_new(d: &CategoricalDataMatrix, x: usize) -> Self {
+        // Get cardinalities.
+        let cards = d.cardinality();
         // Set count matrix shape.
         let shape = (cards[x] as usize,);
 
-        // Allocate count matrix.
-        let mut n = Array1::zeros(shape);
-        // Fill count matrix.
-        for row in d.data().rows() {
-            // Increment at given index.
-            n[row[x] as usize] += 1;
-        }
+        // Count the given observations in parallel.
+        let n = d
+            .data()
+            .axis_chunks_iter(Axis(0), axis_chunks_size(d.data()))
+            .into_par_iter()
+            .map(|d| Self::eval(shape, d, x))
+            .reduce(|| Array1::zeros(shape), |acc, x| acc + x);
 
         Self { n }
     }
@@ -61,13 +87,25 @@ impl ConditionalCountMatrix {
         // Allocate count matrix.
         let mut n = Array2::zeros(shape);
         // Fill count matrix.
-        for row in d.rows() {
-            // Get multi index.
-            let row_z = z.iter().map(|&z| row[z] as usize);
-            // Ravel multi index.
-            let row_z = rmi.call(row_z);
-            // Increment at given index.
-            n[[row_z, row[x] as usize]] += 1;
+        match z {
+            // If there is only one conditioning variable, then its value is the raveled index.
+            &[z] => {
+                for row in d.rows() {
+                    // Increment at given index.
+                    n[[row[z] as usize, row[x] as usize]] += 1;
+                }
+            }
+            // Otherwise, ravel the multi index.
+            _ => {
+                for row in d.rows() {
+                    // Get multi index.
+                    let row_z = z.iter().map(|&z| row[z] as usize);
+                    // Ravel multi index.
+                    let row_z = rmi.call(row_z);
+                    // Increment at given index.
+                    n[[row_z, row[x] as usize]] += 1;
+                }
+            }
         }
 
         n
@@ -1,5 +1,6 @@
 use std::{
     collections::{btree_set, BTreeMap, BTreeSet},
+    f64::consts::PI,
     iter::Map,
     ops::Deref,
 };
@@ -7,12 +8,49 @@ use std::{
 use is_sorted::IsSorted;
 use itertools::Itertools;
 use ndarray::prelude::*;
+use ndarray_rand::rand_distr::Normal;
 use ndarray_stats::QuantileExt;
 use polars::prelude::*;
+use rand::{distributions::Distribution, Rng};
 use serde::{Deserialize, Serialize};
+use statrs::distribution::{ContinuousCDF, Normal as StandardNormal};
 
 use super::DataSet;
-use crate::types::{FxIndexMap, FxIndexSet};
+use crate::{
+    stats::{CovarianceMatrix, PartialCorrelationMatrix, PrecisionMatrix},
+    types::{FxIndexMap, FxIndexSet},
+};
+
+/// Variable type of a data matrix column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VariableType {
+    /// Categorical variable, see `CategoricalDataMatrix`.
+    Categorical,
+    /// Continuous variable, see `GaussianDataMatrix`.
+    Continuous,
+}
+
+/// Infer the variables types of a data frame.
+///
+/// Float columns are inferred as continuous, any other column as categorical.
+/// The returned map is sorted by variables labels, as in data matrices.
+pub fn infer_types(data_frame: &DataFrame) -> FxIndexMap<String, VariableType> {
+    data_frame
+        .iter()
+        // Map each column to its variable type.
+        .map(|s| {
+            (
+                s.name().to_owned(),
+                match s.dtype().is_float() {
+                    true => VariableType::Continuous,
+                    false => VariableType::Categorical,
+                },
+            )
+        })
+        // Sort by variables labels.
+        .sorted_by(|(x, _), (y, _)| x.cmp(y))
+        .collect()
+}
 
 /* Implement CategoricalDataMatrix */
 
@@ -25,7 +63,86 @@ pub struct CategoricalDataMatrix {
 }
 
 impl CategoricalDataMatrix {
+    /// Construct a new categorical data matrix given the encoded values and the variables states.
+    ///
+    /// Each column of `data` encodes the states of the associated variable by
+    /// their position in the given states, which are then sorted, as the columns
+    /// are sorted w.r.t. the variables labels.
+    ///
+    /// # Panics
+    ///
+    /// The number of columns must match the number of variables, labels must be unique
+    /// and each value must encode a given state of the associated variable.
+    pub fn from_ndarray<I, J, K, V>(data: Array2<u8>, states: I) -> Self
+    where
+        I: IntoIterator<Item = (K, J)>,
+        J: IntoIterator<Item = V>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        // Collect states.
+        let states = states
+            .into_iter()
+            .map(|(x, ys)| {
+                (
+                    x.into(),
+                    ys.into_iter().map_into().collect::<FxIndexSet<String>>(),
+                )
+            })
+            .collect_vec();
+
+        // Assert columns and variables are consistent.
+        assert_eq!(
+            data.ncols(),
+            states.len(),
+            "Inconsistent number of columns and labels"
+        );
+        // Assert labels are unique.
+        assert!(
+            states.iter().map(|(x, _)| x).all_unique(),
+            "Labels must be unique"
+        );
+
+        // Sort columns w.r.t. the variables labels.
+        let columns = (0..states.len())
+            .sorted_by_key(|&i| &states[i].0)
+            .collect_vec();
+        // Allocate the new data matrix.
+        let mut sorted_data = Array2::zeros((data.nrows(), data.ncols()));
+        // For each column in sorted order ...
+        for (&i, mut column) in columns.iter().zip(sorted_data.columns_mut()) {
+            // Get the associated states.
+            let (x, ys) = &states[i];
+            // Compute the position of each state w.r.t. the sorted states.
+            let mut ranks = vec![0; ys.len()];
+            (0..ys.len())
+                .sorted_by_key(|&j| &ys[j])
+                .enumerate()
+                .for_each(|(k, j)| ranks[j] = k as u8);
+            // Align values encoding w.r.t. the sorted states.
+            column.zip_mut_with(&data.column(i), |y, &x_y| {
+                *y = *ranks
+                    .get(x_y as usize)
+                    .unwrap_or_else(|| panic!("Invalid value `{x_y}` for variable `{x}`"));
+            });
+        }
+
+        // Sort the states.
+        let states = columns
+            .into_iter()
+            .map(|i| {
+                let (x, ys) = &states[i];
+                (x.clone(), ys.iter().cloned().sorted().collect())
+            })
+            .collect();
+
+        Self::with_data_labels(sorted_data, states)
+    }
+
     /// Gets the vector of variables cardinalities.
+    ///
+    /// The cardinality of each variable is the number of its declared states,
+    /// which may be greater than the number of observed states (see `with_states`).
     #[inline]
     pub fn cardinality(&self) -> &Vec<u8> {
         &self.cardinality
@@ -37,6 +154,42 @@ impl CategoricalDataMatrix {
         &self.states
     }
 
+    /// Iterate over the rows of the data matrix as views of the encoded values, without copying.
+    ///
+    /// The values of each row are sorted w.r.t. the variables labels, and each one
+    /// encodes the state of the associated variable by its position in `states`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    /// use ndarray::prelude::*;
+    ///
+    /// // Construct a data matrix.
+    /// let d = CategoricalDataMatrix::from_ndarray(
+    ///     array![[0, 1], [1, 0]],
+    ///     [("X", vec!["A", "B"]), ("Y", vec!["a", "b"])],
+    /// );
+    ///
+    /// // Map the encoded values of each row to the associated states.
+    /// let rows: Vec<Vec<&str>> = d
+    ///     .rows()
+    ///     .map(|row| {
+    ///         row.iter()
+    ///             .zip(d.states().values())
+    ///             .map(|(&y, ys)| ys[y as usize].as_str())
+    ///             .collect()
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(rows, [["A", "b"], ["B", "a"]]);
+    /// ```
+    ///
+    #[inline]
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = ArrayView1<'_, u8>> {
+        self.data.rows().into_iter()
+    }
+
     /// Set states of the categorical data matrix.
     ///
     /// # Panics
@@ -87,6 +240,156 @@ impl CategoricalDataMatrix {
     }
 }
 
+impl CategoricalDataMatrix {
+    /// Recode the states of a variable given a state-to-state mapping.
+    ///
+    /// States not in the mapping are left unchanged, while states mapped to the
+    /// same label are merged. The states of the variable are then re-sorted.
+    ///
+    /// # Panics
+    ///
+    /// The variable label must be in the data matrix.
+    pub fn recode(&self, x: &str, mapping: &FxIndexMap<String, String>) -> Self {
+        // Get the variable index and states.
+        let (i, _, ys) = self
+            .states
+            .get_full(x)
+            .unwrap_or_else(|| panic!("No variable with label `{x}`"));
+        // Map each state to its new label.
+        let zs = ys.iter().map(|y| mapping.get(y).unwrap_or(y)).collect_vec();
+        // Compute the new sorted states.
+        let states: FxIndexSet<String> = zs.iter().copied().sorted().dedup().cloned().collect();
+        // Map each state index to its new index.
+        let indices = zs
+            .into_iter()
+            .map(|z| states.get_index_of(z).unwrap() as u8)
+            .collect_vec();
+
+        // Clone the data matrix.
+        let mut data = self.clone();
+        // Align values encoding w.r.t. new states.
+        data.data
+            .column_mut(i)
+            .mapv_inplace(|y| indices[y as usize]);
+        // Set new cardinality.
+        data.cardinality[i] = states.len() as u8;
+        // Set new states.
+        data.states[i] = states;
+
+        data
+    }
+
+    /// Concatenate two data matrices by rows.
+    ///
+    /// The states of each variable are the union of the states of both data matrices,
+    /// and the values are encoded w.r.t. such union.
+    ///
+    /// # Panics
+    ///
+    /// The data matrices must have the same variables labels.
+    pub fn vconcat(&self, other: &Self) -> Self {
+        // Assert labels are identical.
+        assert!(
+            self.labels_iter().eq(other.labels_iter()),
+            "Data matrices must have the same labels"
+        );
+
+        // Compute the union of the states.
+        let states = self
+            .states
+            .iter()
+            .zip(other.states.values())
+            .map(|((x, a), b)| (x.clone(), a.union(b).cloned().collect_vec()))
+            .collect_vec();
+        // Align values encoding w.r.t. the union of the states.
+        let a = self.clone().with_states(states.clone());
+        let b = other.clone().with_states(states);
+        // Concatenate the values by rows.
+        let data = ndarray::concatenate(Axis(0), &[a.data.view(), b.data.view()])
+            .expect("Failed to concatenate data matrices");
+
+        Self::with_data_labels(data, a.states)
+    }
+
+    /// Concatenate two data matrices by columns.
+    ///
+    /// The columns are sorted w.r.t. the variables labels.
+    ///
+    /// # Panics
+    ///
+    /// The data matrices must have the same sample size and disjoint variables labels.
+    pub fn hconcat(&self, other: &Self) -> Self {
+        // Assert sample sizes are equal.
+        assert_eq!(
+            self.sample_size(),
+            other.sample_size(),
+            "Data matrices must have the same sample size"
+        );
+        // Assert labels are disjoint.
+        assert!(
+            self.states.keys().all(|x| !other.states.contains_key(x)),
+            "Data matrices must have disjoint labels"
+        );
+
+        // Concatenate the values by columns.
+        let data = ndarray::concatenate(Axis(1), &[self.data.view(), other.data.view()])
+            .expect("Failed to concatenate data matrices");
+        // Collect the states.
+        let states = self.states.iter().chain(&other.states).collect_vec();
+        // Sort columns w.r.t. the variables labels.
+        let columns = (0..states.len())
+            .sorted_by_key(|&i| states[i].0)
+            .collect_vec();
+        let data = data.select(Axis(1), &columns);
+        // Sort the states.
+        let states = columns
+            .into_iter()
+            .map(|i| (states[i].0.clone(), states[i].1.clone()))
+            .collect();
+
+        Self::with_data_labels(data, states)
+    }
+
+    /// Corrupt the data matrix by reassigning a fraction of the values to other states.
+    ///
+    /// Each value is corrupted with probability `rate`, by replacing it with
+    /// another state of the same variable, drawn uniformly at random.
+    /// The states of the variables are preserved, hence no new state is added.
+    ///
+    /// # Panics
+    ///
+    /// The corruption rate must be in $[0, 1]$.
+    pub fn corrupt<R: Rng>(&self, rng: &mut R, rate: f64) -> Self {
+        // Assert corruption rate is in [0, 1].
+        assert!(
+            (0. ..=1.).contains(&rate),
+            "Corruption rate must be in [0, 1]"
+        );
+
+        // Clone the data matrix.
+        let mut data = self.clone();
+        // For each column ...
+        for (mut column, &k) in data.data.columns_mut().into_iter().zip(&self.cardinality) {
+            // Skip variables with a single state.
+            if k < 2 {
+                continue;
+            }
+            // For each value in the column ...
+            column.iter_mut().for_each(|x| {
+                // Corrupt the value with given probability ...
+                if rng.gen_bool(rate) {
+                    // ... drawing a state from the other ones.
+                    let y = rng.gen_range(0..(k - 1));
+                    // Skip the current state.
+                    *x = if y < *x { y } else { y + 1 };
+                }
+            });
+        }
+
+        data
+    }
+}
+
 impl From<DataFrame> for CategoricalDataMatrix {
     fn from(data_frame: DataFrame) -> Self {
         // Check for missing values.
@@ -99,9 +402,14 @@ impl From<DataFrame> for CategoricalDataMatrix {
         );
 
         // Check for wrong data type.
+        let columns = data_frame
+            .iter()
+            .filter(|s| s.dtype().is_float())
+            .map(|s| s.name())
+            .collect_vec();
         assert!(
-            data_frame.iter().all(|s| !s.dtype().is_float()),
-            "DataSet must contain only categorical types"
+            columns.is_empty(),
+            "DataSet must contain only categorical types, found float columns: {columns:?}"
         );
 
         // Cast to categorical datatype.
@@ -289,6 +597,104 @@ impl DataSet for CategoricalDataMatrix {
 
 /* Implement GaussianDataMatrix */
 
+/// Discretization method of a continuous data matrix, with parameters given
+/// for each variable, in the same order of the variables labels.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Discretization {
+    /// Equal-width bins, given the number of bins of each variable.
+    EqualWidth(Vec<usize>),
+    /// Equal-frequency (quantile) bins, given the number of bins of each variable.
+    EqualFrequency(Vec<usize>),
+    /// Fixed bins, given the sorted inner breakpoints of each variable.
+    Breakpoints(Vec<Vec<f64>>),
+}
+
+impl Discretization {
+    /// Compute the sorted inner breakpoints of each variable of a given data matrix.
+    ///
+    /// The returned breakpoints can be used with `Discretization::Breakpoints`
+    /// to discretize new data consistently.
+    ///
+    /// # Panics
+    ///
+    /// The number of parameters must match the number of variables, the number of bins
+    /// must be strictly positive and at most 256, i.e. the number of states representable
+    /// by a categorical variable, and the breakpoints must be finite and strictly increasing.
+    pub fn breakpoints(&self, data: &GaussianDataMatrix) -> Vec<Vec<f64>> {
+        // Get the number of parameters.
+        let n = match self {
+            Self::EqualWidth(k) | Self::EqualFrequency(k) => {
+                // Assert the number of bins is strictly positive.
+                assert!(
+                    k.iter().all(|&k| k > 0),
+                    "Number of bins must be strictly positive"
+                );
+                // Assert the number of bins is representable.
+                assert!(
+                    k.iter().all(|&k| k <= u8::MAX as usize + 1),
+                    "Number of bins must be at most 256"
+                );
+
+                k.len()
+            }
+            Self::Breakpoints(b) => {
+                // Assert the breakpoints are finite and strictly increasing.
+                assert!(
+                    b.iter().all(|b| b.iter().all(|b| b.is_finite())
+                        && b.iter().tuple_windows().all(|(a, b)| a < b)),
+                    "Breakpoints must be finite and strictly increasing"
+                );
+                // Assert the number of bins is representable.
+                assert!(
+                    b.iter().all(|b| b.len() < u8::MAX as usize + 1),
+                    "Number of bins must be at most 256"
+                );
+
+                b.len()
+            }
+        };
+        // Assert the number of parameters matches the number of variables.
+        assert_eq!(
+            n,
+            data.data().ncols(),
+            "Inconsistent number of parameters and variables"
+        );
+
+        match self {
+            Self::EqualWidth(k) => data
+                .data()
+                .columns()
+                .into_iter()
+                .zip(k)
+                .map(|(x, &k)| {
+                    // Compute the range of the variable.
+                    let (min, max) = (*x.min_skipnan(), *x.max_skipnan());
+                    // Compute the bins width.
+                    let w = (max - min) / k as f64;
+                    // Compute the inner breakpoints.
+                    (1..k).map(|i| min + i as f64 * w).dedup().collect()
+                })
+                .collect(),
+            Self::EqualFrequency(k) => data
+                .data()
+                .columns()
+                .into_iter()
+                .zip(k)
+                .map(|(x, &k)| {
+                    // Sort the values of the variable.
+                    let x = x.iter().copied().sorted_by(f64::total_cmp).collect_vec();
+                    // Compute the inner breakpoints as the empirical quantiles.
+                    (1..k)
+                        .map(|i| x[((i * x.len()).div_ceil(k)).saturating_sub(1)])
+                        .dedup()
+                        .collect()
+                })
+                .collect(),
+            Self::Breakpoints(b) => b.clone(),
+        }
+    }
+}
+
 /// Data matrix for continuous data.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct GaussianDataMatrix {
@@ -296,6 +702,255 @@ pub struct GaussianDataMatrix {
     labels: BTreeSet<String>,
 }
 
+impl GaussianDataMatrix {
+    /// Construct a new continuous data matrix given the values and the variables labels.
+    ///
+    /// The columns are sorted w.r.t. the variables labels.
+    ///
+    /// # Panics
+    ///
+    /// The number of columns must match the number of variables and labels must be unique.
+    pub fn from_ndarray<I, V>(data: Array2<f64>, labels: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        // Collect labels.
+        let labels: Vec<String> = labels.into_iter().map_into().collect();
+
+        // Assert columns and variables are consistent.
+        assert_eq!(
+            data.ncols(),
+            labels.len(),
+            "Inconsistent number of columns and labels"
+        );
+        // Assert labels are unique.
+        assert!(labels.iter().all_unique(), "Labels must be unique");
+
+        // Sort columns w.r.t. the variables labels.
+        let columns = (0..labels.len())
+            .sorted_by_key(|&i| &labels[i])
+            .collect_vec();
+        let data = data.select(Axis(1), &columns);
+
+        Self::with_data_labels(data, labels.into_iter().collect())
+    }
+
+    /// Iterate over the rows of the data matrix as views of the values, without copying.
+    ///
+    /// The values of each row are sorted w.r.t. the variables labels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    /// use ndarray::prelude::*;
+    ///
+    /// // Construct a data matrix.
+    /// let d = GaussianDataMatrix::from_ndarray(array![[1., 2.], [3., 4.]], ["X", "Y"]);
+    ///
+    /// // Compute the sum of each row.
+    /// let sums: Vec<f64> = d.rows().map(|row| row.sum()).collect();
+    ///
+    /// assert_eq!(sums, [3., 7.]);
+    /// ```
+    ///
+    #[inline]
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = ArrayView1<'_, f64>> {
+        self.data.rows().into_iter()
+    }
+
+    /// Compute the precision matrix $\Omega = \Sigma^{-1}$, i.e. the inverse of the (sample) covariance matrix.
+    ///
+    /// The rows and columns are sorted w.r.t. the variables labels.
+    ///
+    /// # Panics
+    ///
+    /// The covariance matrix must be positive definite, e.g. the sample size must be greater
+    /// than the number of variables. Otherwise, estimate it with a shrinkage estimator, e.g.
+    /// [`CovarianceEstimator::LedoitWolf`](crate::stats::CovarianceEstimator::LedoitWolf),
+    /// and convert it with [`PrecisionMatrix::from`].
+    ///
+    #[inline]
+    pub fn precision_matrix(&self) -> Array2<f64> {
+        PrecisionMatrix::from(CovarianceMatrix::from(self)).into()
+    }
+
+    /// Compute the partial correlation matrix, i.e. the correlation of each pair of variables given all the others.
+    ///
+    /// The rows and columns are sorted w.r.t. the variables labels. Near-zero off-diagonal values
+    /// suggest conditional independence, see [`PartialCorrelationMatrix`].
+    ///
+    /// # Panics
+    ///
+    /// See [`Self::precision_matrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    /// use ndarray::prelude::*;
+    ///
+    /// // Construct a data matrix with Y = X + Z + noise, where X and Z are uncorrelated.
+    /// let d = GaussianDataMatrix::from_ndarray(
+    ///     array![
+    ///         [0., 1.5, 1.],
+    ///         [0., -1.5, -1.],
+    ///         [1., 2., 1.],
+    ///         [1., 0.5, -1.],
+    ///         [-1., -0.5, 1.],
+    ///         [-1., -2., -1.],
+    ///     ],
+    ///     ["X", "Y", "Z"],
+    /// );
+    ///
+    /// // Compute the partial correlation matrix.
+    /// let rho = d.partial_correlation_matrix();
+    ///
+    /// // Check the diagonal is +1.
+    /// assert!(rho.diag().iter().all(|&r| r == 1.));
+    /// // Check X and Z are dependent given Y, i.e. Y is a collider.
+    /// assert!(rho[[0, 2]] < -0.5);
+    /// ```
+    ///
+    #[inline]
+    pub fn partial_correlation_matrix(&self) -> Array2<f64> {
+        PartialCorrelationMatrix::from(CovarianceMatrix::from(self)).into()
+    }
+
+    /// Discretize the data matrix into a categorical data matrix.
+    ///
+    /// Given the sorted inner breakpoints $b_1 < \dots < b_k$ of a variable (see [`Discretization::breakpoints`]),
+    /// each value $x$ is mapped to the $j$-th state `j: (b_{j-1}, b_j]`, with $b_0 = -\infty$ and the last
+    /// state being `k: (b_k, inf)`. The bin index is zero-padded, so that the states sort in bins order.
+    ///
+    /// # Panics
+    ///
+    /// The data matrix must not contain NaN values, see also [`Discretization::breakpoints`].
+    pub fn discretize(&self, method: &Discretization) -> CategoricalDataMatrix {
+        // Assert the data matrix has no NaN values, which would fall into the first bin.
+        assert!(
+            !self.data.iter().any(|x| x.is_nan()),
+            "Data matrix must not contain NaN values"
+        );
+
+        // Compute the breakpoints.
+        let breakpoints = method.breakpoints(self);
+
+        // Allocate the new data matrix.
+        let mut data = Array2::<u8>::zeros(self.data.dim());
+        // For each variable ...
+        for ((mut y, x), b) in data
+            .columns_mut()
+            .into_iter()
+            .zip(self.data.columns())
+            .zip(&breakpoints)
+        {
+            // Map each value to its bin, i.e. the number of breakpoints lower than the value.
+            y.zip_mut_with(&x, |y, x| *y = b.partition_point(|b| b < x) as u8);
+        }
+
+        // Compute the states.
+        let states = self.labels.iter().zip(breakpoints).map(|(x, b)| {
+            // Get the lower and upper bounds of the bins.
+            let lower = std::iter::once(f64::NEG_INFINITY).chain(b.iter().copied());
+            let upper = b.iter().map(|b| format!("{b}]")).chain(["inf)".into()]);
+            // Get the width of the bins indices, so that the states sort in bins order.
+            let w = b.len().to_string().len();
+            // Format the bins.
+            let ys = lower
+                .zip(upper)
+                .enumerate()
+                .map(|(i, (a, b))| format!("{i:0w$}: ({a}, {b}"));
+
+            (x.clone(), ys.collect_vec())
+        });
+
+        CategoricalDataMatrix::from_ndarray(data, states)
+    }
+
+    /// Add i.i.d. Gaussian noise $\mathcal{N}(0, \sigma^2)$ to the data matrix.
+    ///
+    /// # Panics
+    ///
+    /// The standard deviation $\sigma$ must be non-negative and finite.
+    pub fn add_noise<R: Rng>(&self, rng: &mut R, sd: f64) -> Self {
+        // Initialize the noise distribution.
+        let noise = Normal::new(0., sd)
+            .ok()
+            .filter(|_| sd.is_finite())
+            .expect("Standard deviation must be non-negative and finite");
+
+        // Clone the data matrix.
+        let mut data = self.clone();
+        // Add noise to each value.
+        data.data.mapv_inplace(|x| x + noise.sample(rng));
+
+        data
+    }
+
+    /// Apply the nonparanormal transformation[^1] to the data matrix.
+    ///
+    /// Each value $x$ is mapped to $\Phi^{-1}(\tilde{F}(x))$, where $\Phi^{-1}$ is the standard
+    /// normal quantile function and $\tilde{F}$ is the empirical distribution function of the
+    /// associated variable, with ties given their average rank. In order to bound the tails,
+    /// $\tilde{F}$ is Winsorized to $[\delta_n, 1 - \delta_n]$, with truncation constant:
+    ///
+    /// $$ \delta_n = \frac{1}{4 n^{1/4} \sqrt{\pi \ln n}} $$
+    ///
+    /// The transformed data can be used with Gaussian methods, e.g. [`FisherZ`](crate::stats::FisherZ).
+    ///
+    /// # Panics
+    ///
+    /// The sample size must be greater than one.
+    ///
+    /// [^1]: [Liu, H., Lafferty, J., & Wasserman, L. (2009). The nonparanormal: Semiparametric estimation of high dimensional undirected graphs. Journal of Machine Learning Research, 10(10).](https://scholar.google.com/scholar?q=The+nonparanormal%3A+Semiparametric+estimation+of+high+dimensional+undirected+graphs)
+    ///
+    pub fn nonparanormal(&self) -> Self {
+        // Get the sample size.
+        let n = self.data.nrows();
+        // Assert the sample size is greater than one.
+        assert!(n > 1, "Sample size must be greater than one");
+
+        // Compute the truncation constant.
+        let delta = 1. / (4. * (n as f64).powf(0.25) * (PI * (n as f64).ln()).sqrt());
+        // Initialize the standard normal distribution.
+        let normal = StandardNormal::new(0., 1.).unwrap();
+
+        // Clone the data matrix.
+        let mut data = self.data.clone();
+        // For each variable ...
+        for mut x in data.columns_mut() {
+            // Sort the samples w.r.t. the values.
+            let order = (0..n)
+                .sorted_by(|&i, &j| x[i].total_cmp(&x[j]))
+                .collect_vec();
+            // Initialize the ranks.
+            let mut ranks = vec![0.; n];
+            // For each group of tied values ...
+            let mut i = 0;
+            while i < n {
+                // ... find the last tied value ...
+                let mut j = i;
+                while j + 1 < n && x[order[j + 1]] == x[order[i]] {
+                    j += 1;
+                }
+                // ... and assign the average rank.
+                for &k in &order[i..=j] {
+                    ranks[k] = (i + j + 2) as f64 / 2.;
+                }
+                i = j + 1;
+            }
+            // Map each rank to the associated normal quantile.
+            x.iter_mut().zip(ranks).for_each(|(x, r)| {
+                *x = normal.inverse_cdf((r / n as f64).clamp(delta, 1. - delta));
+            });
+        }
+
+        Self::with_data_labels(data, self.labels.clone())
+    }
+}
+
 impl From<DataFrame> for GaussianDataMatrix {
     fn from(data_frame: DataFrame) -> Self {
         // Check for missing values.
@@ -308,9 +963,14 @@ impl From<DataFrame> for GaussianDataMatrix {
         );
 
         // Check for wrong data type.
+        let columns = data_frame
+            .iter()
+            .filter(|s| !s.dtype().is_float())
+            .map(|s| s.name())
+            .collect_vec();
         assert!(
-            data_frame.iter().all(|s| s.dtype().is_float()),
-            "DataSet must contain only float types"
+            columns.is_empty(),
+            "DataSet must contain only float types, found non-float columns: {columns:?}"
         );
 
         // Sort columns by name.
@@ -409,9 +1069,14 @@ impl From<DataFrame> for ZINBDataMatrix {
         );
 
         // Check for wrong data type.
+        let columns = data_frame
+            .iter()
+            .filter(|s| !s.dtype().is_float())
+            .map(|s| s.name())
+            .collect_vec();
         assert!(
-            data_frame.iter().all(|s| s.dtype().is_float()),
-            "DataSet must contain only float types"
+            columns.is_empty(),
+            "DataSet must contain only float types, found non-float columns: {columns:?}"
         );
 
         // Sort columns by name.
@@ -68,6 +68,19 @@ pub trait DataSetSample: DataSet {
         sample_size: usize,
         bootstrap_size: usize,
     ) -> Self::BootstrapIter<'a, 'b, R>;
+
+    /// Draw a bootstrap sample, i.e. as many samples as the data set with replacement.
+    #[inline]
+    fn bootstrap<R: Rng>(&self, rng: &mut R) -> Self {
+        self.sample_with_replacement(rng, self.sample_size())
+    }
+
+    /// Draw a weighted bootstrap sample, i.e. the distinct samples drawn by `bootstrap`
+    /// in their original order, together with their multiplicities.
+    ///
+    /// Given the same random number generator state, the expansion of the weighted
+    /// bootstrap sample is a permutation of the bootstrap sample.
+    fn weighted_bootstrap<R: Rng>(&self, rng: &mut R) -> (Self, Array1<usize>);
 }
 
 /// Data set bootstrap iterator.
@@ -194,11 +207,38 @@ where
     ) -> Self::BootstrapIter<'a, 'b, R> {
         Self::BootstrapIter::new(self, rng, sample_size, bootstrap_size)
     }
+
+    fn weighted_bootstrap<R: Rng>(&self, rng: &mut R) -> (Self, Array1<usize>) {
+        // Allocate memory for the multiplicities.
+        let mut weights = Array1::<usize>::zeros(self.sample_size());
+
+        // Initialize the sample indices range.
+        let indices = rng.sample_iter(Uniform::new(0, self.sample_size()));
+
+        // For each sample ...
+        for i in indices.take(self.sample_size()) {
+            // ... increment its multiplicity.
+            weights[i] += 1;
+        }
+
+        // Get the distinct sample indices.
+        let indices = weights
+            .indexed_iter()
+            .filter_map(|(i, &w)| (w > 0).then_some(i))
+            .collect_vec();
+        // Select the distinct samples.
+        let data = self.data().select(Axis(0), &indices);
+        // Select the associated multiplicities.
+        let weights = weights.select(Axis(0), &indices);
+
+        (Self::with_data_labels(data, self.labels().clone()), weights)
+    }
 }
 
 /* Test the `DataSetSample` trait using `CategoricalDataMatrix`. */
 #[cfg(test)]
 mod test_data_set_sample {
+    use itertools::Itertools;
     use ndarray::prelude::*;
     use rand::prelude::*;
     use rand_xoshiro::Xoshiro256StarStar;
@@ -263,6 +303,57 @@ mod test_data_set_sample {
             assert_eq!(sample.labels(), data_set.labels());
         });
     }
+
+    #[test]
+    fn test_bootstrap() {
+        let data = Array2::from_shape_fn((10, 2), |(i, j)| ((i + j) % 3) as u8);
+        let labels = [("X", ["a", "b", "c"]), ("Y", ["a", "b", "c"])]
+            .into_iter()
+            .map(|(l, s)| (l.into(), s.iter().map(|&s| s.into()).collect()))
+            .collect();
+        let data_set = CategoricalDataMatrix::with_data_labels(data, labels);
+        let mut rng = Xoshiro256StarStar::seed_from_u64(42);
+        let sample = data_set.bootstrap(&mut rng);
+        assert_eq!(sample.sample_size(), data_set.sample_size());
+        assert_eq!(sample.labels(), data_set.labels());
+        let mut rng = Xoshiro256StarStar::seed_from_u64(42);
+        assert_eq!(data_set.bootstrap(&mut rng).data(), sample.data());
+    }
+
+    #[test]
+    fn test_weighted_bootstrap() {
+        let data = Array2::from_shape_fn((10, 2), |(i, j)| ((i + j) % 3) as u8);
+        let labels = [("X", ["a", "b", "c"]), ("Y", ["a", "b", "c"])]
+            .into_iter()
+            .map(|(l, s)| (l.into(), s.iter().map(|&s| s.into()).collect()))
+            .collect();
+        let data_set = CategoricalDataMatrix::with_data_labels(data, labels);
+        let mut rng = Xoshiro256StarStar::seed_from_u64(42);
+        let (sample, weights) = data_set.weighted_bootstrap(&mut rng);
+        assert_eq!(sample.sample_size(), weights.len());
+        assert_eq!(weights.sum(), data_set.sample_size());
+        assert!(weights.iter().all(|&w| w > 0));
+        assert_eq!(sample.labels(), data_set.labels());
+        // Expand the weighted sample and compare it to the bootstrap sample.
+        let mut rng = Xoshiro256StarStar::seed_from_u64(42);
+        let bootstrap = data_set.bootstrap(&mut rng);
+        let expanded = sample
+            .data()
+            .rows()
+            .into_iter()
+            .zip(&weights)
+            .flat_map(|(row, &w)| std::iter::repeat(row.to_vec()).take(w))
+            .sorted()
+            .collect_vec();
+        let bootstrap = bootstrap
+            .data()
+            .rows()
+            .into_iter()
+            .map(|row| row.to_vec())
+            .sorted()
+            .collect_vec();
+        assert_eq!(expanded, bootstrap);
+    }
 }
 
 /// Parallel data set bootstrap iterator.
@@ -1,6 +1,13 @@
-use std::{fmt::Debug, iter::FusedIterator};
+use std::{
+    fmt::Debug,
+    iter::FusedIterator,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, RwLock,
+    },
+};
 
-use crate::models::Independence;
+use crate::{models::Independence, types::FxIndexMap};
 
 /// Conditional Independence Test (CIT) trait.
 pub trait ConditionalIndependenceTest<'a>: Clone + Debug + Sync {
@@ -15,6 +22,9 @@ pub trait ConditionalIndependenceTest<'a>: Clone + Debug + Sync {
     /// Returns `true` whether $H_0: X \mathrlap{\thinspace\perp}{\perp}_{\mathcal{P}} \thinspace Y \mid \mathbf{Z}$ is not rejected.
     fn call(&self, x: usize, y: usize, z: &[usize]) -> bool;
 
+    /// Get significance level $\alpha$.
+    fn significance_level(&self) -> f64;
+
     /// Set significance level $\alpha$.
     ///
     /// # Panics
@@ -26,6 +36,18 @@ pub trait ConditionalIndependenceTest<'a>: Clone + Debug + Sync {
     fn labels(&self) -> Self::LabelsIter<'_>;
 }
 
+/// Assert a significance level $\alpha$ is in the (0, 1) interval.
+///
+/// Shared by the implementations of [`ConditionalIndependenceTest::with_significance_level`].
+///
+#[inline]
+pub(crate) fn check_significance_level(alpha: f64) {
+    assert!(
+        alpha > 0. && alpha < 1.,
+        "Significance level must be in the (0, 1) interval, got {alpha}"
+    );
+}
+
 impl<'a, T> Independence for T
 where
     T: ConditionalIndependenceTest<'a>,
@@ -35,3 +57,126 @@ where
         <Self as ConditionalIndependenceTest>::call(self, x, y, z)
     }
 }
+
+/// Local CIT results cache type.
+type Cache = FxIndexMap<(usize, usize, Vec<usize>), (usize, f64, f64)>;
+
+/// Cached Conditional Independence Test (CIT) wrapper.
+///
+/// The results of the underlying test are memoized w.r.t. $(X, Y, \mathbf{Z})$,
+/// assuming the test is symmetric in $X$ and $Y$ and invariant to the order of $\mathbf{Z}$.
+/// Since the results do not depend on the significance level, the cache is shared
+/// across clones and significance levels. The underlying data set is borrowed for
+/// the whole lifetime of the test, therefore it cannot change while cached.
+#[derive(Clone, Debug)]
+pub struct CachedConditionalIndependenceTest<T> {
+    test: T,
+    cache: Arc<RwLock<Cache>>,
+    hits: Arc<AtomicUsize>,
+    misses: Arc<AtomicUsize>,
+}
+
+impl<T> CachedConditionalIndependenceTest<T> {
+    /// Construct a new cached test given the underlying test.
+    #[inline]
+    pub fn new(test: T) -> Self {
+        Self {
+            test,
+            cache: Default::default(),
+            hits: Default::default(),
+            misses: Default::default(),
+        }
+    }
+
+    /// Get the underlying test.
+    #[inline]
+    pub const fn test(&self) -> &T {
+        &self.test
+    }
+
+    /// Get the number of cache hits.
+    #[inline]
+    pub fn hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Get the number of cache misses.
+    #[inline]
+    pub fn misses(&self) -> usize {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// Get the cache hit rate, i.e. the ratio of hits over queries, if any.
+    #[inline]
+    pub fn hit_rate(&self) -> Option<f64> {
+        // Get the number of queries.
+        let n = self.hits() + self.misses();
+
+        (n > 0).then(|| self.hits() as f64 / n as f64)
+    }
+
+    /// Clear the cache and its statistics.
+    pub fn clear(&self) {
+        // Clear the cache.
+        self.cache.write().unwrap().clear();
+        // Reset the statistics.
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+}
+
+impl<'a, T> ConditionalIndependenceTest<'a> for CachedConditionalIndependenceTest<T>
+where
+    T: ConditionalIndependenceTest<'a>,
+{
+    type LabelsIter<'b> = T::LabelsIter<'b> where Self: 'b;
+
+    fn eval(&self, x: usize, y: usize, z: &[usize]) -> (usize, f64, f64) {
+        // Compute the key as (min(X, Y), max(X, Y), sorted Z).
+        let mut k = (x.min(y), x.max(y), z.to_vec());
+        k.2.sort_unstable();
+
+        // Check if the result is cached.
+        if let Some(&r) = self.cache.read().unwrap().get(&k) {
+            // Increment the hits.
+            self.hits.fetch_add(1, Ordering::Relaxed);
+
+            return r;
+        }
+
+        // Increment the misses.
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        // Compute the result.
+        let r = self.test.eval(x, y, z);
+        // Cache the result.
+        self.cache.write().unwrap().insert(k, r);
+
+        r
+    }
+
+    #[inline]
+    fn call(&self, x: usize, y: usize, z: &[usize]) -> bool {
+        // Compute p-value.
+        let (_, _, pval) = self.eval(x, y, z);
+
+        pval > self.significance_level()
+    }
+
+    #[inline]
+    fn significance_level(&self) -> f64 {
+        self.test.significance_level()
+    }
+
+    #[inline]
+    fn with_significance_level(mut self, alpha: f64) -> Self {
+        // Set significance level of the underlying test.
+        self.test = self.test.with_significance_level(alpha);
+
+        self
+    }
+
+    #[inline]
+    fn labels(&self) -> Self::LabelsIter<'_> {
+        self.test.labels()
+    }
+}
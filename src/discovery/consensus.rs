@@ -0,0 +1,98 @@
+use ndarray::prelude::*;
+
+use crate::{graphs::BaseGraph, prelude::PDGraph, L};
+
+/// Consensus of a collection of CPDAGs by majority orientation.
+///
+/// Vertices are aligned by label across the given CPDAGs, e.g. the outputs of
+/// PC-Stable on bootstrap samples. An edge is kept if it is present in at least
+/// `edge_threshold` fraction of CPDAGs. A kept edge $X \rightarrow Y$ is oriented
+/// if $X \rightarrow Y$ is the strict majority among the CPDAGs containing the edge,
+/// and its fraction is at least `orient_threshold`. Otherwise it is left undirected,
+/// e.g. in case of ties.
+///
+/// Note that the consensus graph is not guaranteed to be a CPDAG.
+///
+/// # Panics
+///
+/// The collection of CPDAGs must be non-empty, with the same vertices,
+/// and both thresholds must be in the $[0, 1]$ interval.
+///
+/// # Examples
+///
+/// ```
+/// use causal_hub::prelude::*;
+///
+/// // Build three CPDAGs.
+/// let g = PDGraph::new_pagraph(["A", "B", "C"], [], [("A", "B")]);
+/// let h = PDGraph::new_pagraph(["A", "B", "C"], [("A", "B")], [("B", "C")]);
+/// let k = PDGraph::new_pagraph(["A", "B", "C"], [], [("A", "B")]);
+///
+/// // Compute their consensus.
+/// let c = consensus_cpdag(&[g, h, k], 0.5, 0.5);
+///
+/// // Check that only the majority edge is kept and oriented.
+/// assert_eq!(c, PDGraph::new_pagraph(["A", "B", "C"], [], [("A", "B")]));
+/// ```
+///
+pub fn consensus_cpdag(cpdags: &[PDGraph], edge_threshold: f64, orient_threshold: f64) -> PDGraph {
+    // Assert the collection of CPDAGs is non-empty.
+    assert!(!cpdags.is_empty(), "CPDAGs must be non-empty");
+    // Assert the CPDAGs have the same vertices.
+    assert!(
+        cpdags.iter().all(|g| L!(g).eq(L!(cpdags[0]))),
+        "CPDAGs must have the same vertices"
+    );
+    // Assert thresholds are in [0, 1].
+    assert!(
+        (0. ..=1.).contains(&edge_threshold),
+        "Edge threshold must be in [0, 1]"
+    );
+    assert!(
+        (0. ..=1.).contains(&orient_threshold),
+        "Orientation threshold must be in [0, 1]"
+    );
+
+    // Get the number of CPDAGs.
+    let n = cpdags.len() as f64;
+    // Get the number of vertices.
+    let order = cpdags[0].order();
+
+    // Initialize the adjacency and the orientation counts.
+    let mut adjacent = Array2::<usize>::zeros((order, order));
+    let mut directed = Array2::<usize>::zeros((order, order));
+    // For each CPDAG ...
+    for g in cpdags {
+        // ... get its marked adjacency matrix ...
+        let m = g.to_marked_matrix();
+        // ... and count the adjacencies and orientations.
+        adjacent.zip_mut_with(&m, |a, &m| *a += (m != 0) as usize);
+        directed.zip_mut_with(&m, |d, &m| *d += (m == 1) as usize);
+    }
+
+    // Initialize the consensus marked adjacency matrix.
+    let mut marks = Array2::<i8>::zeros((order, order));
+    // For each couple of vertices ...
+    for i in 0..order {
+        for j in (i + 1)..order {
+            // ... check if the edge is frequent enough.
+            let a = adjacent[[i, j]];
+            if a == 0 || (a as f64) / n < edge_threshold {
+                continue;
+            }
+            // Get the orientations counts.
+            let (f, b) = (directed[[i, j]], directed[[j, i]]);
+            // Orient the edge by majority vote, leaving ties undirected.
+            let m = match (f, b) {
+                (f, b) if f > b && (f as f64) / (a as f64) >= orient_threshold => 1,
+                (f, b) if b > f && (b as f64) / (a as f64) >= orient_threshold => -1,
+                _ => 2,
+            };
+            // Set the marks.
+            marks[[i, j]] = m;
+            marks[[j, i]] = if m == 2 { 2 } else { -m };
+        }
+    }
+
+    PDGraph::from_marked_matrix(L!(cpdags[0]), marks)
+}
@@ -0,0 +1,124 @@
+use itertools::Itertools;
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    discovery::ConditionalIndependenceTest,
+    graphs::BaseGraph,
+    models::{GraphicalSeparation, Independence},
+    prelude::DiGraph,
+    L,
+};
+
+/// Faithfulness report, i.e. the agreement between conditional independence
+/// statements tested from data and d-separation statements in a graph.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FaithfulnessReport {
+    agreements: usize,
+    violations: Vec<(usize, usize, Vec<usize>)>,
+}
+
+impl FaithfulnessReport {
+    /// Get the number of checked triples.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.agreements + self.violations.len()
+    }
+
+    /// Checks if no triple was checked.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the number of agreements.
+    #[inline]
+    pub fn agreements(&self) -> usize {
+        self.agreements
+    }
+
+    /// Get the fraction of agreements, if any triple was checked.
+    #[inline]
+    pub fn agreement_rate(&self) -> Option<f64> {
+        (!self.is_empty()).then(|| self.agreements as f64 / self.len() as f64)
+    }
+
+    /// Get the violations, i.e. the triples $(X, Y, \mathbf{Z})$ on which the test and the graph disagree.
+    #[inline]
+    pub fn violations(&self) -> &[(usize, usize, Vec<usize>)] {
+        &self.violations
+    }
+}
+
+/// Check faithfulness of the data w.r.t. a DAG on random triples.
+///
+/// For each of the `n` triples $(X, Y, \mathbf{Z})$ drawn at random, with $|\mathbf{Z}|$
+/// uniform in $[0, |\mathbf{V}| - 2]$, the conditional independence statement
+/// $X \mathrlap{\thinspace\perp}{\perp} \thinspace Y \mid \mathbf{Z}$ given by the test,
+/// which is computed from the data set, is compared to d-separation in the DAG.
+/// Since the triples depend only on the random number generator, the report is
+/// reproducible under a seed.
+///
+/// # Panics
+///
+/// The DAG and the test must have the same variables, at least two.
+///
+/// # Examples
+///
+/// ```
+/// use causal_hub::prelude::*;
+/// use rand::SeedableRng;
+/// use rand_xoshiro::Xoshiro256PlusPlus;
+///
+/// // Build a new directed graph.
+/// let g = DiGraph::new(["A", "B", "C"], [("A", "C"), ("B", "C")]);
+///
+/// // Check faithfulness of the d-separation oracle.
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+/// let report = faithfulness_report(&g, &DSeparationOracle::new(&g), 10, &mut rng);
+///
+/// // Assert the oracle is faithful.
+/// assert_eq!(report.agreement_rate(), Some(1.));
+/// ```
+///
+pub fn faithfulness_report<'a, T, R>(
+    dag: &DiGraph,
+    test: &T,
+    n: usize,
+    rng: &mut R,
+) -> FaithfulnessReport
+where
+    T: ConditionalIndependenceTest<'a>,
+    R: Rng,
+{
+    // Assert DAG and test have the same variables.
+    assert!(
+        L!(dag).eq(test.labels()),
+        "DAG and test must have the same variables"
+    );
+    // Assert there are at least two variables.
+    assert!(dag.order() > 1, "DAG must have at least two variables");
+
+    // Initialize the graphical separation functor.
+    let d_separation = GraphicalSeparation::new(dag);
+    // Initialize the vertices.
+    let mut vertices = (0..dag.order()).collect_vec();
+
+    // Initialize the report.
+    let mut report = FaithfulnessReport::default();
+    // For each triple ...
+    for _ in 0..n {
+        // ... draw the size of Z ...
+        let k = rng.gen_range(0..=(vertices.len() - 2));
+        // ... and draw X, Y and Z.
+        let (xyz, _) = vertices.partial_shuffle(rng, k + 2);
+        let (x, y) = (xyz[0], xyz[1]);
+        let z = xyz[2..].iter().copied().sorted().collect_vec();
+        // Check the agreement between the test and d-separation.
+        match test.call(x, y, &z) == d_separation.is_independent(x, y, &z) {
+            true => report.agreements += 1,
+            false => report.violations.push((x, y, z)),
+        }
+    }
+
+    report
+}
@@ -1,4 +1,4 @@
-use std::marker::PhantomData;
+use std::{fmt::Debug, marker::PhantomData, sync::Mutex};
 
 use itertools::{iproduct, Itertools};
 use log::{debug, trace};
@@ -56,6 +56,7 @@ where
     max_in_degree: usize,
     max_iter: usize,
     seed: Option<u64>,
+    stable_tiebreak: bool,
     _d: PhantomData<D>,
     _k: PhantomData<K>,
     _t: PhantomData<T>,
@@ -97,6 +98,7 @@ where
             max_in_degree,
             max_iter: usize::MAX,
             seed: None,
+            stable_tiebreak: false,
             _d: PhantomData,
             _k: PhantomData,
             _t: PhantomData,
@@ -105,7 +107,14 @@ where
         }
     }
 
-    /// Set initial graph $\mathcal{G}$.
+    /// Set initial graph $\mathcal{G}$, e.g. to warm start the search from a previous result.
+    ///
+    /// The initial score is computed on $\mathcal{G}$, with the required edges added.
+    ///
+    /// # Panics
+    ///
+    /// The initial graph must be acyclic, with the same labels of the data set,
+    /// and must not contain forbidden edges. Checked when calling the search.
     ///
     /// # Examples
     ///
@@ -234,6 +243,41 @@ where
 
         self
     }
+
+    /// Enables stable tie-breaking of equivalent-score edge operations.
+    ///
+    /// If enabled, the search space is updated preserving the order of the edge operations,
+    /// so that ties are broken as in `bnlearn`, at the cost of an $O(n)$ update per iteration.
+    /// Otherwise, the search space is updated in $O(1)$, swapping the last edge operation
+    /// in place of the removed one, which may change the order in which ties are broken.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::{prelude::*, polars::prelude::*};
+    ///
+    /// // Load data set from CSV file.
+    /// let data_set = CsvReader::from_path("./tests/assets/asia.csv").unwrap().finish().unwrap();
+    /// let data_set: CategoricalDataMatrix = data_set.into();
+    /// // Initialize empty prior knowledge.
+    /// let prior_knowledge = FR::new(data_set.labels_iter(), [], []);
+    ///
+    /// // Initialize scoring criterion.
+    /// let scoring_criterion = BIC::new(&data_set);
+    ///
+    /// // Perform discovery with stable tie-breaking.
+    /// let pred_graph: DiGraph = HC::new(&scoring_criterion)
+    ///     .with_stable_tiebreak(true)
+    ///     .call(&data_set, &prior_knowledge);
+    /// ```
+    ///
+    #[inline]
+    pub const fn with_stable_tiebreak(mut self, stable_tiebreak: bool) -> Self {
+        // Set tie-breaking rule.
+        self.stable_tiebreak = stable_tiebreak;
+
+        self
+    }
 }
 
 impl<'a, D, K, G, S, T, const PARALLEL: bool> HillClimbing<'a, D, K, G, S, T, PARALLEL>
@@ -281,12 +325,20 @@ where
 
     /// Update edge space for each edge operation.
     #[inline]
-    fn update((mut add, mut del, mut rev): ES, x: usize, y: usize, a: u8) -> ES {
+    fn update(stable: bool, (mut add, mut del, mut rev): ES, x: usize, y: usize, a: u8) -> ES {
+        // Select the removal rule w.r.t. the tie-breaking rule.
+        let remove = match stable {
+            // Preserve the order of the edge space in O(n).
+            true => E::shift_remove::<(usize, usize)>,
+            // Swap the last edge in place of the removed one in O(1).
+            false => E::swap_remove::<(usize, usize)>,
+        };
+
         // Apply operation.
         match a {
             Op::ADD => {
                 // Remove performed action.
-                assert!(add.remove(&(x, y)));
+                assert!(remove(&mut add, &(x, y)));
                 // Add(X, Y) implies that (X, Y) is not in the
                 // required list, therefore Del(X, Y) is valid.
                 assert!(del.insert((x, y)));
@@ -301,18 +353,18 @@ where
                 // forbidden list, therefore Add(X, Y) is valid.
                 assert!(add.insert((x, y)));
                 // Remove performed action.
-                assert!(del.remove(&(x, y)));
+                assert!(remove(&mut del, &(x, y)));
                 // If Add(Y, X) and Del(X, Y) are valid, then Rev(X, Y) is valid.
                 // Since Del(X, Y) is valid by construction, check only Add(Y, X).
                 if add.contains(&(y, x)) {
-                    assert!(rev.remove(&(x, y)));
+                    assert!(remove(&mut rev, &(x, y)));
                 }
             }
             Op::REV => {
                 // Remove performed action(s).
-                assert!(add.remove(&(y, x)));
-                assert!(del.remove(&(x, y)));
-                assert!(rev.remove(&(x, y)));
+                assert!(remove(&mut add, &(y, x)));
+                assert!(remove(&mut del, &(x, y)));
+                assert!(remove(&mut rev, &(x, y)));
                 // Rev(X, Y) implies than (X, Y) is not in the
                 // required list nor in the forbidden list,
                 // therefore, Add(X, Y) is valid.
@@ -359,6 +411,8 @@ where
             "Graph labels must be equal to prior knowledge labels"
         );
 
+        // Check acyclicity of the initial graph.
+        assert!(g.is_acyclic(), "Initial graph must be acyclic");
         // Check that every edge in the graph is not in forbidden.
         assert!(
             !E!(g).any(|(x, y)| k.has_forbidden(x, y)),
@@ -547,6 +601,59 @@ macro_rules! search {
     };
 }
 
+/// Local scores recorder, i.e. a cache view that records the computed local scores,
+/// in order to evaluate the delta helpers while collecting the cache updates.
+#[derive(Debug)]
+struct Recorder<'b, T> {
+    cache: &'b T,
+    fragments: Mutex<CU<KE>>,
+}
+
+impl<'b, T> Recorder<'b, T> {
+    /// Construct a new local scores recorder given a cache.
+    #[inline]
+    fn new(cache: &'b T) -> Self {
+        Self {
+            cache,
+            fragments: Default::default(),
+        }
+    }
+
+    /// Returns the recorded cache updates.
+    #[inline]
+    fn into_fragments(self) -> CU<KE> {
+        self.fragments.into_inner().unwrap()
+    }
+}
+
+impl<'b, T> Clone for Recorder<'b, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            cache: self.cache,
+            fragments: Mutex::new(self.fragments.lock().unwrap().clone()),
+        }
+    }
+}
+
+impl<'a, 'b, D, G, S> DecomposableScoringCriterion<D, G>
+    for Recorder<'b, C<'a, D, G, S, score_types::Decomposable, (usize, Vec<usize>)>>
+where
+    D: Clone + Debug + Sync,
+    G: DirectedGraph<Direction = directions::Directed>,
+    S: DecomposableScoringCriterion<D, G>,
+{
+    #[inline]
+    fn call(&self, x: usize, z: &[usize]) -> f64 {
+        // Get the score from cache or compute it.
+        let (k, s) = self.cache.call(x, z);
+        // Record the score.
+        self.fragments.lock().unwrap().push((k, s));
+
+        s
+    }
+}
+
 /* Implement Hill-Climbing for Decomposable Scoring Criteria */
 impl<'a, D, K, G, S, const PARALLEL: bool>
     HillClimbing<'a, D, K, G, S, score_types::Decomposable, PARALLEL>
@@ -565,50 +672,19 @@ where
         x: usize,
         y: usize,
     ) -> ((A, f64), CU<KE>) {
-        // Get current Y score.s
-        let mut pa_y = Pa!(g, y).collect_vec();
-        let s_y = cache.call(y, &pa_y);
+        // Record the local scores computed through the cache.
+        let scores = Recorder::new(cache);
+        // Get the parents of Y.
+        let pa_y = Pa!(g, y).collect_vec();
         // Compute delta score depending on operation.
-        let (delta_star, s_star) = match OP {
-            Op::ADD => {
-                // Add X in-place by leveraging Pa(G, Y) order.
-                let i = pa_y.binary_search(&x).unwrap_err();
-                pa_y.insert(i, x);
-                // Compute score.
-                let s_y_star = cache.call(y, &pa_y);
-
-                (s_y_star.1 - s_y.1, vec![s_y_star, s_y])
-            }
-            Op::DEL => {
-                // Remove X in-place by leveraging Pa(G, Y) order.
-                let i = pa_y.binary_search(&x).unwrap();
-                pa_y.remove(i);
-                // Compute score.
-                let s_y_star = cache.call(y, &pa_y);
-
-                (s_y_star.1 - s_y.1, vec![s_y_star, s_y])
-            }
+        let delta_star = match OP {
+            Op::ADD => scores.delta_add(y, &pa_y, x),
+            Op::DEL => scores.delta_del(y, &pa_y, x),
             Op::REV => {
-                // Get current X score.
-                let mut pa_x = Pa!(g, x).collect_vec();
-                let s_x = cache.call(x, &pa_x);
+                // Get the parents of X.
+                let pa_x = Pa!(g, x).collect_vec();
 
-                // Add Y in-place by leveraging Pa(G, X) order.
-                let i = pa_x.binary_search(&y).unwrap_err();
-                pa_x.insert(i, y);
-                // Compute score.
-                let s_x_star = cache.call(x, &pa_x);
-
-                // Remove X in-place by leveraging Pa(G, Y) order.
-                let i = pa_y.binary_search(&x).unwrap();
-                pa_y.remove(i);
-                // Compute score.
-                let s_y_star = cache.call(y, &pa_y);
-
-                (
-                    (s_x_star.1 - s_x.1) + (s_y_star.1 - s_y.1),
-                    vec![s_x_star, s_x, s_y_star, s_y],
-                )
+                scores.delta_rev(x, &pa_x, y, &pa_y)
             }
             _ => panic!("Unknown operation code"),
         };
@@ -627,7 +703,7 @@ where
             delta_star
         );
 
-        (((x, y, OP), delta_star), s_star)
+        (((x, y, OP), delta_star), scores.into_fragments())
     }
 
     /// Search for best operation given current graph and edges space.
@@ -724,7 +800,7 @@ where
                 // Apply operation to current solution.
                 (g, s_g) = (Self::apply(&mut in_degree, g, x, y, a), s_g + delta);
                 // Update search space.
-                (add, del, rev) = Self::update((add, del, rev), x, y, a);
+                (add, del, rev) = Self::update(self.stable_tiebreak, (add, del, rev), x, y, a);
                 // Set the flag.
                 flag = true;
             }
@@ -804,7 +880,7 @@ where
             delta_star
         );
 
-        (((x, y, OP), delta_star), s_star)
+        (((x, y, OP), delta_star), scores.into_fragments())
     }
 
     /// Search for best operation given current graph and edges space.
@@ -871,7 +947,7 @@ where
                 // Apply operation to current solution.
                 (g, s_g) = (Self::apply(&mut in_degree, g, x, y, a), s_g + delta);
                 // Update search space.
-                (add, del, rev) = Self::update((add, del, rev), x, y, a);
+                (add, del, rev) = Self::update(self.stable_tiebreak, (add, del, rev), x, y, a);
                 // Set the flag.
                 flag = true;
             }
@@ -1,6 +1,15 @@
 mod conditional_independence_test;
 pub use conditional_independence_test::*;
 
+/// Alias for cached conditional independence test.
+pub type CachedCIT<T> = CachedConditionalIndependenceTest<T>;
+
+mod consensus;
+pub use consensus::*;
+
+mod faithfulness;
+pub use faithfulness::*;
+
 mod hill_climbing;
 pub use hill_climbing::*;
 
@@ -12,3 +21,6 @@ pub use prior_knowledge::*;
 
 mod scoring_criterion;
 pub use scoring_criterion::*;
+
+mod skeleton;
+pub use skeleton::*;
@@ -1,4 +1,5 @@
 use itertools::Itertools;
+use ndarray::prelude::*;
 use rayon::prelude::*;
 
 use crate::prelude::*;
@@ -10,6 +11,8 @@ where
     T: ConditionalIndependenceTest<'a>,
 {
     test: &'a T,
+    precompute_marginals: bool,
+    tiers: Option<&'a Tiers>,
 }
 
 impl<'a, T> PCStable<'a, T>
@@ -18,12 +21,120 @@ where
 {
     /// Construct a new PC-Stable functor.
     pub fn new(test: &'a T) -> Self {
-        Self { test }
+        Self {
+            test,
+            precompute_marginals: false,
+            tiers: None,
+        }
+    }
+
+    /// Set the temporal tiers used to orient the cross-tier edges forward in time.
+    ///
+    /// The v-structures that would point backward in time are skipped, then the
+    /// remaining cross-tier edges are oriented forward in time.
+    ///
+    /// # Panics
+    ///
+    /// The tiers must have the same variables of the test.
+    ///
+    pub fn with_tiers(mut self, tiers: &'a Tiers) -> Self {
+        // Assert tiers and test have the same variables.
+        assert!(
+            tiers.labels().iter().eq(self.test.labels()),
+            "Tiers and test must have the same variables"
+        );
+        // Set tiers.
+        self.tiers = Some(tiers);
+
+        self
+    }
+
+    /// Set whether to precompute the marginal independence tests.
+    ///
+    /// If set, the marginal independence tests $X \mathrlap{\thinspace\perp}{\perp} \thinspace Y$
+    /// are computed in parallel for each couple of variables before the skeleton discovery,
+    /// which then consults them instead of calling the test with an empty conditioning set.
+    /// The discovered skeleton is unchanged, while it requires $O(|\mathbf{V}|^2)$ extra memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::{prelude::*, polars::prelude::*};
+    ///
+    /// // Read data from file.
+    /// let data_set = CsvReader::from_path("./tests/assets/pc_stable/asia.csv")
+    ///     .unwrap()
+    ///     .finish()
+    ///     .unwrap();
+    /// let data_set = CategoricalDataMatrix::from(data_set);
+    ///
+    /// // Initialize conditional independence test.
+    /// let test = ChiSquared::new(&data_set);
+    ///
+    /// // Perform discovery with precomputed marginal tests.
+    /// let pred_graph = PCStable::new(&test)
+    ///     .with_precompute_marginals(true)
+    ///     .call();
+    ///
+    /// // Check the result is the same.
+    /// assert_eq!(pred_graph, PCStable::new(&test).call());
+    /// ```
+    ///
+    #[inline]
+    pub fn with_precompute_marginals(mut self, precompute_marginals: bool) -> Self {
+        // Set precompute marginals flag.
+        self.precompute_marginals = precompute_marginals;
+
+        self
+    }
+
+    /// Private function. It computes the marginal independence tests in parallel, if required.
+    #[inline]
+    fn marginals(&self) -> Option<Array2<bool>> {
+        // Check if marginals must be precomputed.
+        if !self.precompute_marginals {
+            return None;
+        }
+
+        // Get the number of variables.
+        let n = self.test.labels().len();
+        // Compute the marginal independence tests for each couple of variables, in parallel.
+        let marginals: Vec<_> = (0..n)
+            .flat_map(|x| (0..x).map(move |y| (x, y)))
+            .collect_vec()
+            .into_par_iter()
+            .map(|(x, y)| (x, y, self.test.call(y, x, &[])))
+            .collect();
+
+        // Fill the symmetric matrix of marginal independence tests.
+        let mut m = Array2::from_elem((n, n), false);
+        for (x, y, f) in marginals {
+            m[[x, y]] = f;
+            m[[y, x]] = f;
+        }
+
+        Some(m)
     }
 
     /// Private function. It performs skeleton discovery given a test.
     #[inline]
     fn skeleton(&self) -> (Graph, SepSets) {
+        // Precompute the marginal independence tests, if required.
+        match self.marginals() {
+            Some(m) => self.skeleton_with(|x, y, z| match z.is_empty() {
+                true => m[[x, y]],
+                false => self.test.call(x, y, z),
+            }),
+            None => self.skeleton_with(|x, y, z| self.test.call(x, y, z)),
+        }
+    }
+
+    /// Private function. It performs skeleton discovery given an independence decision.
+    #[inline]
+    fn skeleton_with<F>(&self, mut is_independent: F) -> (Graph, SepSets)
+    where
+        F: FnMut(usize, usize, &[usize]) -> bool,
+    {
         // Set complete graph
         let mut g = Graph::complete(self.test.labels());
         // Initialize set of separating sets
@@ -51,7 +162,7 @@ where
                     // If there exists at least one, set the flag to true
                     .inspect(|_| flag = true)
                     // Assign each edge its related sepset
-                    .find_map(|z| match self.test.call(x, y, &z) {
+                    .find_map(|z| match is_independent(x, y, &z) {
                         true => Some((x, y, z.into_iter().collect())),
                         _ => None,
                     })
@@ -74,8 +185,24 @@ where
 
     /// Private function. It performs parallel skeleton discovery given a test.
     #[inline]
-    #[allow(clippy::type_complexity)]
     fn par_skeleton(&self) -> (Graph, SepSets) {
+        // Precompute the marginal independence tests, if required.
+        match self.marginals() {
+            Some(m) => self.par_skeleton_with(|x, y, z| match z.is_empty() {
+                true => m[[x, y]],
+                false => self.test.call(x, y, z),
+            }),
+            None => self.par_skeleton_with(|x, y, z| self.test.call(x, y, z)),
+        }
+    }
+
+    /// Private function. It performs parallel skeleton discovery given an independence decision.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn par_skeleton_with<F>(&self, is_independent: F) -> (Graph, SepSets)
+    where
+        F: Fn(usize, usize, &[usize]) -> bool + Sync,
+    {
         // Set complete graph
         let mut g = Graph::complete(self.test.labels());
         // Initialize set of separating sets
@@ -107,7 +234,7 @@ where
                     // If there exists at least one, set the flag to true
                     .inspect(|_| f = true)
                     // Assign each edge its related sepset
-                    .find_map(|z| match self.test.call(x, y, &z) {
+                    .find_map(|z| match is_independent(x, y, &z) {
                         true => Some((x, y, z.into_iter().collect())),
                         _ => None,
                     });
@@ -133,6 +260,39 @@ where
         (g, sepsets)
     }
 
+    /// Private function. It evaluates the separating test of each removed edge.
+    fn statistics(&self, sepsets: &SepSets) -> CIStatistics {
+        // Initialize the statistics.
+        let mut statistics = CIStatistics::default();
+        // For each removed edge, evaluate the test given its separation set.
+        for (&(x, y), z) in sepsets.iter().filter(|((x, y), _)| x < y) {
+            let s = self.test.eval(x, y, &z.iter().copied().collect_vec());
+            // Set the statistics for both orientations.
+            statistics.insert((x, y), s);
+            statistics.insert((y, x), s);
+        }
+
+        statistics
+    }
+
+    /// Private function. It evaluates the separating test of each removed edge, in parallel.
+    fn par_statistics(&self, sepsets: &SepSets) -> CIStatistics {
+        // For each removed edge, evaluate the test given its separation set, in parallel.
+        let statistics: Vec<_> = sepsets
+            .iter()
+            .filter(|((x, y), _)| x < y)
+            .collect_vec()
+            .into_par_iter()
+            .map(|(&(x, y), z)| (x, y, self.test.eval(x, y, &z.iter().copied().collect_vec())))
+            .collect();
+
+        // Set the statistics for both orientations.
+        statistics
+            .into_iter()
+            .flat_map(|(x, y, s)| [((x, y), s), ((y, x), s)])
+            .collect()
+    }
+
     /// Perform skeleton discovery given test.
     #[inline]
     pub fn call_skeleton(&self) -> Graph {
@@ -145,76 +305,92 @@ where
         self.par_skeleton().0
     }
 
-    /// Perform discovery given a test.
-    /// Firstly, it performs skeleton discovery and then orients v-structures leveraging discovery implied separation sets.
+    /// Perform skeleton discovery given test, keeping the separation sets
+    /// and the statistics of the test that removed each edge.
     #[inline]
-    pub fn call(&self) -> PDGraph {
+    pub fn call_skeleton_with_sepsets(&self) -> Skeleton {
         // Perform skeleton discovery
         let (g, sepsets) = self.skeleton();
-        // Cast the graph to a partially directed graph
-        let mut g: PDGraph = g.into();
-        // Create the set of unshielded triples (x, y, z) in which (x, z) is not d-separated by y
-        let triples: Vec<_> = V!(g)
-            .flat_map(|y| {
-                std::iter::repeat(y)
-                    .zip(Adj!(g, y).combinations(2))
-                    .map(|(y, xz)| (xz[0], y, xz[1]))
-                    .filter(|&(x, y, z)| {
-                        !g.has_edge_by_index(x, z) && !sepsets[&(x, z)].contains(&y)
-                    })
+        // Evaluate the separating tests
+        let statistics = self.statistics(&sepsets);
+
+        Skeleton::new(g, sepsets).with_statistics(statistics)
+    }
+
+    /// Perform parallel skeleton discovery given test, keeping the separation sets
+    /// and the statistics of the test that removed each edge.
+    #[inline]
+    pub fn par_call_skeleton_with_sepsets(&self) -> Skeleton {
+        // Perform skeleton discovery
+        let (g, sepsets) = self.par_skeleton();
+        // Evaluate the separating tests
+        let statistics = self.par_statistics(&sepsets);
+
+        Skeleton::new(g, sepsets).with_statistics(statistics)
+    }
+
+    /// Perform skeleton discovery for each significance level $\alpha$ in a given path.
+    ///
+    /// The significance level of the test is ignored, while the p-values are cached
+    /// and reused across the path, since only the rejection threshold changes.
+    /// Therefore, each skeleton is equal to the one discovered with the associated
+    /// significance level, at a fraction of the cost of independent runs.
+    /// Each skeleton keeps its separation sets, so that it can be oriented afterwards.
+    ///
+    /// # Panics
+    ///
+    /// If any $\alpha$ is not in the (0, 1) interval.
+    pub fn call_alpha_path(&self, alphas: &[f64]) -> Vec<(f64, Skeleton)> {
+        // Assert each alpha is in (0, 1).
+        assert!(
+            alphas.iter().all(|alpha| *alpha > 0. && *alpha < 1.),
+            "Significance levels must be in the (0, 1) interval"
+        );
+
+        // Initialize the p-values cache.
+        let mut cache: FxIndexMap<(usize, usize, Vec<usize>), f64> = Default::default();
+
+        alphas
+            .iter()
+            .map(|&alpha| {
+                // Perform skeleton discovery w.r.t. the cached p-values.
+                let (g, sepsets) = self.skeleton_with(|x, y, z| {
+                    // Get the p-value, computing it if not cached.
+                    let p_value = *cache
+                        .entry((x, y, z.to_vec()))
+                        .or_insert_with(|| self.test.eval(x, y, z).2);
+
+                    p_value > alpha
+                });
+
+                (alpha, Skeleton::new(g, sepsets))
             })
-            .collect();
+            .collect()
+    }
 
-        // For every unshielded triple ...
-        for (x, y, z) in triples {
-            // ... if one of the edges is already directed ...
-            if !g.has_undirected_edge_by_index(x, y) || !g.has_undirected_edge_by_index(z, y) {
-                // ... skip this triple.
-                continue;
-            }
-            // Otherwise, the triple is a v-structure.
-            g.orient_edge(x, y);
-            g.orient_edge(z, y);
-        }
+    /// Perform discovery given a test.
+    /// Firstly, it performs skeleton discovery and then orients v-structures leveraging discovery implied separation sets.
+    #[inline]
+    pub fn call(&self) -> PDGraph {
+        // Perform skeleton discovery.
+        let skeleton = self.call_skeleton_with_sepsets();
 
-        g
+        match self.tiers {
+            Some(tiers) => skeleton.orient_with_tiers(tiers),
+            None => skeleton.orient(),
+        }
     }
 
     /// Perform parallel discovery given a test.
     /// Firstly, it performs parallel skeleton discovery and then orients v-structures leveraging discovery implied separation sets.
     #[inline]
     pub fn par_call(&self) -> PDGraph {
-        // Perform skeleton discovery
-        let (g, sepsets) = self.par_skeleton();
-        // Cast the graph to a partially directed graph
-        let mut g: PDGraph = g.into();
-
-        // Create the set of unshielded triples (x, y, z) in which (x, z) is not d-separated by y
-        let triples: Vec<_> = V!(g)
-            .par_bridge()
-            .flat_map(|y| {
-                std::iter::repeat(y)
-                    .zip(Adj!(g, y).combinations(2))
-                    .map(|(y, xz)| (xz[0], y, xz[1]))
-                    .par_bridge()
-                    .filter(|&(x, y, z)| {
-                        !g.has_edge_by_index(x, z) && !sepsets[&(x, z)].contains(&y)
-                    })
-            })
-            .collect();
+        // Perform parallel skeleton discovery.
+        let skeleton = self.par_call_skeleton_with_sepsets();
 
-        // For every unshielded triple ...
-        for (x, y, z) in triples {
-            // ... if one of the edges is already directed ...
-            if !g.has_undirected_edge_by_index(x, y) || !g.has_undirected_edge_by_index(z, y) {
-                // ... skip this triple.
-                continue;
-            }
-            // Otherwise, the triple is a v-structure.
-            g.orient_edge(x, y);
-            g.orient_edge(z, y);
+        match self.tiers {
+            Some(tiers) => skeleton.par_orient_with_tiers(tiers),
+            None => skeleton.par_orient(),
         }
-
-        g
     }
 }
@@ -159,3 +159,176 @@ impl PriorKnowledge for ForbiddenRequired {
 
 /// Alias for the forbidden and required sets.
 pub type FR = ForbiddenRequired;
+
+/// Temporal tiers, i.e. a known temporal order over groups of variables.
+///
+/// Each variable is assigned to a tier, where lower tiers precede higher ones.
+/// Since edges can only go forward in time, any edge from a higher tier to
+/// a lower one is forbidden, while edges within the same tier are allowed.
+///
+/// The edges forbidden by the tiers cannot be deleted, while further forbidden
+/// and required edges can be added, e.g. merging a user-supplied prior knowledge.
+#[derive(Clone, Debug)]
+pub struct Tiers {
+    tiers: Vec<usize>,
+    prior_knowledge: ForbiddenRequired,
+}
+
+impl Tiers {
+    /// Constructor for the temporal tiers given the tier of each variable.
+    ///
+    /// # Panics
+    ///
+    /// The variables labels must be unique.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    ///
+    /// // Assign the variables to the tiers.
+    /// let k = Tiers::new([("B", 1), ("A", 0), ("C", 1)]);
+    ///
+    /// // Check that backward edges are forbidden.
+    /// assert_eq!(k.tier(0), 0);
+    /// assert!(k.has_forbidden(1, 0));
+    /// assert!(!k.has_forbidden(0, 1));
+    /// assert!(!k.has_forbidden(1, 2));
+    /// ```
+    ///
+    pub fn new<V, I>(tiers: I) -> Self
+    where
+        I: IntoIterator<Item = (V, usize)>,
+        V: Into<String>,
+    {
+        // Collect the tiers, sorted w.r.t. the variables labels.
+        let tiers = tiers
+            .into_iter()
+            .map(|(x, t)| (x.into(), t))
+            .sorted()
+            .collect_vec();
+        // Assert labels are unique.
+        assert!(
+            tiers.iter().map(|(x, _)| x).all_unique(),
+            "Labels must be unique"
+        );
+
+        // Compute the forbidden edges, i.e. backward in time.
+        let forbidden = tiers
+            .iter()
+            .cartesian_product(&tiers)
+            .filter(|((_, s), (_, t))| s > t)
+            .map(|((x, _), (y, _))| (x.as_str(), y.as_str()))
+            .collect_vec();
+        // Initialize the forbidden and required sets.
+        let prior_knowledge =
+            ForbiddenRequired::new(tiers.iter().map(|(x, _)| x.as_str()), forbidden, []);
+        // Get the tier of each variable.
+        let tiers = tiers.into_iter().map(|(_, t)| t).collect();
+
+        Self {
+            tiers,
+            prior_knowledge,
+        }
+    }
+
+    /// Merge a given prior knowledge into the temporal tiers.
+    ///
+    /// # Panics
+    ///
+    /// The prior knowledge must have the same variables of the tiers,
+    /// and its required edges must not go backward in time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    ///
+    /// // Set the user-supplied prior knowledge.
+    /// let fr = FR::new(["A", "B", "C"], [("B", "C")], [("A", "B")]);
+    /// // Merge it into the tiers.
+    /// let k = Tiers::new([("B", 1), ("A", 0), ("C", 1)]).with_prior_knowledge(&fr);
+    ///
+    /// // Check that both the tiers and the prior knowledge hold.
+    /// assert!(k.has_forbidden(1, 0));
+    /// assert!(k.has_forbidden(1, 2));
+    /// assert!(k.has_required(0, 1));
+    /// ```
+    ///
+    pub fn with_prior_knowledge(mut self, prior_knowledge: &ForbiddenRequired) -> Self {
+        // Assert tiers and prior knowledge have the same variables.
+        assert!(
+            self.labels().eq(prior_knowledge.labels()),
+            "Tiers and prior knowledge must have the same variables"
+        );
+        // Add the forbidden edges.
+        for &(x, y) in prior_knowledge.forbidden() {
+            self.add_forbidden(x, y);
+        }
+        // Add the required edges.
+        for &(x, y) in prior_knowledge.required() {
+            self.add_required(x, y);
+        }
+
+        self
+    }
+
+    /// Get the tier of a given variable.
+    #[inline]
+    pub fn tier(&self, x: usize) -> usize {
+        self.tiers[x]
+    }
+}
+
+impl PriorKnowledge for Tiers {
+    #[inline]
+    fn forbidden(&self) -> &FxIndexSet<(usize, usize)> {
+        self.prior_knowledge.forbidden()
+    }
+
+    #[inline]
+    fn has_forbidden(&self, x: usize, y: usize) -> bool {
+        self.prior_knowledge.has_forbidden(x, y)
+    }
+
+    #[inline]
+    fn add_forbidden(&mut self, x: usize, y: usize) -> bool {
+        self.prior_knowledge.add_forbidden(x, y)
+    }
+
+    #[inline]
+    fn del_forbidden(&mut self, x: usize, y: usize) -> bool {
+        // Check the edge is not forbidden by the tiers.
+        assert!(
+            self.tier(x) <= self.tier(y),
+            "Failed to delete edge from the forbidden set since it goes backward in time"
+        );
+
+        self.prior_knowledge.del_forbidden(x, y)
+    }
+
+    #[inline]
+    fn required(&self) -> &FxIndexSet<(usize, usize)> {
+        self.prior_knowledge.required()
+    }
+
+    #[inline]
+    fn has_required(&self, x: usize, y: usize) -> bool {
+        self.prior_knowledge.has_required(x, y)
+    }
+
+    #[inline]
+    fn add_required(&mut self, x: usize, y: usize) -> bool {
+        self.prior_knowledge.add_required(x, y)
+    }
+
+    #[inline]
+    fn del_required(&mut self, x: usize, y: usize) -> bool {
+        self.prior_knowledge.del_required(x, y)
+    }
+
+    #[inline]
+    fn labels(&self) -> &FxIndexSet<String> {
+        self.prior_knowledge.labels()
+    }
+}
@@ -33,6 +33,45 @@ pub trait ScoringCriterion<D, G, T>: Clone + Debug + Sync {
 }
 
 /// Decomposable scoring criterion trait.
+///
+/// A decomposable score is the sum of local scores $\mathcal{S}(X, Pa(X))$, hence score-based
+/// algorithms, e.g. hill-climbing, are generic over this trait: a custom score is plugged in
+/// by implementing the local score, while the global score and the delta helpers are derived.
+///
+/// # Examples
+///
+/// ```
+/// use causal_hub::{prelude::*, polars::prelude::*};
+///
+/// // Define a custom score, i.e. the BIC with an additional penalty for each parent.
+/// #[derive(Clone, Debug)]
+/// struct PenalizedBIC<'a>(BIC<'a, CategoricalDataMatrix>);
+///
+/// impl<'a> DecomposableScoringCriterion<CategoricalDataMatrix, DiGraph> for PenalizedBIC<'a> {
+///     fn call(&self, x: usize, z: &[usize]) -> f64 {
+///         DecomposableScoringCriterion::<_, DiGraph>::call(&self.0, x, z) - 10. * z.len() as f64
+///     }
+/// }
+///
+/// // Load data set from CSV file.
+/// let data_set = CsvReader::from_path("./tests/assets/asia.csv").unwrap().finish().unwrap();
+/// let data_set: CategoricalDataMatrix = data_set.into();
+/// // Initialize empty prior knowledge.
+/// let prior_knowledge = FR::new(data_set.labels_iter(), [], []);
+///
+/// // Initialize the custom scoring criterion.
+/// let scoring_criterion = PenalizedBIC(BIC::new(&data_set));
+///
+/// // Check the delta of adding a parent includes the additional penalty.
+/// let delta = scoring_criterion.delta_add(1, &[], 5);
+/// let bic = DecomposableScoringCriterion::<_, DiGraph>::delta_add(&scoring_criterion.0, 1, &[], 5);
+/// assert!((delta - (bic - 10.)).abs() < 1e-8);
+///
+/// // Perform discovery with the custom scoring criterion.
+/// let pred_graph: DiGraph = HC::new(&scoring_criterion)
+///     .call(&data_set, &prior_knowledge);
+/// ```
+///
 pub trait DecomposableScoringCriterion<D, G>: Clone + Debug + Sync {
     /// Computes the score value for the given data set $\mathbf{D}$, vertex $X$ and parents $\mathbf{Z}$.
     fn call(&self, x: usize, z: &[usize]) -> f64;
@@ -42,6 +81,52 @@ pub trait DecomposableScoringCriterion<D, G>: Clone + Debug + Sync {
     fn max_in_degree_hint(&self) -> Option<usize> {
         None
     }
+
+    /// Computes the score delta of adding $Y$ to the parents $\mathbf{Z}$ of $X$.
+    ///
+    /// # Panics
+    ///
+    /// The parents $\mathbf{Z}$ must be sorted and must not contain $Y$.
+    ///
+    fn delta_add(&self, x: usize, z: &[usize], y: usize) -> f64 {
+        // Add Y in-place by leveraging Z order.
+        let i = z
+            .binary_search(&y)
+            .expect_err("Parents must not contain the added vertex");
+        let mut z_star = z.to_vec();
+        z_star.insert(i, y);
+
+        self.call(x, &z_star) - self.call(x, z)
+    }
+
+    /// Computes the score delta of removing $Y$ from the parents $\mathbf{Z}$ of $X$.
+    ///
+    /// # Panics
+    ///
+    /// The parents $\mathbf{Z}$ must be sorted and must contain $Y$.
+    ///
+    fn delta_del(&self, x: usize, z: &[usize], y: usize) -> f64 {
+        // Remove Y in-place by leveraging Z order.
+        let i = z
+            .binary_search(&y)
+            .expect("Parents must contain the removed vertex");
+        let mut z_star = z.to_vec();
+        z_star.remove(i);
+
+        self.call(x, &z_star) - self.call(x, z)
+    }
+
+    /// Computes the score delta of reversing $X \rightarrow Y$, given the parents
+    /// $\mathbf{Z}_X$ of $X$ and $\mathbf{Z}_Y$ of $Y$.
+    ///
+    /// # Panics
+    ///
+    /// The parents must be sorted, with $X \in \mathbf{Z}_Y$ and $Y \notin \mathbf{Z}_X$.
+    ///
+    #[inline]
+    fn delta_rev(&self, x: usize, z_x: &[usize], y: usize, z_y: &[usize]) -> f64 {
+        self.delta_add(x, z_x, y) + self.delta_del(y, z_y, x)
+    }
 }
 
 /* Blanket implementation for Decomposable Scoring Criterion */
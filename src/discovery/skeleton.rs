@@ -0,0 +1,250 @@
+use std::cmp::Ordering;
+
+use itertools::Itertools;
+use rayon::prelude::*;
+
+use crate::prelude::*;
+
+/// Conditional independence statistics type, i.e. degrees of freedom, statistic and p-value.
+pub type CIStatistics = FxIndexMap<(usize, usize), (usize, f64, f64)>;
+
+/// Skeleton struct, i.e. an undirected graph with its separation sets.
+///
+/// It represents the intermediate output of constraint-based causal discovery,
+/// e.g. PC-Stable, which can be inspected and oriented by different strategies.
+#[derive(Clone, Debug)]
+pub struct Skeleton {
+    graph: Graph,
+    sepsets: SepSets,
+    statistics: Option<CIStatistics>,
+}
+
+impl Skeleton {
+    /// Construct a new skeleton given an undirected graph and its separation sets.
+    ///
+    /// # Panics
+    ///
+    /// If any separation set refers to a couple of adjacent vertices.
+    ///
+    pub fn new(graph: Graph, sepsets: SepSets) -> Self {
+        // Assert separation sets refer to non-adjacent vertices.
+        assert!(
+            sepsets
+                .keys()
+                .all(|&(x, y)| !graph.is_adjacent_by_index(x, y)),
+            "Separation sets must refer to non-adjacent vertices"
+        );
+
+        Self {
+            graph,
+            sepsets,
+            statistics: None,
+        }
+    }
+
+    /// Set the conditional independence statistics of the removed edges.
+    ///
+    /// # Panics
+    ///
+    /// If any statistic refers to a couple of adjacent vertices.
+    ///
+    pub fn with_statistics(mut self, statistics: CIStatistics) -> Self {
+        // Assert statistics refer to non-adjacent vertices.
+        assert!(
+            statistics
+                .keys()
+                .all(|&(x, y)| !self.graph.is_adjacent_by_index(x, y)),
+            "Statistics must refer to non-adjacent vertices"
+        );
+        // Set statistics.
+        self.statistics = Some(statistics);
+
+        self
+    }
+
+    /// Returns the underlying undirected graph.
+    #[inline]
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+
+    /// Returns the separation sets.
+    #[inline]
+    pub fn sepsets(&self) -> &SepSets {
+        &self.sepsets
+    }
+
+    /// Returns the separation set of a given couple of vertices, if any.
+    #[inline]
+    pub fn sepset(&self, x: usize, y: usize) -> Option<&FxIndexSet<usize>> {
+        self.sepsets.get(&(x, y))
+    }
+
+    /// Returns the conditional independence statistics, if any.
+    #[inline]
+    pub fn statistics(&self) -> Option<&CIStatistics> {
+        self.statistics.as_ref()
+    }
+
+    /// Checks whether a given couple of vertices are adjacent.
+    #[inline]
+    pub fn is_adjacent_by_index(&self, x: usize, y: usize) -> bool {
+        self.graph.is_adjacent_by_index(x, y)
+    }
+
+    /// Private function. It checks whether the unshielded triple $(X, Y, Z)$ is a v-structure.
+    #[inline]
+    fn is_v_structure(&self, x: usize, y: usize, z: usize) -> bool {
+        !self.graph.has_edge_by_index(x, z) && !self.sepsets[&(x, z)].contains(&y)
+    }
+
+    /// Private function. It orients the given v-structures, skipping conflicting ones.
+    #[inline]
+    fn orient_triples(&self, triples: Vec<(usize, usize, usize)>) -> PDGraph {
+        // Cast the graph to a partially directed graph
+        let mut g: PDGraph = self.graph.clone().into();
+
+        // For every unshielded triple ...
+        for (x, y, z) in triples {
+            // ... if one of the edges is already directed ...
+            if !g.has_undirected_edge_by_index(x, y) || !g.has_undirected_edge_by_index(z, y) {
+                // ... skip this triple.
+                continue;
+            }
+            // Otherwise, the triple is a v-structure.
+            g.orient_edge(x, y);
+            g.orient_edge(z, y);
+        }
+
+        g
+    }
+
+    /// Private function. It orients the given v-structures, skipping conflicting ones and
+    /// the ones pointing backward in time, then the remaining cross-tier edges forward in time.
+    fn orient_triples_with_tiers(
+        &self,
+        tiers: &Tiers,
+        triples: Vec<(usize, usize, usize)>,
+    ) -> PDGraph {
+        // Assert tiers and skeleton have the same variables.
+        assert!(
+            tiers.labels().iter().eq(L!(self.graph)),
+            "Tiers and skeleton must have the same variables"
+        );
+
+        // Cast the graph to a partially directed graph
+        let mut g: PDGraph = self.graph.clone().into();
+
+        // For every unshielded triple ...
+        for (x, y, z) in triples {
+            // ... if one of the edges is already directed ...
+            if !g.has_undirected_edge_by_index(x, y) || !g.has_undirected_edge_by_index(z, y) {
+                // ... skip this triple.
+                continue;
+            }
+            // ... if one of the edges would point backward in time ...
+            if tiers.tier(x) > tiers.tier(y) || tiers.tier(z) > tiers.tier(y) {
+                // ... skip this triple.
+                continue;
+            }
+            // Otherwise, the triple is a v-structure.
+            g.orient_edge(x, y);
+            g.orient_edge(z, y);
+        }
+
+        // For every edge ...
+        for (x, y) in E!(self.graph) {
+            // ... orient it forward in time, if across tiers and still undirected.
+            match tiers.tier(x).cmp(&tiers.tier(y)) {
+                Ordering::Less => g.orient_edge(x, y),
+                Ordering::Greater => g.orient_edge(y, x),
+                Ordering::Equal => false,
+            };
+        }
+
+        g
+    }
+
+    /// Private function. It collects the unshielded triples $(X, Y, Z)$ that are v-structures.
+    fn triples(&self) -> Vec<(usize, usize, usize)> {
+        // Create the set of unshielded triples (x, y, z) in which (x, z) is not d-separated by y
+        V!(self.graph)
+            .flat_map(|y| {
+                std::iter::repeat(y)
+                    .zip(Adj!(self.graph, y).combinations(2))
+                    .map(|(y, xz)| (xz[0], y, xz[1]))
+                    .filter(|&(x, y, z)| self.is_v_structure(x, y, z))
+            })
+            .collect()
+    }
+
+    /// Private function. It collects the unshielded triples $(X, Y, Z)$ that are v-structures in parallel.
+    fn par_triples(&self) -> Vec<(usize, usize, usize)> {
+        // Create the set of unshielded triples (x, y, z) in which (x, z) is not d-separated by y
+        V!(self.graph)
+            .par_bridge()
+            .flat_map(|y| {
+                std::iter::repeat(y)
+                    .zip(Adj!(self.graph, y).combinations(2))
+                    .map(|(y, xz)| (xz[0], y, xz[1]))
+                    .par_bridge()
+                    .filter(|&(x, y, z)| self.is_v_structure(x, y, z))
+            })
+            .collect()
+    }
+
+    /// Orient the v-structures leveraging the separation sets.
+    ///
+    /// The resulting partially directed graph can be further oriented, e.g. with Meek rules.
+    ///
+    #[inline]
+    pub fn orient(&self) -> PDGraph {
+        self.orient_triples(self.triples())
+    }
+
+    /// Orient the v-structures leveraging the separation sets in parallel.
+    ///
+    /// The resulting partially directed graph can be further oriented, e.g. with Meek rules.
+    ///
+    #[inline]
+    pub fn par_orient(&self) -> PDGraph {
+        self.orient_triples(self.par_triples())
+    }
+
+    /// Orient the v-structures leveraging the separation sets, skipping the ones that
+    /// would point backward in time, then the remaining cross-tier edges forward in time.
+    ///
+    /// # Panics
+    ///
+    /// The tiers must have the same variables of the skeleton.
+    ///
+    #[inline]
+    pub fn orient_with_tiers(&self, tiers: &Tiers) -> PDGraph {
+        self.orient_triples_with_tiers(tiers, self.triples())
+    }
+
+    /// Orient the v-structures leveraging the separation sets in parallel, skipping the ones
+    /// that would point backward in time, then the remaining cross-tier edges forward in time.
+    ///
+    /// # Panics
+    ///
+    /// The tiers must have the same variables of the skeleton.
+    ///
+    #[inline]
+    pub fn par_orient_with_tiers(&self, tiers: &Tiers) -> PDGraph {
+        self.orient_triples_with_tiers(tiers, self.par_triples())
+    }
+
+    /// Decompose the skeleton into its undirected graph, separation sets and statistics.
+    #[inline]
+    pub fn into_parts(self) -> (Graph, SepSets, Option<CIStatistics>) {
+        (self.graph, self.sepsets, self.statistics)
+    }
+}
+
+impl From<Skeleton> for Graph {
+    #[inline]
+    fn from(skeleton: Skeleton) -> Self {
+        skeleton.graph
+    }
+}
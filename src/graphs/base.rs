@@ -425,6 +425,50 @@ pub trait BaseGraph:
     ///
     fn size(&self) -> usize;
 
+    /// Density of the graph.
+    ///
+    /// Return the ratio between the graph size and the maximum number of edges
+    /// between distinct vertices, e.g. $\frac{2 |\mathbf{E}|}{|\mathbf{V}| (|\mathbf{V}| - 1)}$
+    /// for undirected graphs and $\frac{|\mathbf{E}|}{|\mathbf{V}| (|\mathbf{V}| - 1)}$ for
+    /// directed graphs. Graphs with less than two vertices have zero density.
+    ///
+    /// The default implementation counts unordered pairs of vertices, therefore
+    /// directed graphs must override it to count ordered pairs instead.
+    ///
+    /// # Note
+    ///
+    /// Self-loops are counted in the graph size but not in the maximum number of edges,
+    /// hence the density of a graph with self-loops can be greater than one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    ///
+    /// // Build a new graph.
+    /// let g = Graph::new(["A", "B", "C"], [("A", "B"), ("B", "C")]);
+    /// assert_eq!(g.density(), 2. / 3.);
+    ///
+    /// // Build a new directed graph.
+    /// let g = DiGraph::new(["A", "B", "C"], [("A", "B"), ("B", "C")]);
+    /// assert_eq!(g.density(), 1. / 3.);
+    /// ```
+    ///
+    #[inline]
+    fn density(&self) -> f64 {
+        // Get the order of the graph.
+        let n = self.order();
+        // Check if there are at least two vertices.
+        if n < 2 {
+            return 0.;
+        }
+
+        // Compute the maximum number of edges, i.e. unordered pairs.
+        let m = n * (n - 1) / 2;
+
+        self.size() as f64 / m as f64
+    }
+
     /// Edge iterator.
     ///
     /// Iterates over the edge set $\mathbf{E}$ order by identifier values.
@@ -1,5 +1,7 @@
 use std::iter::FusedIterator;
 
+use itertools::Itertools;
+
 use super::{BaseGraph, PartialOrdGraph, SubGraph};
 
 /// Directions pseudo-enumerator for generics algorithms.
@@ -215,6 +217,17 @@ macro_rules! De {
     };
 }
 
+/// Markov blanket vertex set.
+///
+/// Return the sorted vertex set representing $Mb(\mathcal{G}, X)$.
+///
+#[macro_export]
+macro_rules! Mb {
+    ($g:expr, $x:expr) => {
+        $g.get_markov_blanket_by_index($x)
+    };
+}
+
 /// Directed graph trait.
 pub trait DirectedGraph: BaseGraph + PartialOrdGraph + SubGraph {
     /// Edges iterator type.
@@ -498,6 +511,46 @@ pub trait DirectedGraph: BaseGraph + PartialOrdGraph + SubGraph {
         De!(self, x).any(|z| z == y)
     }
 
+    /// Markov blanket vertex set.
+    ///
+    /// Returns the sorted vertex set $Mb(\mathcal{G}, X)$ of a given vertex $X$,
+    /// i.e. the parents, the children and the parents of the children of $X$.
+    ///
+    /// # Panics
+    ///
+    /// The vertex label does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    ///
+    /// // Define edge set.
+    /// let e = EdgeList::from([("A", "B"), ("C", "B"), ("B", "D"), ("E", "D"), ("D", "F")]);
+    ///
+    /// // Build a graph.
+    /// let g = DiGraph::from(e);
+    ///
+    /// // Choose vertex.
+    /// let x = g.get_vertex_index("B");
+    ///
+    /// // Get the Markov blanket.
+    /// assert_eq!(g.get_markov_blanket_by_index(x), [0, 2, 3, 4]);
+    ///
+    /// // Use the associated macro 'Mb!'.
+    /// assert_eq!(g.get_markov_blanket_by_index(x), Mb!(g, x));
+    /// ```
+    ///
+    fn get_markov_blanket_by_index(&self, x: usize) -> Vec<usize> {
+        Pa!(self, x)
+            .chain(Ch!(self, x))
+            .chain(Ch!(self, x).flat_map(|y| Pa!(self, y)))
+            .filter(|&z| z != x)
+            .sorted()
+            .dedup()
+            .collect()
+    }
+
     /// Checks whether the graph has a given directed edge or not.
     fn has_directed_edge_by_index(&self, x: usize, y: usize) -> bool {
         dE!(self).any(|z| z == (x, y))
@@ -642,4 +695,12 @@ pub trait MeekRules: PartiallyDirectedGraph {
     fn meek_procedure_until_3(self) -> Self;
     /// Meek's procedure until Meek's rule 4
     fn meek_procedure_until_4(self) -> Self;
+    /// Parallel Meek's procedure until Meek's rule 3, defaults to the serial procedure
+    fn par_meek_procedure_until_3(self) -> Self {
+        self.meek_procedure_until_3()
+    }
+    /// Parallel Meek's procedure until Meek's rule 4, defaults to the serial procedure
+    fn par_meek_procedure_until_4(self) -> Self {
+        self.meek_procedure_until_4()
+    }
 }
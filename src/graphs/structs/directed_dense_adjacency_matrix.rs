@@ -9,9 +9,10 @@ use std::{
 use is_sorted::IsSorted;
 use itertools::{iproduct, Itertools};
 use ndarray::{iter::IndexedIter, prelude::*, OwnedRepr};
+use rand::{distributions::WeightedIndex, prelude::*};
 use serde::{Deserialize, Serialize};
 
-use super::UndirectedDenseAdjacencyMatrixGraph;
+use super::{check_vertex_index, UndirectedDenseAdjacencyMatrixGraph};
 use crate::{
     graphs::{
         algorithms::traversal::{DFSEdge, DFSEdges, Traversal},
@@ -179,6 +180,265 @@ impl Default for DirectedDenseAdjacencyMatrixGraph {
     }
 }
 
+impl DirectedDenseAdjacencyMatrixGraph {
+    /// Erdős-Rényi random directed acyclic graph constructor.
+    ///
+    /// Draws a random topological order over the given vertices, then adds
+    /// each edge consistent with such order with probability `p`. If a maximum
+    /// in-degree is given, the parents of each vertex are uniformly subsampled
+    /// to satisfy it.
+    ///
+    /// # Panics
+    ///
+    /// The edge probability must be in $[0, 1]$.
+    pub fn random_dag<V, I, R>(
+        vertices: I,
+        p: f64,
+        max_in_degree: Option<usize>,
+        rng: &mut R,
+    ) -> Self
+    where
+        V: Into<String>,
+        I: IntoIterator<Item = V>,
+        R: Rng,
+    {
+        // Assert edge probability is in [0, 1].
+        assert!(
+            (0. ..=1.).contains(&p),
+            "Edge probability must be in [0, 1]"
+        );
+
+        // Initialize an empty graph.
+        let mut g = Self::empty(vertices);
+        // Draw a random topological order.
+        let mut order = V!(g).collect_vec();
+        order.shuffle(rng);
+
+        // For each vertex in the topological order ...
+        for (i, &y) in order.iter().enumerate() {
+            // Select the parents among the preceding vertices.
+            let mut pa_y = order[..i]
+                .iter()
+                .copied()
+                .filter(|_| rng.gen_bool(p))
+                .collect_vec();
+            // Subsample the parents, if the maximum in-degree is exceeded.
+            if let Some(k) = max_in_degree.filter(|&k| pa_y.len() > k) {
+                pa_y = pa_y.choose_multiple(rng, k).copied().collect();
+            }
+            // Add the edges.
+            for x in pa_y {
+                g.add_edge_by_index(x, y);
+            }
+        }
+
+        // Assert graph is acyclic.
+        debug_assert!(g.is_acyclic());
+
+        g
+    }
+
+    /// Scale-free random directed acyclic graph constructor.
+    ///
+    /// Draws a random topological order over the given vertices, then attaches
+    /// each vertex to `m` preceding vertices by preferential attachment, i.e.
+    /// with probability proportional to their degree plus one. Edges are oriented
+    /// w.r.t. the topological order, hence the in-degree is at most `m`.
+    pub fn random_scale_free<V, I, R>(vertices: I, m: usize, rng: &mut R) -> Self
+    where
+        V: Into<String>,
+        I: IntoIterator<Item = V>,
+        R: Rng,
+    {
+        // Initialize an empty graph.
+        let mut g = Self::empty(vertices);
+        // Draw a random topological order.
+        let mut order = V!(g).collect_vec();
+        order.shuffle(rng);
+
+        // Initialize the degree of each vertex.
+        let mut degree = vec![0; order.len()];
+        // For each vertex in the topological order ...
+        for (i, &y) in order.iter().enumerate() {
+            // Compute the attachment weights of the preceding vertices.
+            let mut weights = order[..i].iter().map(|&x| degree[x] + 1).collect_vec();
+            // Attach to the preceding vertices without replacement.
+            for _ in 0..usize::min(m, i) {
+                // Select a preceding vertex.
+                let j = WeightedIndex::new(&weights)
+                    .expect("Failed to initialize weighted index")
+                    .sample(rng);
+                // Exclude it from further selection.
+                weights[j] = 0;
+                // Add the edge.
+                let x = order[j];
+                g.add_edge_by_index(x, y);
+                // Update the degrees.
+                degree[x] += 1;
+                degree[y] += 1;
+            }
+        }
+
+        // Assert graph is acyclic.
+        debug_assert!(g.is_acyclic());
+
+        g
+    }
+
+    /// Reversed graph, i.e. the graph with each edge $X \rightarrow Y$ flipped into $X \leftarrow Y$.
+    ///
+    /// The vertices labels and indices are the same of the original graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    ///
+    /// // Build a new directed graph.
+    /// let g = DiGraph::new(["A", "B", "C"], [("A", "B"), ("B", "C")]);
+    /// // Reverse the graph.
+    /// let h = g.reversed();
+    ///
+    /// // Check edges are flipped.
+    /// assert!(h.has_edge_by_index(1, 0));
+    /// assert!(h.has_edge_by_index(2, 1));
+    /// assert_eq!(g.size(), h.size());
+    /// // Check reversing twice gives the original graph.
+    /// assert_eq!(h.reversed(), g);
+    /// ```
+    ///
+    pub fn reversed(&self) -> Self {
+        Self {
+            labels: self.labels.clone(),
+            // Transpose the adjacency matrix.
+            adjacency_matrix: self.adjacency_matrix.t().as_standard_layout().into_owned(),
+            size: self.size,
+        }
+    }
+
+    /// Canonical edge set, i.e. the sorted set of edges given by vertices labels.
+    ///
+    /// The edge set is independent of the vertices indices, hence two graphs
+    /// with the same structure have the same canonical edge set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    ///
+    /// // Build a new directed graph.
+    /// let g = DiGraph::new(["A", "B", "C"], [("B", "C"), ("A", "B")]);
+    ///
+    /// // Check the canonical edge set.
+    /// assert_eq!(
+    ///     g.canonical_edge_set(),
+    ///     EdgeList::from([
+    ///         ("A".to_string(), "B".to_string()),
+    ///         ("B".to_string(), "C".to_string())
+    ///     ])
+    /// );
+    /// ```
+    ///
+    pub fn canonical_edge_set(&self) -> EdgeList<String> {
+        // Map each edge to its vertices labels.
+        E!(self)
+            .map(|(x, y)| {
+                (
+                    self.get_vertex_by_index(x).into(),
+                    self.get_vertex_by_index(y).into(),
+                )
+            })
+            .collect()
+    }
+
+    /// Find a directed cycle, if any, as the sequence of its vertices indices.
+    ///
+    /// The cycle is given by a back edge of a depth-first search, hence the last
+    /// vertex of the sequence is a parent of the first one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    ///
+    /// // Build a new directed graph.
+    /// let g = DiGraph::new(["A", "B", "C"], [("A", "B"), ("B", "C"), ("C", "B")]);
+    ///
+    /// // Check the cycle is found.
+    /// assert_eq!(g.find_cycle(), Some(vec![1, 2]));
+    /// ```
+    ///
+    pub fn find_cycle(&self) -> Option<Vec<usize>> {
+        // Initialize the visit state, i.e. unvisited, on the current path or finished.
+        let mut state = vec![0_u8; self.order()];
+        // For each unvisited vertex ...
+        for x in V!(self) {
+            if state[x] != 0 {
+                continue;
+            }
+            // ... initialize the current path with the children to be visited ...
+            state[x] = 1;
+            let mut path = vec![(x, Ch!(self, x).collect_vec(), 0)];
+            // ... and visit in depth-first order.
+            while let Some((y, ch, i)) = path.last_mut() {
+                // Get the next child, if any.
+                let (y, z) = (*y, ch.get(*i).copied());
+                *i += 1;
+                match z {
+                    // If the child is on the current path, then there is a back edge.
+                    Some(z) if state[z] == 1 => {
+                        // Get the position of the child in the current path.
+                        let j = path.iter().position(|&(w, _, _)| w == z).unwrap();
+
+                        return Some(path[j..].iter().map(|&(w, _, _)| w).collect());
+                    }
+                    // If the child is unvisited, then extend the current path.
+                    Some(z) if state[z] == 0 => {
+                        state[z] = 1;
+                        path.push((z, Ch!(self, z).collect_vec(), 0));
+                    }
+                    // If the child is finished, then skip it.
+                    Some(_) => {}
+                    // If there are no more children, then the vertex is finished.
+                    None => {
+                        state[y] = 2;
+                        path.pop();
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Assert the graph is acyclic, i.e. it is a Directed Acyclic Graph (DAG).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graph contains a cycle, reporting its vertices labels.
+    ///
+    #[inline]
+    pub fn assert_acyclic(&self) {
+        // Check if there is a cycle.
+        if let Some(cycle) = self.find_cycle() {
+            panic!(
+                "Graph must be acyclic, found cycle: {}",
+                self.format_cycle(&cycle)
+            );
+        }
+    }
+
+    /// Private function. It formats a cycle by its vertices labels, closing it on the
+    /// first one, e.g. `A -> B -> A`.
+    pub(crate) fn format_cycle(&self, cycle: &[usize]) -> String {
+        cycle
+            .iter()
+            .chain(&cycle[..1])
+            .map(|&x| self.get_vertex_by_index(x))
+            .join(" -> ")
+    }
+}
+
 impl BaseGraph for DirectedDenseAdjacencyMatrixGraph {
     type Data = DenseAdjacencyMatrix;
 
@@ -340,9 +600,12 @@ impl BaseGraph for DirectedDenseAdjacencyMatrixGraph {
 
     #[inline]
     fn get_vertex_by_index(&self, x: usize) -> &str {
-        self.labels
-            .get_index(x)
-            .unwrap_or_else(|| panic!("No vertex with label `{x}`"))
+        self.labels.get_index(x).unwrap_or_else(|| {
+            panic!(
+                "No vertex with identifier `{x}` in a graph of order `{}`",
+                self.labels.len()
+            )
+        })
     }
 
     fn add_vertex<V>(&mut self, x: V) -> usize
@@ -467,6 +730,21 @@ impl BaseGraph for DirectedDenseAdjacencyMatrixGraph {
         self.size
     }
 
+    #[inline]
+    fn density(&self) -> f64 {
+        // Get the order of the graph.
+        let n = self.order();
+        // Check if there are at least two vertices.
+        if n < 2 {
+            return 0.;
+        }
+
+        // Compute the maximum number of edges, i.e. ordered pairs.
+        let m = n * (n - 1);
+
+        self.size() as f64 / m as f64
+    }
+
     #[inline]
     fn get_edges_index(&self) -> Self::EdgesIndexIter<'_> {
         Self::EdgesIndexIter::new(self)
@@ -474,11 +752,19 @@ impl BaseGraph for DirectedDenseAdjacencyMatrixGraph {
 
     #[inline]
     fn has_edge_by_index(&self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         self.adjacency_matrix[[x, y]]
     }
 
     #[inline]
     fn add_edge_by_index(&mut self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         // If edge already exists ...
         if self.adjacency_matrix[[x, y]] {
             // ... return early.
@@ -498,6 +784,10 @@ impl BaseGraph for DirectedDenseAdjacencyMatrixGraph {
 
     #[inline]
     fn del_edge_by_index(&mut self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         // If edge does not exists ...
         if !self.adjacency_matrix[[x, y]] {
             // ... return early.
@@ -517,11 +807,18 @@ impl BaseGraph for DirectedDenseAdjacencyMatrixGraph {
 
     #[inline]
     fn get_adjacents_index(&self, x: usize) -> Self::AdjacentsIndexIter<'_> {
+        // Check vertex index.
+        check_vertex_index(x, self.order());
+
         Self::AdjacentsIndexIter::new(self, x)
     }
 
     #[inline]
     fn is_adjacent_by_index(&self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         // Check using has_edge.
         let f = self.has_edge_by_index(x, y) || self.has_edge_by_index(y, x);
 
@@ -605,14 +902,7 @@ where
 #[allow(clippy::from_over_into)]
 impl Into<EdgeList<String>> for DirectedDenseAdjacencyMatrixGraph {
     fn into(self) -> EdgeList<String> {
-        E!(self)
-            .map(|(x, y)| {
-                (
-                    self.get_vertex_by_index(x).into(),
-                    self.get_vertex_by_index(y).into(),
-                )
-            })
-            .collect()
+        self.canonical_edge_set()
     }
 }
 
@@ -1020,41 +1310,68 @@ impl DirectedGraph for DirectedDenseAdjacencyMatrixGraph {
 
     #[inline]
     fn get_ancestors_by_index(&self, x: usize) -> Self::AncestorsIndexIter<'_> {
+        // Check vertex index.
+        check_vertex_index(x, self.order());
+
         Self::AncestorsIndexIter::new(self, x)
     }
 
     #[inline]
     fn get_parents_by_index(&self, x: usize) -> Self::ParentsIndexIter<'_> {
+        // Check vertex index.
+        check_vertex_index(x, self.order());
+
         Self::ParentsIndexIter::new(self, x)
     }
 
     #[inline]
     fn is_parent_by_index(&self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         self.adjacency_matrix[[y, x]]
     }
 
     #[inline]
     fn get_children_by_index(&self, x: usize) -> Self::ChildrenIndexIter<'_> {
+        // Check vertex index.
+        check_vertex_index(x, self.order());
+
         Self::ChildrenIndexIter::new(self, x)
     }
 
     #[inline]
     fn is_child_by_index(&self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         self.adjacency_matrix[[x, y]]
     }
 
     #[inline]
     fn get_descendants_by_index(&self, x: usize) -> Self::DescendantsIndexIter<'_> {
+        // Check vertex index.
+        check_vertex_index(x, self.order());
+
         Self::DescendantsIndexIter::new(self, x)
     }
 
     #[inline]
     fn has_directed_edge_by_index(&self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         self.adjacency_matrix[[x, y]]
     }
 
     #[inline]
     fn get_in_degree_by_index(&self, x: usize) -> usize {
+        // Check vertex index.
+        check_vertex_index(x, self.order());
+
         // Compute in-degree.
         let d = self.adjacency_matrix.column(x).mapv(|f| f as usize).sum();
 
@@ -1066,6 +1383,9 @@ impl DirectedGraph for DirectedDenseAdjacencyMatrixGraph {
 
     #[inline]
     fn get_out_degree_by_index(&self, x: usize) -> usize {
+        // Check vertex index.
+        check_vertex_index(x, self.order());
+
         // Compute out-degree.
         let d = self.adjacency_matrix.row(x).mapv(|f| f as usize).sum();
 
@@ -1077,6 +1397,10 @@ impl DirectedGraph for DirectedDenseAdjacencyMatrixGraph {
 
     #[inline]
     fn add_directed_edge_by_index(&mut self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         self.add_edge_by_index(x, y)
     }
 }
@@ -1085,6 +1409,10 @@ impl DirectedGraph for DirectedDenseAdjacencyMatrixGraph {
 impl PathGraph for DirectedDenseAdjacencyMatrixGraph {
     #[inline]
     fn has_path_by_index(&self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         self.has_edge_by_index(x, y) || BFS::from((self, x)).skip(1).any(|z| z == y)
     }
 
@@ -1,3 +1,18 @@
+/// Assert a vertex index is in range for a graph of given order.
+///
+/// The check is performed in debug builds, or with the `checked-indices` feature enabled,
+/// catching indices that are out of range, e.g. indices of a larger graph.
+///
+#[inline]
+pub(crate) fn check_vertex_index(x: usize, order: usize) {
+    if cfg!(any(debug_assertions, feature = "checked-indices")) {
+        assert!(
+            x < order,
+            "No vertex with identifier `{x}` in a graph of order `{order}`"
+        );
+    }
+}
+
 mod directed_dense_adjacency_matrix;
 pub use directed_dense_adjacency_matrix::DirectedDenseAdjacencyMatrixGraph;
 
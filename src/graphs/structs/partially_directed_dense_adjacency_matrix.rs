@@ -9,9 +9,12 @@ use std::{
 use is_sorted::IsSorted;
 use itertools::{iproduct, Itertools};
 use ndarray::{iter::IndexedIter, prelude::*, OwnedRepr};
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use super::{DirectedDenseAdjacencyMatrixGraph, UndirectedDenseAdjacencyMatrixGraph};
+use super::{
+    check_vertex_index, DirectedDenseAdjacencyMatrixGraph, UndirectedDenseAdjacencyMatrixGraph,
+};
 use crate::{
     dE,
     graphs::{
@@ -38,6 +41,63 @@ pub struct PartiallyDenseAdjacencyMatrixGraph {
     size: usize,
 }
 
+impl PartiallyDenseAdjacencyMatrixGraph {
+    /// Construct a partially directed graph from its marked adjacency matrix.
+    ///
+    /// See [`Self::to_marked_matrix`] for the marks encoding.
+    ///
+    /// # Panics
+    ///
+    /// The marked matrix must be square, consistent with the vertices and
+    /// must encode each edge with a consistent pair of marks.
+    pub fn from_marked_matrix<V, I>(vertices: I, marked_matrix: Array2<i8>) -> Self
+    where
+        V: Into<String>,
+        I: IntoIterator<Item = V>,
+    {
+        // Check if marked matrix is not square.
+        assert!(marked_matrix.is_square(), "Matrix must be square");
+        // Check if marks are consistent.
+        assert!(
+            marked_matrix.indexed_iter().all(|((i, j), &m)| match m {
+                0 | 2 => marked_matrix[[j, i]] == m,
+                1 | -1 => marked_matrix[[j, i]] == -m,
+                _ => false,
+            }),
+            "Matrix must encode edges with consistent marks"
+        );
+
+        // Decode undirected edges.
+        let undirected_adjacency_matrix = marked_matrix.mapv(|m| m == 2);
+        // Decode directed edges.
+        let directed_adjacency_matrix = marked_matrix.mapv(|m| m == 1);
+
+        Self::from((
+            vertices,
+            undirected_adjacency_matrix,
+            directed_adjacency_matrix,
+        ))
+    }
+
+    /// Returns the marked adjacency matrix.
+    ///
+    /// For each pair of vertices $(X, Y)$, the marks are encoded as:
+    ///
+    /// * $0$, if $X$ and $Y$ are not adjacent,
+    /// * $1$, if $X \rightarrow Y$,
+    /// * $-1$, if $X \leftarrow Y$,
+    /// * $2$, if $X - Y$.
+    pub fn to_marked_matrix(&self) -> Array2<i8> {
+        // Encode undirected edges.
+        let mut marked_matrix = self.undirected_adjacency_matrix.mapv(|f| 2 * f as i8);
+        // Encode directed edges.
+        marked_matrix += &self.directed_adjacency_matrix.mapv(|f| f as i8);
+        marked_matrix -= &self.directed_adjacency_matrix.t().mapv(|f| f as i8);
+
+        marked_matrix
+    }
+}
+
 /* Implement BaseGraph trait. */
 
 #[allow(dead_code, clippy::type_complexity)]
@@ -426,9 +486,12 @@ impl BaseGraph for PartiallyDenseAdjacencyMatrixGraph {
 
     #[inline]
     fn get_vertex_by_index(&self, x: usize) -> &str {
-        self.labels
-            .get_index(x)
-            .unwrap_or_else(|| panic!("No vertex with label `{x}`"))
+        self.labels.get_index(x).unwrap_or_else(|| {
+            panic!(
+                "No vertex with identifier `{x}` in a graph of order `{}`",
+                self.labels.len()
+            )
+        })
     }
 
     fn add_vertex<V>(&mut self, x: V) -> usize
@@ -625,11 +688,19 @@ impl BaseGraph for PartiallyDenseAdjacencyMatrixGraph {
 
     #[inline]
     fn has_edge_by_index(&self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         self.adjacency_matrix[[x, y]]
     }
 
     #[inline]
     fn add_edge_by_index(&mut self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         // If edge already exists ...
         if self.adjacency_matrix[[x, y]] {
             // ... return early.
@@ -694,6 +765,10 @@ impl BaseGraph for PartiallyDenseAdjacencyMatrixGraph {
 
     #[inline]
     fn del_edge_by_index(&mut self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         if !self.has_edge_by_index(x, y) {
             return false;
         }
@@ -774,11 +849,18 @@ impl BaseGraph for PartiallyDenseAdjacencyMatrixGraph {
 
     #[inline]
     fn get_adjacents_index(&self, x: usize) -> Self::AdjacentsIndexIter<'_> {
+        // Check vertex index.
+        check_vertex_index(x, self.order());
+
         Self::AdjacentsIndexIter::new(self, x)
     }
 
     #[inline]
     fn is_adjacent_by_index(&self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         // Check using has_edge.
         let f = self.has_edge_by_index(x, y);
 
@@ -1403,21 +1485,35 @@ impl UndirectedGraph for PartiallyDenseAdjacencyMatrixGraph {
 
     #[inline]
     fn get_neighbors_by_index(&self, x: usize) -> Self::NeighborsIndexIter<'_> {
+        // Check vertex index.
+        check_vertex_index(x, self.order());
+
         Self::NeighborsIndexIter::new_undirected(self, x)
     }
 
     #[inline]
     fn is_neighbor_by_index(&self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         self.undirected_adjacency_matrix[[x, y]]
     }
 
     #[inline]
     fn has_undirected_edge_by_index(&self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         self.undirected_adjacency_matrix[[x, y]]
     }
 
     #[inline]
     fn get_degree_by_index(&self, x: usize) -> usize {
+        // Check vertex index.
+        check_vertex_index(x, self.order());
+
         // Compute degree.
         let d = self
             .undirected_adjacency_matrix
@@ -1432,6 +1528,10 @@ impl UndirectedGraph for PartiallyDenseAdjacencyMatrixGraph {
     }
     #[inline]
     fn add_undirected_edge_by_index(&mut self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         // If edge already exists ...
         if self.adjacency_matrix[[x, y]] {
             debug_assert!(self.adjacency_matrix[[x, y]] == self.adjacency_matrix[[y, x]]);
@@ -1703,41 +1803,68 @@ impl DirectedGraph for PartiallyDenseAdjacencyMatrixGraph {
 
     #[inline]
     fn get_ancestors_by_index(&self, x: usize) -> Self::AncestorsIndexIter<'_> {
+        // Check vertex index.
+        check_vertex_index(x, self.order());
+
         Self::AncestorsIndexIter::new(self, x)
     }
 
     #[inline]
     fn get_parents_by_index(&self, x: usize) -> Self::ParentsIndexIter<'_> {
+        // Check vertex index.
+        check_vertex_index(x, self.order());
+
         Self::ParentsIndexIter::new(self, x)
     }
 
     #[inline]
     fn is_parent_by_index(&self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         self.directed_adjacency_matrix[[y, x]]
     }
 
     #[inline]
     fn get_children_by_index(&self, x: usize) -> Self::ChildrenIndexIter<'_> {
+        // Check vertex index.
+        check_vertex_index(x, self.order());
+
         Self::ChildrenIndexIter::new(self, x)
     }
 
     #[inline]
     fn is_child_by_index(&self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         self.directed_adjacency_matrix[[x, y]]
     }
 
     #[inline]
     fn get_descendants_by_index(&self, x: usize) -> Self::DescendantsIndexIter<'_> {
+        // Check vertex index.
+        check_vertex_index(x, self.order());
+
         Self::DescendantsIndexIter::new(self, x)
     }
 
     #[inline]
     fn has_directed_edge_by_index(&self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         self.directed_adjacency_matrix[[x, y]]
     }
 
     #[inline]
     fn get_in_degree_by_index(&self, x: usize) -> usize {
+        // Check vertex index.
+        check_vertex_index(x, self.order());
+
         // Compute in-degree.
         let d = self
             .directed_adjacency_matrix
@@ -1753,6 +1880,9 @@ impl DirectedGraph for PartiallyDenseAdjacencyMatrixGraph {
 
     #[inline]
     fn get_out_degree_by_index(&self, x: usize) -> usize {
+        // Check vertex index.
+        check_vertex_index(x, self.order());
+
         // Compute out-degree.
         let d = self
             .directed_adjacency_matrix
@@ -1768,6 +1898,10 @@ impl DirectedGraph for PartiallyDenseAdjacencyMatrixGraph {
 
     #[inline]
     fn add_directed_edge_by_index(&mut self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         // If edge already exists ...
         if self.adjacency_matrix[[x, y]] {
             debug_assert!(self.adjacency_matrix[[x, y]] == self.adjacency_matrix[[y, x]]);
@@ -1956,6 +2090,10 @@ impl PartiallyDirectedGraph for PartiallyDenseAdjacencyMatrixGraph {
 impl PathGraph for PartiallyDenseAdjacencyMatrixGraph {
     #[inline]
     fn has_path_by_index(&self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         let has_edge =
             self.has_undirected_edge_by_index(x, y) || self.has_directed_edge_by_index(x, y);
         has_edge || BFS::from((self, x)).skip(1).any(|z| z == y)
@@ -1987,11 +2125,105 @@ impl MoralGraph for PartiallyDenseAdjacencyMatrixGraph {
     }
 }
 
+impl PartiallyDenseAdjacencyMatrixGraph {
+    /// Private function. It returns the edges oriented by Meek's rule 1 around a given vertex.
+    fn meek_1_at(&self, x: usize) -> Vec<(usize, usize)> {
+        if Pa!(self, x).next().is_none() {
+            return vec![];
+        }
+        Ne!(self, x)
+            .filter(|&z| {
+                iter_set::intersection(Adj!(self, z), Pa!(self, x))
+                    .next()
+                    .is_none()
+            })
+            .map(|z| (x, z))
+            .collect()
+    }
+
+    /// Private function. It returns the edges oriented by Meek's rule 2 around a given vertex.
+    fn meek_2_at(&self, x: usize) -> Vec<(usize, usize)> {
+        if Pa!(self, x).next().is_none() {
+            return vec![];
+        }
+        Ch!(self, x)
+            .flat_map(|z| iter_set::intersection(Ne!(self, z), Pa!(self, x)).map(move |y| (y, z)))
+            .collect()
+    }
+
+    /// Private function. It returns the edges oriented by Meek's rule 3 around a given vertex.
+    fn meek_3_at(&self, x: usize) -> Vec<(usize, usize)> {
+        // At least two parents of `x` are required.
+        if Pa!(self, x).nth(1).is_none() {
+            return vec![];
+        }
+        Ne!(self, x)
+            .filter(|&z| {
+                // Look for a non-adjacent couple of parents of `x`
+                iter_set::intersection(Ne!(self, z), Pa!(self, x))
+                    .combinations(2)
+                    .any(|ab| !self.is_adjacent_by_index(ab[0], ab[1]))
+            })
+            .map(|z| (z, x))
+            .collect()
+    }
+
+    /// Private function. It returns the edges oriented by Meek's rule 4 around a given vertex.
+    fn meek_4_at(&self, x: usize) -> Vec<(usize, usize)> {
+        if Pa!(self, x).next().is_none() {
+            return vec![];
+        }
+        Ne!(self, x)
+            .filter(|&z| {
+                iter_set::intersection(
+                    Ne!(self, z),
+                    Pa!(self, x).flat_map(|parent| {
+                        Pa!(self, parent).filter(|&y| !self.is_adjacent_by_index(y, x))
+                    }),
+                )
+                .next()
+                .is_some()
+            })
+            .map(|z| (z, x))
+            .collect()
+    }
+
+    /// Private function. It applies a Meek's rule by scanning the vertices in parallel,
+    /// then orienting the edges found, skipping the ones already oriented by the same pass.
+    fn par_meek_with<F>(&mut self, rule: F) -> bool
+    where
+        F: Fn(&Self, usize) -> Vec<(usize, usize)> + Sync,
+    {
+        // No undirected edge left to orient, skip the scan.
+        if self.undirected_size == 0 {
+            return true;
+        }
+        // Find the edges to orient w.r.t. the current graph, in parallel.
+        let edges: Vec<_> = (0..self.order())
+            .into_par_iter()
+            .flat_map_iter(|x| rule(self, x))
+            .collect();
+        // Flag returning `false` if some orientation takes place
+        let mut is_closed = true;
+        // Orient the edges, serially, skipping the ones already oriented.
+        for (a, b) in edges {
+            if self.orient_edge(a, b) {
+                is_closed = false;
+            }
+        }
+        is_closed
+    }
+}
+
 impl MeekRules for PartiallyDenseAdjacencyMatrixGraph {
     #[inline]
     fn meek_1(&mut self) -> bool {
         // Flag returning `false` if some orientation takes place
         let mut is_closed = true;
+        // No undirected edge left to orient, skip the scan.
+        if self.undirected_size == 0 {
+            return is_closed;
+        }
         for x in V!(self).collect::<Vec<_>>() {
             if Pa!(self, x).next().is_none() {
                 continue;
@@ -2013,6 +2245,10 @@ impl MeekRules for PartiallyDenseAdjacencyMatrixGraph {
     fn meek_2(&mut self) -> bool {
         // Flag returning `false` if some orientation takes place
         let mut is_closed = true;
+        // No undirected edge left to orient, skip the scan.
+        if self.undirected_size == 0 {
+            return is_closed;
+        }
         for x in V!(self).collect::<Vec<_>>() {
             if Pa!(self, x).next().is_none() {
                 continue;
@@ -2031,7 +2267,15 @@ impl MeekRules for PartiallyDenseAdjacencyMatrixGraph {
     fn meek_3(&mut self) -> bool {
         // Flag returning `false` if some orientation takes place
         let mut is_closed = true;
+        // No undirected edge left to orient, skip the scan.
+        if self.undirected_size == 0 {
+            return is_closed;
+        }
         for x in V!(self).collect::<Vec<_>>() {
+            // At least two parents of `x` are required.
+            if Pa!(self, x).nth(1).is_none() {
+                continue;
+            }
             for z in Ne!(self, x).collect::<Vec<_>>() {
                 let intersection = iter_set::intersection(Ne!(self, z), Pa!(self, x));
                 // Look for a non-adjacent couple of parents of `x`
@@ -2051,6 +2295,10 @@ impl MeekRules for PartiallyDenseAdjacencyMatrixGraph {
     fn meek_4(&mut self) -> bool {
         // Flag returning `false` if some orientation takes place
         let mut is_closed = true;
+        // No undirected edge left to orient, skip the scan.
+        if self.undirected_size == 0 {
+            return is_closed;
+        }
         for x in V!(self).collect::<Vec<_>>() {
             if Pa!(self, x).next().is_none() {
                 continue;
@@ -2076,7 +2324,9 @@ impl MeekRules for PartiallyDenseAdjacencyMatrixGraph {
     #[inline]
     fn meek_procedure_until_3(mut self) -> Self {
         let mut is_closed = false;
-        while !is_closed {
+        // Rules are applied serially, since each orientation may enable others,
+        // until either no orientation takes place or no undirected edge is left.
+        while !is_closed && self.undirected_size > 0 {
             is_closed = self.meek_1();
             is_closed &= self.meek_2();
             is_closed &= self.meek_3();
@@ -2087,7 +2337,9 @@ impl MeekRules for PartiallyDenseAdjacencyMatrixGraph {
     #[inline]
     fn meek_procedure_until_4(mut self) -> Self {
         let mut is_closed = false;
-        while !is_closed {
+        // Rules are applied serially, since each orientation may enable others,
+        // until either no orientation takes place or no undirected edge is left.
+        while !is_closed && self.undirected_size > 0 {
             is_closed = self.meek_1();
             is_closed &= self.meek_2();
             is_closed &= self.meek_3();
@@ -2095,4 +2347,31 @@ impl MeekRules for PartiallyDenseAdjacencyMatrixGraph {
         }
         self
     }
+
+    #[inline]
+    fn par_meek_procedure_until_3(mut self) -> Self {
+        let mut is_closed = false;
+        // Each rule scans the vertices in parallel, while orientations are applied
+        // between scans, until either no orientation takes place or no undirected edge is left.
+        while !is_closed && self.undirected_size > 0 {
+            is_closed = self.par_meek_with(Self::meek_1_at);
+            is_closed &= self.par_meek_with(Self::meek_2_at);
+            is_closed &= self.par_meek_with(Self::meek_3_at);
+        }
+        self
+    }
+
+    #[inline]
+    fn par_meek_procedure_until_4(mut self) -> Self {
+        let mut is_closed = false;
+        // Each rule scans the vertices in parallel, while orientations are applied
+        // between scans, until either no orientation takes place or no undirected edge is left.
+        while !is_closed && self.undirected_size > 0 {
+            is_closed = self.par_meek_with(Self::meek_1_at);
+            is_closed &= self.par_meek_with(Self::meek_2_at);
+            is_closed &= self.par_meek_with(Self::meek_3_at);
+            is_closed &= self.par_meek_with(Self::meek_4_at);
+        }
+        self
+    }
 }
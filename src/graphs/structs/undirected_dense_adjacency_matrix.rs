@@ -11,6 +11,7 @@ use itertools::{iproduct, Itertools};
 use ndarray::{iter::IndexedIter, prelude::*};
 use serde::{Deserialize, Serialize};
 
+use super::check_vertex_index;
 use crate::{
     graphs::{
         algorithms::traversal::{DFSEdge, DFSEdges, Traversal},
@@ -333,9 +334,12 @@ impl BaseGraph for UndirectedDenseAdjacencyMatrixGraph {
 
     #[inline]
     fn get_vertex_by_index(&self, x: usize) -> &str {
-        self.labels
-            .get_index(x)
-            .unwrap_or_else(|| panic!("No vertex with label `{x}`"))
+        self.labels.get_index(x).unwrap_or_else(|| {
+            panic!(
+                "No vertex with identifier `{x}` in a graph of order `{}`",
+                self.labels.len()
+            )
+        })
     }
 
     fn add_vertex<V>(&mut self, x: V) -> usize
@@ -467,11 +471,19 @@ impl BaseGraph for UndirectedDenseAdjacencyMatrixGraph {
 
     #[inline]
     fn has_edge_by_index(&self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         self.adjacency_matrix[[x, y]]
     }
 
     #[inline]
     fn add_edge_by_index(&mut self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         // If edge already exists ...
         if self.adjacency_matrix[[x, y]] {
             // ... return early.
@@ -503,6 +515,10 @@ impl BaseGraph for UndirectedDenseAdjacencyMatrixGraph {
 
     #[inline]
     fn del_edge_by_index(&mut self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         // If edge does not exists ...
         if !self.adjacency_matrix[[x, y]] {
             // ... return early.
@@ -534,11 +550,18 @@ impl BaseGraph for UndirectedDenseAdjacencyMatrixGraph {
 
     #[inline]
     fn get_adjacents_index(&self, x: usize) -> Self::AdjacentsIndexIter<'_> {
+        // Check vertex index.
+        check_vertex_index(x, self.order());
+
         Self::AdjacentsIndexIter::new(self, x)
     }
 
     #[inline]
     fn is_adjacent_by_index(&self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         // Check using has_edge.
         let f = self.has_edge_by_index(x, y);
 
@@ -839,21 +862,35 @@ impl UndirectedGraph for UndirectedDenseAdjacencyMatrixGraph {
 
     #[inline]
     fn get_neighbors_by_index(&self, x: usize) -> Self::NeighborsIndexIter<'_> {
+        // Check vertex index.
+        check_vertex_index(x, self.order());
+
         Self::NeighborsIndexIter::new(self, x)
     }
 
     #[inline]
     fn is_neighbor_by_index(&self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         self.adjacency_matrix[[x, y]]
     }
 
     #[inline]
     fn has_undirected_edge_by_index(&self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         self.adjacency_matrix[[x, y]]
     }
 
     #[inline]
     fn get_degree_by_index(&self, x: usize) -> usize {
+        // Check vertex index.
+        check_vertex_index(x, self.order());
+
         // Compute degree.
         let d = self.adjacency_matrix.row(x).mapv(|f| f as usize).sum();
 
@@ -865,6 +902,10 @@ impl UndirectedGraph for UndirectedDenseAdjacencyMatrixGraph {
 
     #[inline]
     fn add_undirected_edge_by_index(&mut self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         self.add_edge_by_index(x, y)
     }
 }
@@ -873,6 +914,10 @@ impl UndirectedGraph for UndirectedDenseAdjacencyMatrixGraph {
 impl PathGraph for UndirectedDenseAdjacencyMatrixGraph {
     #[inline]
     fn has_path_by_index(&self, x: usize, y: usize) -> bool {
+        // Check vertices indices.
+        check_vertex_index(x, self.order());
+        check_vertex_index(y, self.order());
+
         self.has_edge_by_index(x, y) || BFS::from((self, x)).skip(1).any(|z| z == y)
     }
 
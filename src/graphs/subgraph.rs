@@ -113,4 +113,50 @@ pub trait SubGraph: BaseGraph + PartialOrdGraph {
     fn subgraph_by_edges<J>(&self, edges: J) -> Self
     where
         J: IntoIterator<Item = (usize, usize)>;
+
+    /// Constructs the vertex-induced subgraph given vertices labels.
+    ///
+    /// Resolves each label to its vertex identifier, then constructs the vertex-induced subgraph.
+    ///
+    /// # Panics
+    ///
+    /// At least one of the vertex labels does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    ///
+    /// // Build a new directed graph.
+    /// let g = DiGraph::new(
+    ///     ["A", "B", "C", "D", "E", "F"],
+    ///     [
+    ///         ("A", "C"),
+    ///         ("B", "C"),
+    ///         ("C", "D"),
+    ///         ("C", "E"),
+    ///     ]
+    /// );
+    ///
+    /// // Compute subgraph by labels.
+    /// let h = g.subgraph_by_labels(["C", "A", "D"]);
+    ///
+    /// // Assert is subgraph with sorted labels.
+    /// assert!(h.is_subgraph(&g));
+    /// assert!(L!(h).eq(["A", "C", "D"]));
+    /// assert_eq!(h, g.subgraph_by_vertices([0, 2, 3]));
+    /// ```
+    ///
+    fn subgraph_by_labels<'a, I>(&self, labels: I) -> Self
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        // Resolve labels to vertices identifiers.
+        let vertices: Vec<_> = labels
+            .into_iter()
+            .map(|x| self.get_vertex_index(x))
+            .collect();
+
+        self.subgraph_by_vertices(vertices)
+    }
 }
@@ -1,16 +1,91 @@
-use std::{io::Error as IOError, path::PathBuf};
+use std::{
+    fmt::{Display, Formatter},
+    io::Error as IOError,
+    path::PathBuf,
+};
 
 use itertools::Itertools;
+use log::{debug, info, warn};
 use ndarray::prelude::*;
-use pest::{error::Error as ParserError, iterators::Pairs, Parser};
+use pest::{
+    error::{Error as ParserError, LineColLocation},
+    iterators::{Pair, Pairs},
+    Parser,
+};
 use pest_derive::Parser;
 
 use crate::{
+    graphs::{structs::DirectedDenseAdjacencyMatrixGraph, BaseGraph},
     io::File,
     models::CategoricalCPD,
     prelude::{CategoricalBayesianNetwork, Factor, FxIndexMap, FxIndexSet},
 };
 
+/// BIF parsing error, located at the offending line and column.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BifError {
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+impl BifError {
+    /// Private constructor, locating the error at the start of the given pair.
+    fn new(pair: &Pair<'_, Rule>, message: String) -> Self {
+        // Get the line and column.
+        let (line, column) = pair.as_span().start_pos().line_col();
+
+        Self {
+            line,
+            column,
+            message,
+        }
+    }
+
+    /// Line of the error, starting from one.
+    #[inline]
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Column of the error, starting from one.
+    #[inline]
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Human-readable message of the error.
+    #[inline]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for BifError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for BifError {}
+
+impl From<ParserError<Rule>> for BifError {
+    fn from(e: ParserError<Rule>) -> Self {
+        // Get the line and column.
+        let (line, column) = match e.line_col {
+            LineColLocation::Pos(p) | LineColLocation::Span(p, _) => p,
+        };
+        // Get the message.
+        let message = e.variant.message().into_owned();
+
+        Self {
+            line,
+            column,
+            message,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default, Parser)]
 #[grammar = "io/bif/grammar.pest"]
 pub struct BIF {
@@ -20,10 +95,35 @@ pub struct BIF {
 
 impl<'a> From<Pairs<'a, Rule>> for BIF {
     fn from(pairs: Pairs<'a, Rule>) -> Self {
+        Self::try_from_pairs(pairs).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+/// Private function. It parses a probability value, together with the maximum rounding
+/// error of its decimal representation, i.e. half a unit in its last decimal place.
+/// Values written without decimal point and exponent, e.g. `0` or `1`, are exact.
+fn parse_value(x: &str) -> (f64, f64) {
+    // Split the mantissa from the exponent, if any.
+    let (m, e) = x.split_once(|c| c == 'e' || c == 'E').unwrap_or((x, "0"));
+    // Check if the value is written as exact.
+    if m == x && !m.contains('.') {
+        return (x.parse().unwrap(), 0.);
+    }
+    // Count the decimal places, i.e. the fractional digits shifted by the exponent.
+    let d = m.split_once('.').map_or(0, |(_, f)| f.len() as i32) - e.parse::<i32>().unwrap();
+
+    (x.parse().unwrap(), 0.5 * 10_f64.powi(-d))
+}
+
+impl BIF {
+    /// Private function. It converts the parsed rules, checking their consistency.
+    fn try_from_pairs(pairs: Pairs<'_, Rule>) -> Result<Self, BifError> {
         // Initialize scope map. TODO: Generalize to the continuous case.
         let mut scope: FxIndexMap<String, FxIndexSet<String>> = Default::default();
+        // Initialize variables declarations, used to locate errors.
+        let mut declarations: FxIndexMap<String, Pair<'_, Rule>> = Default::default();
         // Initialize CPDs tables vector. TODO: Generalize to the continuous case.
-        let mut tables: Vec<(Vec<String>, Array1<f64>)> = Default::default();
+        let mut tables: Vec<(Vec<Pair<'_, Rule>>, Array1<f64>, Array1<f64>)> = Default::default();
 
         // Match inner rules.
         let mut inner = pairs;
@@ -31,11 +131,23 @@ impl<'a> From<Pairs<'a, Rule>> for BIF {
         // Assert rule match. TODO: Parse network properties.
         let _network = inner.next().unwrap();
         assert!(matches!(_network.as_rule(), Rule::network_declaration));
+        // Count network properties.
+        let properties = _network
+            .into_inner()
+            .flat_map(|x| x.into_inner())
+            .filter(|x| matches!(x.as_rule(), Rule::property))
+            .count();
+        // Report skipped network properties, if any.
+        if properties > 0 {
+            warn!("Skipping {properties} unsupported network properties");
+        }
 
         // Assert rule match.
         for variable_probability in inner {
             match variable_probability.as_rule() {
                 Rule::variable_declaration => {
+                    // Keep the declaration.
+                    let declaration = variable_probability.clone();
                     // Match inner rules.
                     let mut i = variable_probability.into_inner();
 
@@ -43,13 +155,20 @@ impl<'a> From<Pairs<'a, Rule>> for BIF {
                     let name = i.next().unwrap();
                     assert!(matches!(name.as_rule(), Rule::variable_name));
                     // Get variable name.
-                    let name = name.as_str().into();
+                    let name = name.as_str();
 
                     // Assert rule match.
                     let content = i.next().unwrap();
                     assert!(matches!(content.as_rule(), Rule::variable_content));
-                    // Match inner rules. TODO: Generalize to the continuous case.
-                    let mut i = content.into_inner();
+                    // Match inner rules, skipping properties. TODO: Generalize to the continuous case.
+                    let mut i = content.into_inner().filter(|x| match x.as_rule() {
+                        Rule::property => {
+                            // Report skipped variable property.
+                            warn!("Skipping unsupported property of variable `{name}`");
+                            false
+                        }
+                        _ => true,
+                    });
 
                     // Assert rule match.
                     let categorical = i.next().unwrap();
@@ -61,10 +180,16 @@ impl<'a> From<Pairs<'a, Rule>> for BIF {
                     let states = i.next().unwrap();
                     assert!(matches!(states.as_rule(), Rule::variable_states_list));
                     // Collect states.
-                    let states = states.into_inner().map(|s| s.as_str().into()).collect();
+                    let states: FxIndexSet<String> =
+                        states.into_inner().map(|s| s.as_str().into()).collect();
+
+                    // Report parsed variable.
+                    debug!("Parsed variable `{name}` with {} states", states.len());
 
                     // Insert variable with states into scope.
-                    scope.insert(name, states);
+                    scope.insert(name.into(), states);
+                    // Insert variable declaration.
+                    declarations.insert(name.into(), declaration);
                 }
                 Rule::probability_declaration => {
                     // Match inner rules.
@@ -77,54 +202,158 @@ impl<'a> From<Pairs<'a, Rule>> for BIF {
                         Rule::probability_variables_list
                     ));
                     // Get variables names.
-                    let variables = variables
-                        .into_inner()
-                        .map(|x| x.as_str().to_owned())
-                        .collect();
+                    let variables: Vec<Pair<'_, Rule>> = variables.into_inner().collect();
 
                     // Assert rule match.
                     let content = i.next().unwrap();
                     assert!(matches!(content.as_rule(), Rule::probability_content));
 
-                    // Initialize parameters values. TODO: Generalize to the continuous case.
-                    let mut values: Vec<f64> = Default::default();
+                    // Initialize parameters values, with their rounding errors. TODO: Generalize to the continuous case.
+                    let mut values: Vec<(f64, f64)> = Default::default();
                     // Match into inner rules.
                     for entry in content.into_inner() {
                         match entry.as_rule() {
-                            Rule::probability_default_entry | Rule::probability_table => values
-                                .extend(
-                                    entry
-                                        .into_inner()
-                                        .map(|x| x.as_str())
-                                        .map(|x| x.parse::<f64>().unwrap()),
-                                ),
+                            Rule::probability_default_entry | Rule::probability_table => {
+                                values.extend(entry.into_inner().map(|x| parse_value(x.as_str())))
+                            }
                             Rule::probability_entry => values.extend(
                                 entry
                                     .into_inner()
                                     .skip(1) // Skip states declaration.
-                                    .map(|x| x.as_str())
-                                    .map(|x| x.parse::<f64>().unwrap()),
+                                    .map(|x| parse_value(x.as_str())),
                             ),
+                            Rule::property => {
+                                // Report skipped probability property.
+                                warn!(
+                                    "Skipping unsupported property of probability `{}`",
+                                    variables[0].as_str()
+                                );
+                            }
                             _ => unreachable!(),
                         }
                     }
-                    // Convert vector to array.
-                    let values = Array1::from_vec(values);
+                    // Convert vector to arrays.
+                    let (values, errors): (Vec<_>, Vec<_>) = values.into_iter().unzip();
+                    let (values, errors) = (Array1::from_vec(values), Array1::from_vec(errors));
+
+                    // Report parsed probability.
+                    debug!(
+                        "Parsed probability `{}` with {} values",
+                        variables.iter().map(|x| x.as_str()).join(" | "),
+                        values.len()
+                    );
 
                     // Append to parsed results.
-                    tables.push((variables, values));
+                    tables.push((variables, values, errors));
                 }
                 Rule::EOI => {}
                 _ => unreachable!(),
             }
         }
 
+        // Check each table w.r.t. the declared variables.
+        for (variables, values, errors) in &tables {
+            // Check the variables are declared.
+            if let Some(z) = variables.iter().find(|z| !scope.contains_key(z.as_str())) {
+                return Err(BifError::new(
+                    z,
+                    format!("Probability references unknown variable `{}`", z.as_str()),
+                ));
+            }
+            // Compute the expected number of values as |X| * \Prod_i |Z_i|.
+            let n: usize = variables.iter().map(|z| scope[z.as_str()].len()).product();
+            // Check the number of values.
+            if values.len() != n {
+                return Err(BifError::new(
+                    &variables[0],
+                    format!(
+                        "Probability of `{}` must have {n} values, found {}",
+                        variables[0].as_str(),
+                        values.len()
+                    ),
+                ));
+            }
+            // Get the target variable cardinality.
+            let k = scope[variables[0].as_str()].len();
+            // Check each row of values has a non-zero sum, which can be normalized.
+            if values
+                .exact_chunks(k)
+                .into_iter()
+                .any(|row| row.sum() == 0.)
+            {
+                return Err(BifError::new(
+                    &variables[0],
+                    format!(
+                        "Probability of `{}` must not have rows of zeros",
+                        variables[0].as_str()
+                    ),
+                ));
+            }
+            // Check each row of values sums to one, up to the rounding of the written values,
+            // i.e. the sum of their rounding errors, or floating point noise otherwise.
+            if let Some(sum) = values
+                .exact_chunks(k)
+                .into_iter()
+                .zip(errors.exact_chunks(k))
+                .map(|(row, error)| (row.sum(), f64::max(error.sum(), 1e-6)))
+                .find(|(sum, tolerance)| (sum - 1.).abs() > *tolerance)
+                .map(|(sum, _)| sum)
+            {
+                return Err(BifError::new(
+                    &variables[0],
+                    format!(
+                        "Probability of `{}` must sum to one, found {sum}",
+                        variables[0].as_str()
+                    ),
+                ));
+            }
+        }
+        // Check each declared variable has a table.
+        for (x, declaration) in &declarations {
+            // Check the probability is declared.
+            if !tables
+                .iter()
+                .any(|(variables, _, _)| variables[0].as_str() == x)
+            {
+                return Err(BifError::new(
+                    declaration,
+                    format!("Variable `{x}` has no probability"),
+                ));
+            }
+        }
+        // Get the graph induced by the probabilities, i.e. with edges from Z_i to X.
+        let graph = DirectedDenseAdjacencyMatrixGraph::new(
+            scope.keys().map(|x| x.as_str()),
+            tables.iter().flat_map(|(variables, _, _)| {
+                variables[1..]
+                    .iter()
+                    .map(|z| (z.as_str(), variables[0].as_str()))
+            }),
+        );
+        // Check the graph is acyclic.
+        if let Some(cycle) = graph.find_cycle() {
+            // Get the probability of the first variable in the cycle.
+            let x = graph.get_vertex_by_index(cycle[0]);
+            let (variables, _, _) = tables
+                .iter()
+                .find(|(variables, _, _)| variables[0].as_str() == x)
+                .unwrap();
+
+            return Err(BifError::new(
+                &variables[0],
+                format!(
+                    "Graph must be acyclic, found cycle: {}",
+                    graph.format_cycle(&cycle)
+                ),
+            ));
+        }
+
         // Construct parameters from scopes, variables and tables. TODO: Generalize to the continuous case.
         let theta = tables
             .into_iter()
-            .map(|(variables, values)| {
+            .map(|(variables, values, _)| {
                 // Consume variables iterator.
-                let mut variables = variables.into_iter();
+                let mut variables = variables.into_iter().map(|x| x.as_str().to_owned());
                 // Get target variable X scope.
                 let x = variables.next().expect("Failed to get target variable");
                 let (x, y) = (x.clone(), scope[&x].clone());
@@ -134,19 +363,166 @@ impl<'a> From<Pairs<'a, Rule>> for BIF {
                 let shape = (values.len() / y.len(), y.len());
                 // Reshape values.
                 let values = values.into_shape(shape).expect("Failed to reshape values");
-                // Normalized values.
-                let values = &values / values.sum_axis(Axis(1)).insert_axis(Axis(1));
+                // Compute normalization constants, which are one up to the tolerance.
+                let sums = values.sum_axis(Axis(1));
+                // Normalize values, removing the rounding of the written values.
+                let values = &values / sums.insert_axis(Axis(1));
                 // Construct associated parameter.
                 CategoricalCPD::new((x, y), z, values)
             })
-            .collect();
+            .collect_vec();
 
-        Self { theta }
+        // Count free parameters, i.e. (|X| - 1) * \Prod_i |Z_i| for each CPD.
+        let parameters: usize = theta
+            .iter()
+            .map(|phi| {
+                // Get target variable cardinality.
+                let c = phi.states()[phi.target()].len();
+
+                phi.values().len() / c * (c - 1)
+            })
+            .sum();
+        // Report parsing summary.
+        info!(
+            "Parsed BIF with {} variables and {parameters} parameters",
+            scope.len()
+        );
+
+        Ok(Self { theta })
     }
 }
 
-impl From<BIF> for String {
-    fn from(value: BIF) -> Self {
+/// BIF formatting options.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ToBifOptions {
+    precision: Option<usize>,
+    compatibility: bool,
+}
+
+impl ToBifOptions {
+    /// Constructor for BIF formatting options.
+    ///
+    /// By default, values are formatted with the shortest representation
+    /// that round-trips to the same `f64`, i.e. without loss of precision.
+    ///
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            precision: None,
+            compatibility: false,
+        }
+    }
+
+    /// Set the number of decimal digits used to format values.
+    #[inline]
+    pub const fn with_precision(mut self, precision: usize) -> Self {
+        // Set the precision.
+        self.precision = Some(precision);
+
+        self
+    }
+
+    /// Get the number of decimal digits used to format values, if any.
+    #[inline]
+    pub const fn precision(&self) -> Option<usize> {
+        self.precision
+    }
+
+    /// Set the compatibility mode with third-party BIF readers, e.g. `pgmpy` and `bnlearn`.
+    ///
+    /// Such readers accept a narrower grammar for names than this crate, hence in
+    /// compatibility mode any character of variables and states names other than
+    /// ASCII alphanumerics and `_` is replaced with `_`, e.g. `(-inf, 0.5]` is
+    /// written as `__inf__0_5_`. Values are written as plain decimal numbers.
+    ///
+    #[inline]
+    pub const fn with_compatibility(mut self, compatibility: bool) -> Self {
+        // Set the compatibility mode.
+        self.compatibility = compatibility;
+
+        self
+    }
+
+    /// Get the compatibility mode with third-party BIF readers.
+    #[inline]
+    pub const fn compatibility(&self) -> bool {
+        self.compatibility
+    }
+
+    /// Private function. It formats a name w.r.t. the options.
+    #[inline]
+    fn format_name(&self, x: &str) -> String {
+        match self.compatibility {
+            // Replace the characters outside the common grammar.
+            true => x
+                .chars()
+                .map(|c| match c.is_ascii_alphanumeric() || c == '_' {
+                    true => c,
+                    false => '_',
+                })
+                .collect(),
+            // Keep the name as is.
+            false => x.to_owned(),
+        }
+    }
+
+    /// Private function. It formats a value w.r.t. the options.
+    #[inline]
+    fn format(&self, x: &f64) -> String {
+        match self.precision {
+            // Format with the given number of decimal digits.
+            Some(p) => format!("{x:.p$}"),
+            // Format with the shortest round-trip representation.
+            None => x.to_string(),
+        }
+    }
+}
+
+impl BIF {
+    /// Format to string w.r.t. the given formatting options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::{io::bif::ToBifOptions, prelude::*};
+    ///
+    /// // Read BIF from file.
+    /// let bif = BIF::read("./tests/assets/bif/asia.bif").unwrap();
+    ///
+    /// // Format values with four decimal digits.
+    /// let out = bif.to_string_with(ToBifOptions::new().with_precision(4));
+    /// assert!(out.contains("table 0.9900, 0.0100;"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// In compatibility mode, the replaced names must still be unique.
+    ///
+    pub fn to_string_with(&self, options: ToBifOptions) -> String {
+        // Assert the replaced names are unique.
+        if options.compatibility {
+            // Assert the variables names are unique.
+            assert!(
+                self.theta
+                    .iter()
+                    .map(|phi| options.format_name(phi.target()))
+                    .all_unique(),
+                "Variables names must be unique in compatibility mode"
+            );
+            // Assert the states names of each variable are unique.
+            for phi in self.theta.iter() {
+                // Get associated target.
+                let x = phi.target();
+                assert!(
+                    phi.states()[x]
+                        .iter()
+                        .map(|s| options.format_name(s))
+                        .all_unique(),
+                    "States names of `{x}` must be unique in compatibility mode"
+                );
+            }
+        }
+
         // Allocate output string.
         let mut bif = String::new();
 
@@ -154,7 +530,7 @@ impl From<BIF> for String {
         bif += "network unknown {\n}\n";
 
         // Write variables declaration.
-        for phi in value.theta.iter() {
+        for phi in self.theta.iter() {
             // Get associated target.
             let x = phi.target();
             // Get associated states.
@@ -162,13 +538,15 @@ impl From<BIF> for String {
             // Get cardinality of associated states.
             let c = s.len();
             // Collect associated states.
-            let s = s.iter().join(", ");
+            let s = s.iter().map(|s| options.format_name(s)).join(", ");
+            // Format associated target.
+            let x = options.format_name(x);
             // Format variable declaration.
             bif += &format!("variable {x} {{\n  type discrete [ {c} ] {{ {s} }};\n}}\n");
         }
 
         // Write variables probability.
-        for phi in value.theta {
+        for phi in self.theta.iter() {
             // Get associated target.
             let x = phi.target();
             // Match probability declaration with states.
@@ -178,7 +556,11 @@ impl From<BIF> for String {
                     // Get associated states.
                     let s = phi.states();
                     // Get conditioning variables.
-                    let z = s.keys().filter(|&z| z != x).join(", ");
+                    let z = s
+                        .keys()
+                        .filter(|&z| z != x)
+                        .map(|z| options.format_name(z))
+                        .join(", ");
                     // Get target index.
                     let i = s
                         .get_index_of(x)
@@ -201,24 +583,26 @@ impl From<BIF> for String {
                     let v = s
                         .map(|s| {
                             // Format conditioning states.
-                            let s = s.into_iter().join(", ");
+                            let s = s.into_iter().map(|s| options.format_name(s)).join(", ");
                             // Format conditioned values.
                             let v = v
                                 .iter_mut()
-                                .map(|x| x.next().unwrap().to_string())
+                                .map(|x| options.format(x.next().unwrap()))
                                 .join(", ");
                             // Joint states and values.
                             format!("  ({s}) {v};")
                         })
                         .join("\n");
                     // Format probability declaration.
+                    let x = options.format_name(x);
                     bif += &format!("probability ( {x} | {z} ) {{\n{v}\n}}\n");
                 }
                 // Format P(X).
                 false => {
                     // Format probability values.
-                    let v = phi.values().into_iter().map(|x| x.to_string()).join(", ");
+                    let v = phi.values().iter().map(|x| options.format(x)).join(", ");
                     // Format probability declaration.
+                    let x = options.format_name(x);
                     bif += &format!("probability ( {x} ) {{\n  table {v};\n}}\n")
                 }
             }
@@ -228,6 +612,13 @@ impl From<BIF> for String {
     }
 }
 
+impl From<BIF> for String {
+    #[inline]
+    fn from(value: BIF) -> Self {
+        value.to_string_with(Default::default())
+    }
+}
+
 impl TryFrom<String> for BIF {
     type Error = ParserError<Rule>;
 
@@ -252,6 +643,8 @@ impl File for BIF {
     {
         // Get path.
         let path = path.into();
+        // Report file path.
+        debug!("Reading BIF from \"{}\"", path.display());
         // Read file to string.
         let out = std::fs::read_to_string(&path)
             .unwrap_or_else(|_| format!("Failed to read file: \"{}\"", path.display()));
@@ -272,11 +665,72 @@ impl File for BIF {
 
 impl From<CategoricalBayesianNetwork> for BIF {
     fn from(b: CategoricalBayesianNetwork) -> Self {
+        // Get the variables in topological order.
+        let order = b
+            .topological_order_labels()
+            .into_iter()
+            .map(String::from)
+            .collect_vec();
         // Get parameters.
-        let (_, theta) = b.into();
-        // Map to vector of parameters.
-        let theta = theta.into_values().collect();
+        let (_, mut theta) = b.into();
+        // Map to vector of parameters in topological order.
+        let theta = order
+            .into_iter()
+            .map(|x| theta.swap_remove(&x).expect("Failed to get parameter"))
+            .collect();
 
         Self { theta }
     }
 }
+
+impl CategoricalBayesianNetwork {
+    /// Parse a categorical BN from BIF source.
+    ///
+    /// # Errors
+    ///
+    /// The source must be valid BIF, where each variable has a probability block,
+    /// each probability references declared variables only and has as many values
+    /// as the product of the variables cardinalities. Otherwise, the error reports
+    /// the line and column of the offending declaration. Moreover, each row of values
+    /// must have a non-zero sum, must sum to one up to the rounding of the written
+    /// values, e.g. $k \cdot 0.5 \cdot 10^{-p}$ for $k$ values with $p$ decimal places,
+    /// or up to a tolerance of $10^{-6}$ if larger, and the induced graph must be acyclic.
+    /// The rows of values are then normalized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    ///
+    /// // Parse a BN with a probability of an unknown variable.
+    /// let b = CategoricalBN::try_from_bif(concat!(
+    ///     "network unknown {\n}\n",
+    ///     "variable A {\n  type discrete [ 2 ] { no, yes };\n}\n",
+    ///     "probability ( A | B ) {\n  table 0.5, 0.5;\n}\n",
+    /// ));
+    ///
+    /// // Check the error location.
+    /// let e = b.unwrap_err();
+    /// assert_eq!((e.line(), e.column()), (6, 19));
+    /// assert_eq!(e.message(), "Probability references unknown variable `B`");
+    /// ```
+    ///
+    pub fn try_from_bif(bif: &str) -> Result<Self, BifError> {
+        // Parse the given string.
+        let pairs = BIF::parse(Rule::compilation_unit, bif)?;
+        // Match inner rules.
+        let bif = BIF::try_from_pairs(pairs)?;
+
+        Ok(bif.into())
+    }
+
+    /// Parse a categorical BN from BIF source.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the source is not valid BIF, see [`Self::try_from_bif`].
+    ///
+    pub fn from_bif(bif: &str) -> Self {
+        Self::try_from_bif(bif).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
@@ -31,8 +31,11 @@ rs_attrs += "use crate::types::FxIndexSet;\n"
 rs_attrs += "\n"
 rs_attrs += "/// Quote string if necessary.\n"
 rs_attrs += "fn quote(s: &str) -> String {\n"
-rs_attrs += "    // Check if quoted and needs quoting.\n"
-rs_attrs += "    if !(s.starts_with(\"\\\"\") && s.ends_with(\"\\\"\")) && s.contains(\" \") {\n"
+rs_attrs += "    // Check if quoted or HTML-like.\n"
+rs_attrs += "    let quoted =\n"
+rs_attrs += "        (s.starts_with('\"') && s.ends_with('\"')) || (s.starts_with('<') && s.ends_with('>'));\n"
+rs_attrs += "    // Check if needs quoting.\n"
+rs_attrs += "    if !quoted && s.contains(' ') {\n"
 rs_attrs += "        // Add quoting to given string.\n"
 rs_attrs += "        return format!(\"\\\"{s}\\\"\");\n"
 rs_attrs += "    }\n"
@@ -102,15 +105,25 @@ for (w, (k, v)) in enumerate(used_by.items()):
     rs_used_by += "    /// Key is not valid for this attributes set. <a href=\"https://graphviz.org/doc/info/attrs.html#h:uses\" target=\"_blank\">Read more</a>.\n"
     rs_used_by += "    ///\n"
     rs_used_by += "    pub fn insert_raw_parts(&mut self, key: &str, value: &str) -> bool {\n"
+    rs_used_by += "        self.try_insert_raw_parts(key, value).unwrap_or_else(|e| panic!(\"{e}\"))\n"
+    rs_used_by += "    }\n"
+    rs_used_by += "\n"
+    rs_used_by += "    /// Try to set attribute from `key` and `value` raw parts. Returns whether the attribute was newly set.\n"
+    rs_used_by += "    ///\n"
+    rs_used_by += "    /// # Errors\n"
+    rs_used_by += "    ///\n"
+    rs_used_by += "    /// Key is not valid for this attributes set. <a href=\"https://graphviz.org/doc/info/attrs.html#h:uses\" target=\"_blank\">Read more</a>.\n"
+    rs_used_by += "    ///\n"
+    rs_used_by += "    pub fn try_insert_raw_parts(&mut self, key: &str, value: &str) -> Result<bool, String> {\n"
     rs_used_by += "        let value = quote(value);\n"
     rs_used_by += "        let item = match key {\n"
     for i in v:
         rs_used_by += f"            \"{i}\" => Attribute::{sc2cm(i)}(value),\n"
-    rs_used_by += "            _ => panic!(\"Invalid attribute key `{key}` for " + \
-        f"{k.removesuffix('s')}Attributes\"),\n"
+    rs_used_by += "            _ => return Err(format!(\"Invalid attribute key `{key}` for " + \
+        f"{k.removesuffix('s')}Attributes\")),\n"
     rs_used_by += "        };\n"
     rs_used_by += "\n"
-    rs_used_by += "        self.attributes.replace(item).is_none()\n"
+    rs_used_by += "        Ok(self.attributes.replace(item).is_none())\n"
     rs_used_by += "    }\n"
     rs_used_by += "\n"
     rs_used_by += "    /// Get attributes length.\n"
@@ -6,8 +6,11 @@ use crate::types::FxIndexSet;
 
 /// Quote string if necessary.
 fn quote(s: &str) -> String {
-    // Check if quoted and needs quoting.
-    if !(s.starts_with('"') && s.ends_with('"')) && s.contains(' ') {
+    // Check if quoted or HTML-like.
+    let quoted =
+        (s.starts_with('"') && s.ends_with('"')) || (s.starts_with('<') && s.ends_with('>'));
+    // Check if needs quoting.
+    if !quoted && s.contains(' ') {
         // Add quoting to given string.
         return format!("\"{s}\"");
     }
@@ -587,6 +590,17 @@ impl GraphAttributes {
     /// Key is not valid for this attributes set. <a href="https://graphviz.org/doc/info/attrs.html#h:uses" target="_blank">Read more</a>.
     ///
     pub fn insert_raw_parts(&mut self, key: &str, value: &str) -> bool {
+        self.try_insert_raw_parts(key, value)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Try to set attribute from `key` and `value` raw parts. Returns whether the attribute was newly set.
+    ///
+    /// # Errors
+    ///
+    /// Key is not valid for this attributes set. <a href="https://graphviz.org/doc/info/attrs.html#h:uses" target="_blank">Read more</a>.
+    ///
+    pub fn try_insert_raw_parts(&mut self, key: &str, value: &str) -> Result<bool, String> {
         let value = quote(value);
         let item = match key {
             "_background" => Attribute::Background(value),
@@ -692,10 +706,10 @@ impl GraphAttributes {
             "viewport" => Attribute::Viewport(value),
             "voro_margin" => Attribute::VoroMargin(value),
             "xdotversion" => Attribute::Xdotversion(value),
-            _ => panic!("Invalid attribute key `{key}` for GraphAttributes"),
+            _ => return Err(format!("Invalid attribute key `{key}` for GraphAttributes")),
         };
 
-        self.attributes.replace(item).is_none()
+        Ok(self.attributes.replace(item).is_none())
     }
 
     /// Get attributes length.
@@ -2590,6 +2604,17 @@ impl VertexAttributes {
     /// Key is not valid for this attributes set. <a href="https://graphviz.org/doc/info/attrs.html#h:uses" target="_blank">Read more</a>.
     ///
     pub fn insert_raw_parts(&mut self, key: &str, value: &str) -> bool {
+        self.try_insert_raw_parts(key, value)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Try to set attribute from `key` and `value` raw parts. Returns whether the attribute was newly set.
+    ///
+    /// # Errors
+    ///
+    /// Key is not valid for this attributes set. <a href="https://graphviz.org/doc/info/attrs.html#h:uses" target="_blank">Read more</a>.
+    ///
+    pub fn try_insert_raw_parts(&mut self, key: &str, value: &str) -> Result<bool, String> {
         let value = quote(value);
         let item = match key {
             "area" => Attribute::Area(value),
@@ -2641,10 +2666,14 @@ impl VertexAttributes {
             "xlabel" => Attribute::Xlabel(value),
             "xlp" => Attribute::Xlp(value),
             "z" => Attribute::Z(value),
-            _ => panic!("Invalid attribute key `{key}` for VertexAttributes"),
+            _ => {
+                return Err(format!(
+                    "Invalid attribute key `{key}` for VertexAttributes"
+                ))
+            }
         };
 
-        self.attributes.replace(item).is_none()
+        Ok(self.attributes.replace(item).is_none())
     }
 
     /// Get attributes length.
@@ -3567,6 +3596,17 @@ impl ClusterAttributes {
     /// Key is not valid for this attributes set. <a href="https://graphviz.org/doc/info/attrs.html#h:uses" target="_blank">Read more</a>.
     ///
     pub fn insert_raw_parts(&mut self, key: &str, value: &str) -> bool {
+        self.try_insert_raw_parts(key, value)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Try to set attribute from `key` and `value` raw parts. Returns whether the attribute was newly set.
+    ///
+    /// # Errors
+    ///
+    /// Key is not valid for this attributes set. <a href="https://graphviz.org/doc/info/attrs.html#h:uses" target="_blank">Read more</a>.
+    ///
+    pub fn try_insert_raw_parts(&mut self, key: &str, value: &str) -> Result<bool, String> {
         let value = quote(value);
         let item = match key {
             "area" => Attribute::Area(value),
@@ -3600,10 +3640,14 @@ impl ClusterAttributes {
             "target" => Attribute::Target(value),
             "tooltip" => Attribute::Tooltip(value),
             "URL" => Attribute::Url(value),
-            _ => panic!("Invalid attribute key `{key}` for ClusterAttributes"),
+            _ => {
+                return Err(format!(
+                    "Invalid attribute key `{key}` for ClusterAttributes"
+                ))
+            }
         };
 
-        self.attributes.replace(item).is_none()
+        Ok(self.attributes.replace(item).is_none())
     }
 
     /// Get attributes length.
@@ -4202,6 +4246,17 @@ impl EdgeAttributes {
     /// Key is not valid for this attributes set. <a href="https://graphviz.org/doc/info/attrs.html#h:uses" target="_blank">Read more</a>.
     ///
     pub fn insert_raw_parts(&mut self, key: &str, value: &str) -> bool {
+        self.try_insert_raw_parts(key, value)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Try to set attribute from `key` and `value` raw parts. Returns whether the attribute was newly set.
+    ///
+    /// # Errors
+    ///
+    /// Key is not valid for this attributes set. <a href="https://graphviz.org/doc/info/attrs.html#h:uses" target="_blank">Read more</a>.
+    ///
+    pub fn try_insert_raw_parts(&mut self, key: &str, value: &str) -> Result<bool, String> {
         let value = quote(value);
         let item = match key {
             "arrowhead" => Attribute::Arrowhead(value),
@@ -4270,10 +4325,10 @@ impl EdgeAttributes {
             "weight" => Attribute::Weight(value),
             "xlabel" => Attribute::Xlabel(value),
             "xlp" => Attribute::Xlp(value),
-            _ => panic!("Invalid attribute key `{key}` for EdgeAttributes"),
+            _ => return Err(format!("Invalid attribute key `{key}` for EdgeAttributes")),
         };
 
-        self.attributes.replace(item).is_none()
+        Ok(self.attributes.replace(item).is_none())
     }
 
     /// Get attributes length.
@@ -5502,14 +5557,29 @@ impl SubgraphAttributes {
     /// Key is not valid for this attributes set. <a href="https://graphviz.org/doc/info/attrs.html#h:uses" target="_blank">Read more</a>.
     ///
     pub fn insert_raw_parts(&mut self, key: &str, value: &str) -> bool {
+        self.try_insert_raw_parts(key, value)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Try to set attribute from `key` and `value` raw parts. Returns whether the attribute was newly set.
+    ///
+    /// # Errors
+    ///
+    /// Key is not valid for this attributes set. <a href="https://graphviz.org/doc/info/attrs.html#h:uses" target="_blank">Read more</a>.
+    ///
+    pub fn try_insert_raw_parts(&mut self, key: &str, value: &str) -> Result<bool, String> {
         let value = quote(value);
         let item = match key {
             "cluster" => Attribute::Cluster(value),
             "rank" => Attribute::Rank(value),
-            _ => panic!("Invalid attribute key `{key}` for SubgraphAttributes"),
+            _ => {
+                return Err(format!(
+                    "Invalid attribute key `{key}` for SubgraphAttributes"
+                ))
+            }
         };
 
-        self.attributes.replace(item).is_none()
+        Ok(self.attributes.replace(item).is_none())
     }
 
     /// Get attributes length.
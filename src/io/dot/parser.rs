@@ -2,9 +2,9 @@ use std::{collections::BTreeMap, io::Error as IOError, path::PathBuf};
 
 use itertools::Itertools;
 use pest::{
-    error::Error as ParserError,
+    error::{Error as ParserError, ErrorVariant},
     iterators::{Pair, Pairs},
-    Parser,
+    Parser, Position,
 };
 use pest_derive::Parser;
 
@@ -25,6 +25,99 @@ use crate::{
     uE, E, V,
 };
 
+/// Unquote an identifier, if quoted.
+fn unquote(id: &str) -> String {
+    match id.len() > 1 && id.starts_with('"') && id.ends_with('"') {
+        // Remove quotes and unescape inner quotes.
+        true => id[1..id.len() - 1].replace("\\\"", "\""),
+        false => id.into(),
+    }
+}
+
+/// Check the attributes keys w.r.t. an attributes set, mapping the invalid ones to parser errors.
+fn check_attributes<'a, I, F>(pairs: I, mut insert: F) -> Result<(), ParserError<Rule>>
+where
+    I: IntoIterator<Item = Pair<'a, Rule>>,
+    F: FnMut(&str, &str) -> Result<bool, String>,
+{
+    // For each attribute ...
+    for pair in pairs {
+        // Match inner rules.
+        let mut pair = pair.into_inner();
+        // Map attribute to pair.
+        let (key, value) = (pair.next().unwrap(), pair.next().unwrap());
+        // Check the key, pointing the error to its location.
+        insert(key.as_str(), value.as_str()).map_err(|message| {
+            ParserError::new_from_span(ErrorVariant::CustomError { message }, key.as_span())
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Reject a subgraph, pointing the error to its location.
+fn check_subgraph(pair: &Pair<Rule>) -> Result<(), ParserError<Rule>> {
+    Err(ParserError::new_from_span(
+        ErrorVariant::CustomError {
+            message: "Subgraphs are not supported".into(),
+        },
+        pair.as_span(),
+    ))
+}
+
+/// Check the attributes keys of the statements, in order.
+fn check_statements(pairs: Pairs<Rule>) -> Result<(), ParserError<Rule>> {
+    // Set the attributes checkers.
+    let graph = |k: &str, v: &str| GraphAttributes::default().try_insert_raw_parts(k, v);
+    let vertex = |k: &str, v: &str| VertexAttributes::default().try_insert_raw_parts(k, v);
+    let edge = |k: &str, v: &str| EdgeAttributes::default().try_insert_raw_parts(k, v);
+
+    // For each statement ...
+    for pair in pairs {
+        match pair.as_rule() {
+            Rule::attribute => check_attributes([pair], graph)?,
+            Rule::global_attributes => {
+                // Match inner rules.
+                let mut inner = pair.into_inner();
+                // Match attribute type.
+                let t = inner.next().unwrap().as_rule();
+                let attributes = inner.next().unwrap().into_inner();
+                match t {
+                    Rule::graph_type => check_attributes(attributes, graph)?,
+                    Rule::vertex_type => check_attributes(attributes, vertex)?,
+                    Rule::path_type => check_attributes(attributes, edge)?,
+                    _ => unreachable!(),
+                }
+            }
+            // TODO: Add support for subgraphs.
+            Rule::subgraph => check_subgraph(&pair)?,
+            Rule::vertex => {
+                // Check the vertex attributes, if any.
+                if let Some(attributes) = pair.into_inner().nth(1) {
+                    check_attributes(attributes.into_inner(), vertex)?;
+                }
+            }
+            Rule::path => {
+                // Match inner rules.
+                let mut inner = pair.into_inner();
+                // Check the path endpoints, rejecting the subgraphs.
+                for endpoint in inner.next().unwrap().into_inner() {
+                    if matches!(endpoint.as_rule(), Rule::subgraph) {
+                        check_subgraph(&endpoint)?;
+                    }
+                }
+                // Check the path attributes, if any.
+                if let Some(attributes) = inner.next() {
+                    check_attributes(attributes.into_inner(), edge)?;
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    Ok(())
+}
+
 impl<'a> Extend<Pair<'a, Rule>> for VertexAttributes {
     fn extend<T: IntoIterator<Item = Pair<'a, Rule>>>(&mut self, iter: T) {
         // Map into iter.
@@ -255,14 +348,9 @@ impl<'a> From<Pair<'a, Rule>> for Vertex {
         assert!(matches!(id_port.as_rule(), Rule::vertex_id));
         // Match inner rules.
         let mut id_port = id_port.into_inner();
-        let mut id = id_port.next().unwrap().as_str().to_owned();
-        let port = id_port.next().map(|x| x.as_str().into());
-
         // Unquote the vertex id, if quoted.
-        if id.starts_with('"') && id.ends_with('"') {
-            // Remove quotes.
-            id = id[1..id.len() - 1].replace("\\\"", "\"");
-        }
+        let id = unquote(id_port.next().unwrap().as_str());
+        let port = id_port.next().map(|x| x.as_str().into());
 
         // Match inner rules.
         let attributes = inner.next().map(|x| x.into()).unwrap_or_default();
@@ -365,9 +453,8 @@ impl<'a> From<Pairs<'a, Rule>> for _Path {
             .map(|(from, op, to)| {
                 // Get `from` vertex id.
                 let from = match from.as_rule() {
-                    // TODO: Add support for subgraphs.
-                    Rule::subgraph => todo!("Subgraphs not supported yet"),
-                    Rule::vertex_id => from.into_inner().next().unwrap().as_str().into(),
+                    // Subgraphs are rejected while checking the statements.
+                    Rule::vertex_id => unquote(from.into_inner().next().unwrap().as_str()),
                     _ => unreachable!(),
                 };
                 // Assert edge operator direction.
@@ -376,9 +463,8 @@ impl<'a> From<Pairs<'a, Rule>> for _Path {
                 let op = op.as_str().into();
                 // Get `to` vertex id.
                 let to = match to.as_rule() {
-                    // TODO: Add support for subgraphs.
-                    Rule::subgraph => todo!("Subgraphs not supported yet"),
-                    Rule::vertex_id => to.into_inner().next().unwrap().as_str().into(),
+                    // Subgraphs are rejected while checking the statements.
+                    Rule::vertex_id => unquote(to.into_inner().next().unwrap().as_str()),
                     _ => unreachable!(),
                 };
 
@@ -426,7 +512,7 @@ impl<'a> From<Pairs<'a, Rule>> for _Statements {
                 statements.attributes.insert_raw_parts(key, value);
             }
             Rule::global_attributes => statements.global_attributes.extend(pair.into_inner()),
-            // TODO: Add support for subgraphs.
+            // Subgraphs are rejected while checking the statements.
             Rule::subgraph => {}
             Rule::vertex => {
                 // Match vertex rule.
@@ -497,8 +583,8 @@ impl<'a> From<Pair<'a, Rule>> for DOT {
         // Assert rule match.
         let graph_type = inner.next().unwrap();
         assert!(matches!(graph_type.as_rule(), Rule::graph_type));
-        // Match inner rules.
-        let graph_type = graph_type.as_str().into();
+        // Match inner rules, since keywords are case-insensitive.
+        let graph_type = graph_type.as_str().to_lowercase();
 
         // Assert rule match.
         let id = inner.next().unwrap();
@@ -586,6 +672,16 @@ impl TryFrom<String> for DOT {
     fn try_from(string: String) -> Result<Self, Self::Error> {
         // Parse the given string.
         let dot = Self::parse(Rule::file, string.trim())?;
+        // Check the attributes keys of each graph.
+        for graph in dot.clone().filter(|x| matches!(x.as_rule(), Rule::graph)) {
+            // Get the graph statements.
+            let statements = graph
+                .into_inner()
+                .find(|x| matches!(x.as_rule(), Rule::statements))
+                .unwrap();
+            // Check the attributes keys.
+            check_statements(statements.into_inner())?;
+        }
         // Match inner rules. TODO: Match more than one graph.
         let dot: Self = dot.map_into().next().unwrap();
 
@@ -759,3 +855,230 @@ impl From<PartiallyDenseAdjacencyMatrixGraph> for DOT {
         }
     }
 }
+
+/// Render a graph to DOT source.
+///
+/// The edge marks are styled w.r.t. the graph type, see the [`DOT`] conversions:
+///
+/// * directed edges `X -> Y` are rendered as arrows in a `digraph`,
+/// * undirected edges `X -- Y` are rendered as plain lines in a `graph`,
+/// * undirected edges of partially directed graphs are rendered as `X -> Y [ dir = none; ]`.
+///
+pub trait DotRenderable {
+    /// Render to DOT source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    ///
+    /// // Build a new partially directed graph with the undirected edge B -- C.
+    /// let mut g = PDGraph::new(["A", "B", "C"], [("B", "C")]);
+    /// // Add the directed edge A -> B.
+    /// g.add_directed_edge_by_index(0, 1);
+    ///
+    /// // Render it to DOT.
+    /// let dot = g.to_dot();
+    ///
+    /// assert!(dot.contains("\"A\" -> \"B\"\n"));
+    /// assert!(dot.contains("\"B\" -> \"C\" [ dir = none; ]\n"));
+    /// ```
+    ///
+    fn to_dot(&self) -> String;
+}
+
+impl<G> DotRenderable for G
+where
+    G: Clone + Into<DOT>,
+{
+    #[inline]
+    fn to_dot(&self) -> String {
+        String::from(self.clone().into())
+    }
+}
+
+/// Parse DOT source, checking the graph type.
+fn parse_dot_with(s: &str, graph_type: &str) -> Result<DOT, ParserError<Rule>> {
+    // Parse the given string.
+    let dot = DOT::try_from(s.to_owned())?;
+    // Check the graph type.
+    if dot.graph_type != graph_type {
+        return Err(ParserError::new_from_pos(
+            ErrorVariant::CustomError {
+                message: format!("DOT graph type must be `{graph_type}`"),
+            },
+            Position::from_start(s),
+        ));
+    }
+
+    Ok(dot)
+}
+
+impl UndirectedDenseAdjacencyMatrixGraph {
+    /// Parse an undirected graph from DOT source.
+    ///
+    /// # Errors
+    ///
+    /// The source must be a valid `graph` with valid attributes keys, otherwise
+    /// the error points to the location of the offending token.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    ///
+    /// // Parse a graph with quoted identifiers and a chain of edges.
+    /// let g = Graph::from_dot("graph { \"A\" -- B -- C [color = red] }").unwrap();
+    ///
+    /// assert!(L!(g).eq(["A", "B", "C"]));
+    /// assert!(E!(g).eq([(0, 1), (1, 2)]));
+    /// ```
+    ///
+    pub fn from_dot(s: &str) -> Result<Self, ParserError<Rule>> {
+        Ok(parse_dot_with(s, "graph")?.into())
+    }
+}
+
+impl DirectedDenseAdjacencyMatrixGraph {
+    /// Parse a directed graph from DOT source.
+    ///
+    /// # Errors
+    ///
+    /// The source must be a valid `digraph` with valid attributes keys, otherwise
+    /// the error points to the location of the offending token.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    ///
+    /// // Parse a graph with default attributes and a chain of edges.
+    /// let g = DiGraph::from_dot("digraph { node [shape = box]; A -> B -> C }").unwrap();
+    ///
+    /// assert!(L!(g).eq(["A", "B", "C"]));
+    /// assert!(E!(g).eq([(0, 1), (1, 2)]));
+    ///
+    /// // Invalid attributes keys are reported.
+    /// assert!(DiGraph::from_dot("digraph { A -> B [foo = bar] }").is_err());
+    /// ```
+    ///
+    pub fn from_dot(s: &str) -> Result<Self, ParserError<Rule>> {
+        Ok(parse_dot_with(s, "digraph")?.into())
+    }
+}
+
+/// DOT file I/O error.
+#[derive(Debug)]
+pub enum DotIOError {
+    /// The file could not be read.
+    IO(IOError),
+    /// The file content is not valid DOT source.
+    Parser(ParserError<Rule>),
+}
+
+impl std::fmt::Display for DotIOError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IO(e) => write!(f, "Failed to read DOT file: {e}"),
+            Self::Parser(e) => write!(f, "Failed to parse DOT file: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for DotIOError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::IO(e) => Some(e),
+            Self::Parser(e) => Some(e),
+        }
+    }
+}
+
+impl From<IOError> for DotIOError {
+    #[inline]
+    fn from(e: IOError) -> Self {
+        Self::IO(e)
+    }
+}
+
+impl From<ParserError<Rule>> for DotIOError {
+    #[inline]
+    fn from(e: ParserError<Rule>) -> Self {
+        Self::Parser(e)
+    }
+}
+
+/// Read and write graphs from and to DOT files.
+pub trait DotIO: Sized {
+    /// Read a graph from a DOT file.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the file cannot be read, or if its content is not a valid DOT graph
+    /// of the expected type, see [`DotIOError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    ///
+    /// // Missing files are reported as I/O errors.
+    /// let g = DiGraph::read_dot("./missing.dot");
+    ///
+    /// assert!(matches!(g, Err(DotIOError::IO(_))));
+    /// ```
+    ///
+    fn read_dot<P>(path: P) -> Result<Self, DotIOError>
+    where
+        P: Into<PathBuf>;
+
+    /// Write a graph to a DOT file.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the file cannot be written.
+    ///
+    fn write_dot<P>(&self, path: P) -> Result<(), IOError>
+    where
+        P: Into<PathBuf>;
+}
+
+impl DotIO for UndirectedDenseAdjacencyMatrixGraph {
+    fn read_dot<P>(path: P) -> Result<Self, DotIOError>
+    where
+        P: Into<PathBuf>,
+    {
+        // Read file to string.
+        let dot = std::fs::read_to_string(path.into())?;
+        // Parse string.
+        Ok(Self::from_dot(&dot)?)
+    }
+
+    fn write_dot<P>(&self, path: P) -> Result<(), IOError>
+    where
+        P: Into<PathBuf>,
+    {
+        // Write string to file.
+        std::fs::write(path.into(), self.to_dot())
+    }
+}
+
+impl DotIO for DirectedDenseAdjacencyMatrixGraph {
+    fn read_dot<P>(path: P) -> Result<Self, DotIOError>
+    where
+        P: Into<PathBuf>,
+    {
+        // Read file to string.
+        let dot = std::fs::read_to_string(path.into())?;
+        // Parse string.
+        Ok(Self::from_dot(&dot)?)
+    }
+
+    fn write_dot<P>(&self, path: P) -> Result<(), IOError>
+    where
+        P: Into<PathBuf>,
+    {
+        // Write string to file.
+        std::fs::write(path.into(), self.to_dot())
+    }
+}
@@ -1,10 +1,10 @@
 /// `BIF` language module
 pub mod bif;
-pub use bif::BIF;
+pub use bif::{BifError, ToBifOptions, BIF};
 
 /// `DOT` language module.
 pub mod dot;
-pub use dot::DOT;
+pub use dot::{DotIO, DotIOError, DotRenderable, DOT};
 
 /// `GML` language module.
 pub mod gml;
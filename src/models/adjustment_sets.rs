@@ -0,0 +1,197 @@
+use itertools::Itertools;
+
+use super::{GraphicalSeparation, Independence};
+use crate::{
+    graphs::{structs::DirectedDenseAdjacencyMatrixGraph, BaseGraph, DirectedGraph},
+    types::FxIndexSet,
+    An, Ch, De, Pa,
+};
+
+/// Private function. It returns the graph without the edges out of $X$.
+fn backdoor_graph(
+    g: &DirectedDenseAdjacencyMatrixGraph,
+    x: usize,
+) -> DirectedDenseAdjacencyMatrixGraph {
+    // Clone the graph.
+    let mut h = g.clone();
+    // Remove the edges out of X.
+    for y in Ch!(g, x).collect_vec() {
+        h.del_edge_by_index(x, y);
+    }
+
+    h
+}
+
+/// Check if $\mathbf{Z}$ satisfies the backdoor criterion relative to $(X, Y)$.
+///
+/// That is, no vertex in $\mathbf{Z}$ is a descendant of $X$, and $\mathbf{Z}$ blocks every
+/// path between $X$ and $Y$ that starts with an edge into $X$, i.e. $X$ and $Y$ are d-separated
+/// by $\mathbf{Z}$ in the graph without the edges out of $X$.
+///
+/// # Panics
+///
+/// $X$, $Y$ and $\mathbf{Z}$ must be disjoint subsets of the vertices.
+///
+pub fn is_backdoor_adjustment_set(
+    g: &DirectedDenseAdjacencyMatrixGraph,
+    x: usize,
+    y: usize,
+    z: &[usize],
+) -> bool {
+    // Check Z contains no descendants of X.
+    let de_x: FxIndexSet<_> = De!(g, x).collect();
+    if z.iter().any(|z| de_x.contains(z)) {
+        return false;
+    }
+    // Check Z blocks the backdoor paths.
+    GraphicalSeparation::new(&backdoor_graph(g, x)).is_independent(x, y, z)
+}
+
+/// Enumerate the minimal backdoor adjustment sets relative to $(X, Y)$.
+///
+/// A backdoor adjustment set is minimal if none of its proper subsets is one.
+/// Since minimal sets are made of ancestors of $X$ or $Y$, the candidates are the subsets of
+/// $(An(X) \cup An(Y)) \setminus (De(X) \cup \{X, Y\})$, visited by increasing size, hence
+/// the worst-case complexity is exponential in the number of such ancestors.
+///
+/// # Panics
+///
+/// $X$ and $Y$ must be distinct vertices.
+///
+/// # Examples
+///
+/// ```
+/// use causal_hub::prelude::*;
+///
+/// // Build a new directed graph.
+/// let g = DiGraph::new(
+///     ["W", "X", "Y", "Z1", "Z2", "Z3"],
+///     [
+///         ("Z1", "X"),
+///         ("Z1", "Z3"),
+///         ("Z2", "Z3"),
+///         ("Z2", "Y"),
+///         ("Z3", "X"),
+///         ("Z3", "Y"),
+///         ("X", "W"),
+///         ("W", "Y"),
+///     ],
+/// );
+///
+/// // Enumerate the minimal adjustment sets.
+/// let z = backdoor_adjustment_sets(&g, 1, 2).collect::<Vec<_>>();
+///
+/// // Check conditioning on the collider Z3 requires either Z1 or Z2.
+/// assert_eq!(z, [FxIndexSet::from_iter([3, 5]), FxIndexSet::from_iter([4, 5])]);
+/// ```
+///
+pub fn backdoor_adjustment_sets(
+    g: &DirectedDenseAdjacencyMatrixGraph,
+    x: usize,
+    y: usize,
+) -> impl Iterator<Item = FxIndexSet<usize>> {
+    // Assert X and Y are distinct.
+    assert_ne!(x, y, "X and Y must be distinct");
+
+    // Get the forbidden vertices, i.e. De(X) U {X, Y}.
+    let forbidden: FxIndexSet<_> = De!(g, x).chain([x, y]).collect();
+    // Get the candidate vertices, i.e. the ancestors of X or Y not forbidden.
+    let candidates = An!(g, x)
+        .chain(An!(g, y))
+        .filter(|z| !forbidden.contains(z))
+        .sorted()
+        .dedup()
+        .collect_vec();
+    // Get the graph without the edges out of X.
+    let h = backdoor_graph(g, x);
+
+    // Initialize the minimal sets found so far.
+    let mut minimal: Vec<FxIndexSet<usize>> = vec![];
+    // Visit the candidate subsets by increasing size.
+    candidates.into_iter().powerset().filter_map(move |z| {
+        // Skip the supersets of the minimal sets found so far.
+        if minimal.iter().any(|m| m.iter().all(|w| z.contains(w))) {
+            return None;
+        }
+        // Skip the sets that do not block the backdoor paths.
+        if !GraphicalSeparation::new(&h).is_independent(x, y, &z) {
+            return None;
+        }
+        // Otherwise, it is minimal, since no valid proper subset was found.
+        let z: FxIndexSet<_> = z.into_iter().collect();
+        minimal.push(z.clone());
+
+        Some(z)
+    })
+}
+
+/// Compute the optimal adjustment set, i.e. the O-set, relative to $(X, Y)$.
+///
+/// Among the valid adjustment sets, the O-set[^1] yields the smallest asymptotic variance
+/// of the estimated causal effect of $X$ on $Y$. It is defined as:
+///
+/// $$ \mathbf{O}(X, Y) = Pa(\mathbf{C}) \setminus (De(\mathbf{C}) \cup \mathbf{C} \cup \{X\}) $$
+///
+/// where $\mathbf{C} = De(X) \cap (An(Y) \cup \{Y\})$ are the vertices on the causal paths from $X$ to $Y$,
+/// excluding $X$. Since it contains no descendants of $X$, it also satisfies the backdoor criterion.
+///
+/// [^1]: [Henckel, L., Perković, E., & Maathuis, M. H. (2022). Graphical criteria for efficient total effect estimation via adjustment in causal linear models. Journal of the Royal Statistical Society Series B, 84(2), 579-599.](https://scholar.google.com/scholar?q=Graphical+criteria+for+efficient+total+effect+estimation+via+adjustment+in+causal+linear+models)
+///
+/// # Panics
+///
+/// $Y$ must be a descendant of $X$.
+///
+/// # Examples
+///
+/// ```
+/// use causal_hub::prelude::*;
+///
+/// // Build a new directed graph.
+/// let g = DiGraph::new(
+///     ["W", "X", "Y", "Z1", "Z2", "Z3"],
+///     [
+///         ("Z1", "X"),
+///         ("Z1", "Z3"),
+///         ("Z2", "Z3"),
+///         ("Z2", "Y"),
+///         ("Z3", "X"),
+///         ("Z3", "Y"),
+///         ("X", "W"),
+///         ("W", "Y"),
+///     ],
+/// );
+///
+/// // Compute the optimal adjustment set.
+/// let o = optimal_adjustment_set(&g, 1, 2);
+///
+/// // Check it prefers Z2, which is a parent of Y, over Z1.
+/// assert_eq!(o, FxIndexSet::from_iter([4, 5]));
+/// ```
+///
+pub fn optimal_adjustment_set(
+    g: &DirectedDenseAdjacencyMatrixGraph,
+    x: usize,
+    y: usize,
+) -> FxIndexSet<usize> {
+    // Assert Y is a descendant of X.
+    assert!(De!(g, x).any(|z| z == y), "Y must be a descendant of X");
+
+    // Get An(Y) U {Y}.
+    let an_y: FxIndexSet<_> = An!(g, y).chain([y]).collect();
+    // Get the causal vertices, i.e. De(X) and An(Y) U {Y}.
+    let c: FxIndexSet<_> = De!(g, x).filter(|z| an_y.contains(z)).collect();
+    // Get the forbidden vertices, i.e. De(C) U C U {X}.
+    let forbidden: FxIndexSet<_> = c
+        .iter()
+        .flat_map(|&z| De!(g, z).chain([z]))
+        .chain([x])
+        .collect();
+
+    // Get the parents of the causal vertices that are not forbidden.
+    c.iter()
+        .flat_map(|&z| Pa!(g, z))
+        .filter(|z| !forbidden.contains(z))
+        .sorted()
+        .dedup()
+        .collect()
+}
@@ -2,24 +2,25 @@ use std::fmt::{Debug, Display, Formatter};
 
 use is_sorted::IsSorted;
 use itertools::Itertools;
-use ndarray::{prelude::*, SliceInfoElem as SIE};
+use ndarray::{prelude::*, SliceInfoElem as SIE, Zip};
+use ndarray_rand::rand_distr::Gamma;
 use rand::{distributions::WeightedIndex, prelude::*};
 use rayon::prelude::*;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 use super::{
     CategoricalCPD, CategoricalFactor, CategoricalJPD, ConditionalProbabilityDistribution, Factor,
-    JointProbabilityDistribution,
+    JointProbabilityDistribution, VariableElimination,
 };
 use crate::{
-    graphs::{directions, structs::DirectedDenseAdjacencyMatrixGraph, DirectedGraph},
-    io::BIF,
+    graphs::{directions, structs::DirectedDenseAdjacencyMatrixGraph, DirectedGraph, SubGraph},
+    io::{BIF, DOT},
     prelude::{
         algorithms::traversal::TopologicalSort, BaseGraph, CategoricalDataMatrix, DataSet,
         PathGraph,
     },
     types::FxIndexMap,
-    Pa, L, V,
+    An, Ch, Pa, L, V,
 };
 
 /// Probabilistic Graphical Model (PGM) trait.
@@ -49,6 +50,9 @@ pub trait ProbabilisticGraphicalModel:
     fn graph(&self) -> &Self::Graph;
 
     /// Reference to the parameters.
+    ///
+    /// The parameters are sorted w.r.t. the variables labels, so that their iteration
+    /// order (and therefore serialization) is deterministic.
     fn parameters(&self) -> &FxIndexMap<String, Self::Parameter>;
 
     /// Draw `n` samples.
@@ -71,13 +75,84 @@ pub trait BayesianNetwork: ProbabilisticGraphicalModel + PartialEq + Eq {
         I: IntoIterator<Item = Self::Parameter>;
 }
 
+/// Model well-formedness error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ModelError {
+    /// The variable in the graph has no associated parameter.
+    MissingParameter(String),
+    /// The parameter has no associated variable in the graph.
+    UnknownParameter(String),
+    /// The parameter scope does not match the variable and its parents in the graph.
+    InconsistentScope(String),
+    /// The parameter does not sum to one over the variable states.
+    NotNormalized(String),
+    /// The graph is not acyclic.
+    Cyclic,
+}
+
+impl Display for ModelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingParameter(x) => write!(f, "Variable `{x}` has no parameter"),
+            Self::UnknownParameter(x) => write!(f, "Parameter `{x}` has no variable"),
+            Self::InconsistentScope(x) => {
+                write!(f, "Parameter `{x}` is inconsistent with the graph")
+            }
+            Self::NotNormalized(x) => write!(f, "Parameter `{x}` must sum to one"),
+            Self::Cyclic => write!(f, "Graph must be acyclic"),
+        }
+    }
+}
+
+impl std::error::Error for ModelError {}
+
 /// Categorical Bayesian Network $\mathcal{B}$.
+///
+/// It is `Send + Sync`, while inference and sampling only borrow it, hence a single
+/// network can be shared across threads behind an [`Arc`](std::sync::Arc) without cloning.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CategoricalBayesianNetwork {
+    #[serde(deserialize_with = "deserialize_acyclic")]
     graph: DirectedDenseAdjacencyMatrixGraph,
+    #[serde(deserialize_with = "deserialize_sorted")]
     theta: FxIndexMap<String, CategoricalCPD>,
 }
 
+/// Deserialize the graph rejecting the cyclic ones.
+fn deserialize_acyclic<'de, D>(
+    deserializer: D,
+) -> Result<DirectedDenseAdjacencyMatrixGraph, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    // Deserialize the graph.
+    let graph = DirectedDenseAdjacencyMatrixGraph::deserialize(deserializer)?;
+    // Check the graph is acyclic.
+    if let Some(cycle) = graph.find_cycle() {
+        return Err(serde::de::Error::custom(format!(
+            "Graph must be acyclic, found cycle: {}",
+            graph.format_cycle(&cycle)
+        )));
+    }
+
+    Ok(graph)
+}
+
+/// Deserialize the parameters sorting them w.r.t. the variables labels.
+fn deserialize_sorted<'de, D>(
+    deserializer: D,
+) -> Result<FxIndexMap<String, CategoricalCPD>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    // Deserialize the parameters.
+    let mut theta = FxIndexMap::<String, CategoricalCPD>::deserialize(deserializer)?;
+    // Sort the parameters w.r.t. the variables labels.
+    theta.sort_keys();
+
+    Ok(theta)
+}
+
 impl Display for CategoricalBayesianNetwork {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         // Iterate over the CPDs.
@@ -90,6 +165,550 @@ impl Display for CategoricalBayesianNetwork {
     }
 }
 
+impl CategoricalBayesianNetwork {
+    /// Construct a random $\mathcal{B}$ given the graph $\mathcal{G}$ and the variables cardinalities.
+    ///
+    /// Each conditional distribution $\mathcal{P}(X \mid \mathbf{Z} = \mathbf{z})$
+    /// is drawn from a symmetric Dirichlet prior with concentration `alpha`,
+    /// while the states of each variable are labelled by their index.
+    ///
+    /// # Panics
+    ///
+    /// The cardinalities must be aligned w.r.t. the vertices and strictly positive,
+    /// the concentration must be strictly positive and the graph must be acyclic.
+    pub fn random<R: Rng>(
+        graph: DirectedDenseAdjacencyMatrixGraph,
+        cardinality: &[usize],
+        rng: &mut R,
+        alpha: f64,
+    ) -> Self {
+        // Assert cardinalities are aligned w.r.t. the vertices.
+        assert_eq!(
+            cardinality.len(),
+            graph.order(),
+            "Cardinalities must be aligned w.r.t. the vertices"
+        );
+        // Assert cardinalities are strictly positive.
+        assert!(
+            cardinality.iter().all(|&k| k > 0),
+            "Cardinalities must be strictly positive"
+        );
+        // Initialize the Dirichlet prior by means of normalized Gamma draws.
+        let gamma = Gamma::new(alpha, 1.).expect("Concentration must be strictly positive");
+
+        // Get the states of each variable.
+        let states = cardinality
+            .iter()
+            .map(|&k| (0..k).map(|i| i.to_string()).collect_vec())
+            .collect_vec();
+
+        // For each vertex in the graph ...
+        let theta = V!(graph)
+            .map(|x| {
+                // Get Pa(X).
+                let pa_x = Pa!(graph, x).collect_vec();
+                // Compute the number of parents configurations.
+                let n = pa_x.iter().map(|&z| cardinality[z]).product::<usize>();
+                // Draw P(X | Pa(X) = pa(x)) for each configuration.
+                let mut values =
+                    Array2::from_shape_simple_fn((n, cardinality[x]), || gamma.sample(rng));
+                values /= &values.sum_axis(Axis(1)).insert_axis(Axis(1));
+                // Get the target and its states.
+                let x = (graph.get_vertex_by_index(x), states[x].clone());
+                // Get the conditioning variables and their states.
+                let z = pa_x
+                    .into_iter()
+                    .map(|z| (graph.get_vertex_by_index(z), states[z].clone()));
+                // Construct CPD from states and values.
+                CategoricalCPD::new(x, z, values)
+            })
+            .collect_vec();
+
+        Self::new(graph, theta)
+    }
+
+    /// Compute the log-density $\log \mathcal{P}(\mathbf{X} = \mathbf{x})$ of a given sample.
+    ///
+    /// The sample $\mathbf{x}$ is encoded as the vector of states indices,
+    /// aligned w.r.t. the sorted variables labels, as in the sampled data sets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sample length is not equal to the number of variables.
+    pub fn log_density(&self, x: ArrayView1<u8>) -> f64 {
+        // Assert sample length matches the number of variables.
+        assert_eq!(
+            x.len(),
+            self.graph.order(),
+            "Sample length must be equal to the number of variables"
+        );
+
+        // For each vertex in the graph ...
+        V!(self.graph)
+            .map(|i| {
+                // Get Pa(X) U {X}, sorted as the CPD scope.
+                let scope = Pa!(self.graph, i).chain([i]).sorted();
+                // Get the P(X | Pa(X)) indices.
+                let indices = scope.map(|z| x[z] as usize).collect_vec();
+                // Get log P(X = x | Pa(X) = pa(x)).
+                self.theta[i].values()[indices.as_slice()].ln()
+            })
+            .sum()
+    }
+
+    /// Compute the observed log-likelihood $\sum_i \log P(\mathbf{X}_{o_i} = \mathbf{x}_{o_i})$
+    /// of a partially observed data set.
+    ///
+    /// Each row is a sample aligned w.r.t. the sorted variables labels, where
+    /// missing values are `None`. Per row, the observed values are used as evidence
+    /// while the missing variables are summed-out by variable elimination. Rows
+    /// with the same missingness pattern share the same marginal distribution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of columns is not equal to the number of variables.
+    pub fn observed_log_likelihood(&self, d: ArrayView2<Option<u8>>) -> f64 {
+        // Assert number of columns matches the number of variables.
+        assert_eq!(
+            d.ncols(),
+            self.graph.order(),
+            "Number of columns must be equal to the number of variables"
+        );
+
+        // Initialize the variable elimination functor.
+        let ve = VariableElimination::<_, false>::new(self);
+        // Initialize the marginal distributions cache w.r.t. the missingness pattern.
+        let mut cache: FxIndexMap<Vec<usize>, CategoricalFactor> = Default::default();
+
+        // For each row in the data set ...
+        d.rows()
+            .into_iter()
+            .map(|x| {
+                // Get the observed variables.
+                let o = V!(self.graph).filter(|&i| x[i].is_some()).collect_vec();
+                // If no variable is observed, then the log-density is zero.
+                if o.is_empty() {
+                    return 0.;
+                }
+                // Get the observed values.
+                let indices = o.iter().map(|&i| x[i].unwrap() as usize).collect_vec();
+                // Get the marginal distribution of the observed variables, computing it if not cached.
+                let phi = cache.entry(o).or_insert_with_key(|o| {
+                    ve.call(o.iter().map(|&i| self.graph.get_vertex_by_index(i)))
+                });
+                // Get log P(X_o = x_o), with the scope sorted as the variables labels.
+                phi.values()[indices.as_slice()].ln()
+            })
+            .sum()
+    }
+
+    /// Compute the log-likelihood of a data set decomposed by variable, i.e.
+    /// $\sum_i \log \mathcal{P}(X = x_i \mid Pa(X) = pa(x)_i)$ for each variable $X$.
+    ///
+    /// The contributions sum to the log-likelihood of the data set, hence the ones
+    /// that are low w.r.t. the associated variables entropy reveal the poorly modelled
+    /// variables. See [`Self::log_density`] for the samples encoding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data set variables are not the network variables.
+    pub fn log_likelihood_by_node(&self, d: &CategoricalDataMatrix) -> FxIndexMap<String, f64> {
+        // Assert data set and network have the same variables.
+        assert!(
+            d.labels_iter().eq(L!(self.graph)),
+            "Data set and network must have the same variables"
+        );
+
+        // For each vertex in the graph ...
+        V!(self.graph)
+            .zip(L!(self.graph))
+            .map(|(i, x)| {
+                // Get Pa(X) U {X}, sorted as the CPD scope.
+                let scope = Pa!(self.graph, i).chain([i]).sorted().collect_vec();
+                // Get the CPD values.
+                let values = self.theta[i].values();
+                // Sum log P(X = x | Pa(X) = pa(x)) over the samples.
+                let ll = d
+                    .data()
+                    .rows()
+                    .into_iter()
+                    .map(|s| {
+                        // Get the P(X | Pa(X)) indices.
+                        let indices = scope.iter().map(|&z| s[z] as usize).collect_vec();
+                        // Get log P(X = x | Pa(X) = pa(x)).
+                        values[indices.as_slice()].ln()
+                    })
+                    .sum();
+
+                (x.to_owned(), ll)
+            })
+            .collect()
+    }
+
+    /// Check that $\mathcal{B}$ is well-formed, collecting all the errors found.
+    ///
+    /// Each variable must have a parameter, each parameter scope must match the
+    /// variable and its parents, each parameter must sum to one over the variable
+    /// states and the graph must be acyclic.
+    ///
+    /// This is useful for models that are not checked at construction,
+    /// e.g. deserialized models.
+    pub fn validate(&self) -> Result<(), Vec<ModelError>> {
+        // Initialize the errors.
+        let mut errors = vec![];
+
+        // For each variable in the graph ...
+        for (i, x) in V!(self.graph).zip(L!(self.graph)) {
+            // Get the associated parameter, if any.
+            let Some(t) = self.theta.get(x) else {
+                // Report missing parameter.
+                errors.push(ModelError::MissingParameter(x.to_owned()));
+                continue;
+            };
+            // Check the target and scope are consistent with the parents.
+            let is_consistent = t.target() == x
+                && Pa!(self.graph, i)
+                    .map(|y| self.graph.get_vertex_by_index(y))
+                    .eq(t.scope().filter(|&z| z != x));
+            // Report inconsistent scope.
+            if !is_consistent {
+                errors.push(ModelError::InconsistentScope(x.to_owned()));
+                continue;
+            }
+            // Check the parameter sums to one over the target axis.
+            let j = t.states().get_index_of(x).unwrap();
+            let is_normalized = t
+                .values()
+                .sum_axis(Axis(j))
+                .iter()
+                .all(|s| (s - 1.).abs() < 1e-8);
+            // Report not normalized parameter.
+            if !is_normalized {
+                errors.push(ModelError::NotNormalized(x.to_owned()));
+            }
+        }
+
+        // For each parameter not in the graph, report unknown parameter.
+        errors.extend(
+            self.theta
+                .keys()
+                .filter(|x| !L!(self.graph).any(|y| y == x.as_str()))
+                .map(|x| ModelError::UnknownParameter(x.to_owned())),
+        );
+
+        // Check acyclicity.
+        if !self.graph.is_acyclic() {
+            errors.push(ModelError::Cyclic);
+        }
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors),
+        }
+    }
+
+    /// Get the CPD $\mathcal{P}(X \mid Pa(X))$ of a given variable.
+    ///
+    /// The returned CPD can be further reduced w.r.t. some evidence with
+    /// [`CategoricalCPD::condition`].
+    ///
+    /// # Panics
+    ///
+    /// The variable label must be in the network.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    ///
+    /// // Read BN from BIF.
+    /// let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+    ///
+    /// // Get the CPD of `dysp` given its parents.
+    /// let phi = b.cpd("dysp");
+    /// assert!(phi.scope().eq(["bronc", "dysp", "either"]));
+    ///
+    /// // Condition it on `bronc = yes`.
+    /// let e = [("bronc".to_string(), "yes".to_string())].into_iter().collect();
+    /// let psi = phi.condition(&e, true);
+    /// assert!(psi.scope().eq(["dysp", "either"]));
+    /// ```
+    ///
+    pub fn cpd(&self, x: &str) -> &CategoricalCPD {
+        self.theta
+            .get(x)
+            .unwrap_or_else(|| panic!("No variable with label `{x}`"))
+    }
+
+    /// Get the variables labels in topological order.
+    ///
+    /// The order is the one induced by [`TopologicalSort`] on the underlying graph,
+    /// hence it is deterministic, with ties broken by label.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    ///
+    /// // Read BN from BIF.
+    /// let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+    ///
+    /// // Get the variables in topological order.
+    /// let order = b.topological_order_labels();
+    /// assert_eq!(order[..2], ["asia", "smoke"]);
+    /// ```
+    ///
+    pub fn topological_order_labels(&self) -> Vec<&str> {
+        TopologicalSort::new(&self.graph)
+            .map(|x| self.graph.get_vertex_by_index(x))
+            .collect()
+    }
+
+    /// Compute the marginal distribution $\mathcal{P}(X)$ as a parentless CPD.
+    ///
+    /// Since the non-ancestors of $X$ are barren w.r.t. $X$, only the ancestral
+    /// sub-network of $X$ is considered during variable elimination.
+    ///
+    /// # Panics
+    ///
+    /// The variable label must be in the network.
+    pub fn marginal(&self, x: &str) -> CategoricalCPD {
+        // Get the variable index.
+        let i = self.graph.get_vertex_index(x);
+        // Get the ancestral set An(X) U {X}, sorted.
+        let a = An!(self.graph, i).chain([i]).sorted().collect_vec();
+        // Construct the ancestral sub-network.
+        let b = Self::new(
+            self.graph.subgraph_by_vertices(a.iter().copied()),
+            a.iter().map(|&j| self.theta[j].clone()),
+        );
+        // Compute P(X) by variable elimination over the ancestral sub-network.
+        let phi = VariableElimination::<_, false>::new(&b).call([x]);
+
+        CategoricalCPD::from_factor(x, phi)
+    }
+
+    /// Private method. It predicts the most probable state of $X$ for each sample.
+    fn predict_with<const PARALLEL: bool>(&self, d: &CategoricalDataMatrix, x: &str) -> Array1<u8> {
+        // Assert data set and network have the same variables.
+        assert!(
+            d.labels_iter().eq(L!(self.graph)),
+            "Data set and network must have the same variables"
+        );
+
+        // Get the variable index.
+        let i = self.graph.get_vertex_index(x);
+        // Compute P(X | Pa(X)) * Prod_{Y in Ch(X)} P(Y | Pa(Y)), i.e. the unnormalized P(X | Mb(X)).
+        let phi = Ch!(self.graph, i)
+            .map(|j| CategoricalFactor::from(self.theta[j].clone()))
+            .fold(
+                CategoricalFactor::from(self.theta[i].clone()),
+                |phi, psi| phi * psi,
+            );
+        // Get Mb(X) U {X}, sorted as the factor scope.
+        let scope = phi
+            .scope()
+            .map(|z| self.graph.get_vertex_index(z))
+            .collect_vec();
+        // Get the factor values.
+        let values = phi.values();
+
+        // Predict the most probable state of X given Mb(X) of a sample.
+        let f = |y: &mut u8, row: ArrayView1<u8>| {
+            // Set P(X | Mb(X)) indices.
+            let indices = scope
+                .iter()
+                .map(|&z| match z == i {
+                    true => (..).into(),
+                    false => SIE::Index(row[z] as isize),
+                })
+                .collect_vec();
+            // Get the unnormalized P(X | Mb(X)) values.
+            let weights = values.slice(indices.as_slice());
+            // Select the first state with maximum probability.
+            let (k, _) =
+                weights
+                    .iter()
+                    .enumerate()
+                    .fold((0, f64::NEG_INFINITY), |(k, m), (j, &w)| match w > m {
+                        true => (j, w),
+                        false => (k, m),
+                    });
+            // Assign predicted value.
+            *y = k.try_into().unwrap();
+        };
+
+        // Allocate the predicted values.
+        let mut y = Array1::<u8>::zeros(d.sample_size());
+        // For each sample ...
+        let zip = Zip::from(&mut y).and(d.data().rows());
+        match PARALLEL {
+            true => zip.par_for_each(f),
+            false => zip.for_each(f),
+        }
+
+        y
+    }
+
+    /// Predict the most probable state of $X$ for each sample, i.e. $\arg\max_x \mathcal{P}(X = x \mid \mathbf{V} \setminus \{X\})$.
+    ///
+    /// Since the samples are complete, the posterior of $X$ depends only on its Markov blanket:
+    ///
+    /// $$ \mathcal{P}(X \mid Mb(X)) \propto \mathcal{P}(X \mid Pa(X)) \prod_{Y \in Ch(X)} \mathcal{P}(Y \mid Pa(Y)) $$
+    ///
+    /// hence such factor is computed once and the per-sample cost is a lookup. The values of $X$
+    /// in the data set are ignored and the predictions are encoded as its states indices.
+    /// Ties are broken by the first state.
+    ///
+    /// # Panics
+    ///
+    /// The data set variables must be the network variables and $X$ must be in the network.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    /// use rand::SeedableRng;
+    /// use rand_xoshiro::Xoshiro256PlusPlus;
+    ///
+    /// // Read BN from BIF.
+    /// let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+    ///
+    /// // Sample a data set.
+    /// let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+    /// let d = b.sample(&mut rng, 100);
+    ///
+    /// // Predict `either`, which is the logical OR of `lung` and `tub`.
+    /// let y = b.predict(&d, "either");
+    ///
+    /// // Check the predictions are exact.
+    /// let i = d.labels().get_index_of("either").unwrap();
+    /// assert_eq!(y, d.data().column(i));
+    /// ```
+    ///
+    #[inline]
+    pub fn predict(&self, d: &CategoricalDataMatrix, x: &str) -> Array1<u8> {
+        self.predict_with::<false>(d, x)
+    }
+
+    /// Predict the most probable state of $X$ for each sample in parallel.
+    ///
+    /// See [`Self::predict`].
+    ///
+    #[inline]
+    pub fn par_predict(&self, d: &CategoricalDataMatrix, x: &str) -> Array1<u8> {
+        self.predict_with::<true>(d, x)
+    }
+
+    /// Render the network to DOT source, showing the CPDs as the vertices labels.
+    ///
+    /// Each vertex with parents is labelled with an HTML-like table of $\mathcal{P}(X \mid Pa(X))$,
+    /// with one row per configuration of the parents, while each root vertex is labelled
+    /// with its name and marginal values only. Values are formatted with `precision` decimal digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    ///
+    /// // Read BN from BIF.
+    /// let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+    ///
+    /// // Render it to DOT.
+    /// let dot = b.to_dot_with_cpds(2);
+    ///
+    /// // Check the CPD of `smoke` is a plain label.
+    /// assert!(dot.contains("\"smoke\" [ label = \"smoke\\nno: 0.50\\nyes: 0.50\"; ]"));
+    /// // Check the CPD of `lung` given `smoke = no` is a table row.
+    /// assert!(dot.contains("<tr><td>no</td><td>0.99</td><td>0.01</td></tr>"));
+    /// ```
+    ///
+    pub fn to_dot_with_cpds(&self, precision: usize) -> String {
+        // Escape HTML-like text.
+        let html = |s: &str| {
+            s.replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+                .replace('"', "&quot;")
+        };
+        // Format a value.
+        let format = |v: &f64| format!("{v:.precision$}");
+
+        // Convert the graph into DOT.
+        let mut dot = DOT::from(self.graph.clone());
+        // For each variable ...
+        for (x, phi) in &self.theta {
+            // Get associated states and values.
+            let (s, v) = (phi.states(), phi.values());
+            // Format the label.
+            let label = match s.len() > 1 {
+                // Format the CPD as table.
+                true => {
+                    // Get the conditioning variables.
+                    let z = s.keys().filter(|&z| z != x).collect_vec();
+                    // Format the table title.
+                    let title = format!(
+                        "<tr><td colspan=\"{}\"><b>{}</b></td></tr>",
+                        z.len() + s[x].len(),
+                        html(x)
+                    );
+                    // Format the table header, i.e. the conditioning variables and the states.
+                    let header = z
+                        .iter()
+                        .map(|z| format!("<td><i>{}</i></td>", html(z)))
+                        .chain(s[x].iter().map(|y| format!("<td>{}</td>", html(y))))
+                        .join("");
+                    // Get target index.
+                    let i = s.get_index_of(x).unwrap();
+                    // Construct iterator over values.
+                    let mut w = v.axis_iter(Axis(i)).map(|w| w.into_iter()).collect_vec();
+                    // Format a row for each configuration of the conditioning variables.
+                    let rows = z
+                        .iter()
+                        .map(|&z| &s[z])
+                        .multi_cartesian_product()
+                        .map(|t| {
+                            t.into_iter()
+                                .map(|t| format!("<td>{}</td>", html(t)))
+                                .chain(
+                                    w.iter_mut()
+                                        .map(|w| format!("<td>{}</td>", format(w.next().unwrap()))),
+                                )
+                                .join("")
+                        })
+                        .map(|r| format!("<tr>{r}</tr>"))
+                        .join("");
+
+                    format!(
+                        "<<table border=\"0\" cellborder=\"1\" cellspacing=\"0\">{title}<tr>{header}</tr>{rows}</table>>"
+                    )
+                }
+                // Format the marginal values as plain text.
+                false => {
+                    // Format the states and values.
+                    let values = s[x]
+                        .iter()
+                        .zip(v.iter())
+                        .map(|(y, v)| format!("\\n{y}: {}", format(v)));
+
+                    format!(
+                        "\"{}\"",
+                        (x.clone() + &values.collect::<String>()).replace('"', "\\\"")
+                    )
+                }
+            };
+            // Set the vertex label.
+            dot.vertices
+                .get_mut(x)
+                .expect("Failed to get vertex")
+                .attributes
+                .set_label(&label);
+        }
+
+        dot.into()
+    }
+}
+
 impl From<CategoricalBayesianNetwork>
     for (
         DirectedDenseAdjacencyMatrixGraph,
@@ -151,8 +770,13 @@ impl ProbabilisticGraphicalModel for CategoricalBayesianNetwork {
                 indices.insert(in_x, (..).into());
                 // Get P(X | Pa(X)) values.
                 let weights = phi_x.values().slice(indices.as_slice());
-                // Sample from P(X | Pa(X)).
-                let sample = WeightedIndex::new(&weights).unwrap().sample(rng);
+                // Get the states with non-zero probability.
+                let mut support = weights.iter().positions(|&w| w > 0.);
+                // Sample from P(X | Pa(X)), skipping the draw if deterministic.
+                let sample = match (support.next(), support.next()) {
+                    (Some(i), None) => i,
+                    _ => WeightedIndex::new(&weights).unwrap().sample(rng),
+                };
                 // Assign sampled values.
                 row[x] = sample.try_into().unwrap();
             });
@@ -204,8 +828,13 @@ impl ProbabilisticGraphicalModel for CategoricalBayesianNetwork {
                     indices.insert(in_x, (..).into());
                     // Get P(X | Pa(X)) values.
                     let weights = phi_x.values().slice(indices.as_slice());
-                    // Sample from P(X | Pa(X)).
-                    let sample = WeightedIndex::new(&weights).unwrap().sample(rng);
+                    // Get the states with non-zero probability.
+                    let mut support = weights.iter().positions(|&w| w > 0.);
+                    // Sample from P(X | Pa(X)), skipping the draw if deterministic.
+                    let sample = match (support.next(), support.next()) {
+                        (Some(i), None) => i,
+                        _ => WeightedIndex::new(&weights).unwrap().sample(rng),
+                    };
                     // Assign sampled values.
                     row[x] = sample.try_into().unwrap();
                 });
@@ -261,7 +890,7 @@ impl BayesianNetwork for CategoricalBayesianNetwork {
             "Graph and parameters must induce the same structure"
         );
         // Assert graph is acyclic.
-        assert!(graph.is_acyclic(), "Graph must be acyclic");
+        graph.assert_acyclic();
 
         Self { graph, theta }
     }
@@ -288,6 +917,8 @@ impl BayesianNetwork for CategoricalBayesianNetwork {
         });
         // Construct graph.
         let graph = Self::Graph::new(vertices, edges);
+        // Assert graph is acyclic.
+        graph.assert_acyclic();
 
         Self { graph, theta }
     }
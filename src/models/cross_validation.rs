@@ -0,0 +1,115 @@
+use itertools::Itertools;
+use ndarray::prelude::*;
+use rand::prelude::*;
+use rayon::prelude::*;
+
+use super::{CategoricalBayesianNetwork, ParameterEstimation};
+use crate::{
+    data::{CategoricalDataMatrix, DataSet},
+    graphs::structs::DirectedDenseAdjacencyMatrixGraph,
+};
+
+/// Compute the $k$-fold cross-validated log-likelihood.
+///
+/// The samples are shuffled and split into $k$ folds. For each fold, the structure is
+/// learned by `learn` and the parameters are estimated by `P` on the remaining folds,
+/// then the log-likelihood of the held-out fold is computed. The folds are evaluated
+/// in parallel, while the results are reproducible given the random number generator.
+///
+/// # Panics
+///
+/// The number of folds $k$ must be in the $[2, n]$ interval, with $n$ the sample size.
+///
+/// # Examples
+///
+/// ```
+/// use causal_hub::{prelude::*, polars::prelude::*};
+/// use rand::SeedableRng;
+/// use rand_xoshiro::Xoshiro256PlusPlus;
+///
+/// // Load data set from CSV file.
+/// let data_set = CsvReader::from_path("./tests/assets/asia.csv").unwrap().finish().unwrap();
+/// let data_set: CategoricalDataMatrix = data_set.into();
+///
+/// // Initialize random number generator.
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+///
+/// // Compute the 5-fold cross-validated log-likelihood of hill-climbing and BE.
+/// let scores = cross_validate::<BE, _, _>(
+///     &data_set,
+///     5,
+///     |d| {
+///         // Initialize empty prior knowledge.
+///         let prior_knowledge = FR::new(d.labels_iter(), [], []);
+///         // Perform discovery.
+///         HC::new(&BIC::new(d)).call(d, &prior_knowledge)
+///     },
+///     &mut rng,
+/// );
+///
+/// // Each fold has a log-likelihood.
+/// assert_eq!(scores.len(), 5);
+/// ```
+///
+pub fn cross_validate<P, F, R>(
+    d: &CategoricalDataMatrix,
+    k: usize,
+    learn: F,
+    rng: &mut R,
+) -> Vec<f64>
+where
+    P: ParameterEstimation<
+        CategoricalDataMatrix,
+        DirectedDenseAdjacencyMatrixGraph,
+        CategoricalBayesianNetwork,
+    >,
+    F: Fn(&CategoricalDataMatrix) -> DirectedDenseAdjacencyMatrixGraph + Sync,
+    R: Rng,
+{
+    // Get the sample size.
+    let n = d.sample_size();
+    // Assert the number of folds is in [2, n].
+    assert!(
+        (2..=n).contains(&k),
+        "Number of folds must be in the [2, n] interval"
+    );
+
+    // Shuffle the samples indices.
+    let mut indices = (0..n).collect_vec();
+    indices.shuffle(rng);
+    // Assign each sample to a fold.
+    let mut folds = vec![0; n];
+    indices
+        .into_iter()
+        .enumerate()
+        .for_each(|(i, j)| folds[j] = i * k / n);
+
+    // Select the samples of a given subset of folds.
+    let select = |f: &dyn Fn(usize) -> bool| {
+        // Get the samples indices.
+        let indices = (0..n).filter(|&i| f(folds[i])).collect_vec();
+        // Select the samples.
+        CategoricalDataMatrix::with_data_labels(
+            d.data().select(Axis(0), &indices),
+            d.labels().clone(),
+        )
+    };
+
+    (0..k)
+        .into_par_iter()
+        .map(|i| {
+            // Split the samples into training and held-out sets.
+            let (train, test) = (select(&|j| j != i), select(&|j| j == i));
+            // Learn the structure on the training set.
+            let g = learn(&train);
+            // Estimate the parameters on the training set.
+            let b = P::call(&train, &g);
+            // Compute the log-likelihood of the held-out set.
+            test.data()
+                .rows()
+                .into_iter()
+                .map(|x| b.log_density(x))
+                .sum()
+        })
+        .collect()
+}
@@ -0,0 +1,203 @@
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    BayesianNetwork, CategoricalBayesianNetwork, CategoricalCPD, CategoricalFactor,
+    ConditionalProbabilityDistribution, Factor, ProbabilisticGraphicalModel,
+};
+use crate::{
+    graphs::{structs::DirectedDenseAdjacencyMatrixGraph, BaseGraph, PathGraph},
+    types::{FxIndexMap, FxIndexSet},
+    L,
+};
+
+/// Categorical Dynamic Bayesian Network (DBN), i.e. a 2-time-slice Bayesian network.
+///
+/// The initial network $\mathcal{B}_0$ is defined over the variables of the first
+/// slice, labelled as `X@0`, while the transition CPDs $\mathcal{P}(X_t \mid Pa(X_t))$
+/// are defined over the variables of the current and previous slices, labelled as
+/// `X@t` and `X@t-1` respectively, i.e. intra-slice and inter-slice edges.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CategoricalDynamicBayesianNetwork {
+    initial: CategoricalBayesianNetwork,
+    transition: FxIndexMap<String, CategoricalCPD>,
+}
+
+/// Private function. It relabels a CPD by mapping each variable label.
+fn relabel<F>(phi: &CategoricalCPD, f: F) -> CategoricalCPD
+where
+    F: Fn(&str) -> String,
+{
+    // Map the variables labels, preserving the states and the values.
+    let psi = CategoricalFactor::new(
+        phi.states().iter().map(|(x, s)| (f(x), s.iter())),
+        phi.values().clone(),
+    );
+
+    CategoricalCPD::from_factor(&f(phi.target()), psi)
+}
+
+impl CategoricalDynamicBayesianNetwork {
+    /// Construct a new DBN given the initial network and the transition CPDs.
+    ///
+    /// # Panics
+    ///
+    /// The initial network variables must be labelled as `X@0`, while there must be
+    /// exactly one transition CPD with target `X@t` for each initial variable `X@0`.
+    /// The transition CPDs must condition only on `X@t` or `X@t-1` variables, with the
+    /// same states of the associated initial variables, and the intra-slice graph
+    /// must be acyclic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    /// use ndarray::prelude::*;
+    ///
+    /// // Set the initial network P(A@0) P(B@0 | A@0).
+    /// let initial = CategoricalBN::with_parameters([
+    ///     CategoricalCPD::new(("A@0", ["no", "yes"]), [], array![[0.5, 0.5]]),
+    ///     CategoricalCPD::new(
+    ///         ("B@0", ["no", "yes"]),
+    ///         [("A@0", ["no", "yes"])],
+    ///         array![[0.9, 0.1], [0.2, 0.8]],
+    ///     ),
+    /// ]);
+    /// // Set the transition P(A@t | A@t-1) P(B@t | A@t).
+    /// let transition = [
+    ///     CategoricalCPD::new(
+    ///         ("A@t", ["no", "yes"]),
+    ///         [("A@t-1", ["no", "yes"])],
+    ///         array![[0.7, 0.3], [0.3, 0.7]],
+    ///     ),
+    ///     CategoricalCPD::new(
+    ///         ("B@t", ["no", "yes"]),
+    ///         [("A@t", ["no", "yes"])],
+    ///         array![[0.9, 0.1], [0.2, 0.8]],
+    ///     ),
+    /// ];
+    /// let dbn = CategoricalDBN::new(initial, transition);
+    ///
+    /// // Unroll the DBN over three slices.
+    /// let b = dbn.unroll(3);
+    ///
+    /// // Check the unrolled variables.
+    /// assert!(L!(b.graph()).eq(["A@0", "A@1", "A@2", "B@0", "B@1", "B@2"]));
+    /// ```
+    ///
+    pub fn new<I>(initial: CategoricalBayesianNetwork, transition: I) -> Self
+    where
+        I: IntoIterator<Item = CategoricalCPD>,
+    {
+        // Get the initial variables, without the slice suffix.
+        let variables: FxIndexSet<_> = L!(initial.graph())
+            .map(|x| {
+                x.strip_suffix("@0")
+                    .unwrap_or_else(|| panic!("Initial variable `{x}` must be labelled as `X@0`"))
+                    .to_owned()
+            })
+            .collect();
+
+        // Collect the transition CPDs, sorted by target.
+        let transition: FxIndexMap<_, _> = transition
+            .into_iter()
+            .map(|phi| (phi.target().to_owned(), phi))
+            .sorted_by(|(x, _), (y, _)| x.cmp(y))
+            .collect();
+        // Assert there is exactly one transition CPD for each variable.
+        assert!(
+            transition
+                .keys()
+                .map(|x| x.as_str())
+                .eq(variables.iter().map(|x| format!("{x}@t")).sorted()),
+            "Transition CPDs targets must match the initial variables"
+        );
+        // Assert the transition CPDs are consistent with the initial variables.
+        for (z, s) in transition.values().flat_map(|phi| phi.states()) {
+            // Get the variable of the current or previous slice.
+            let x = z
+                .strip_suffix("@t-1")
+                .or_else(|| z.strip_suffix("@t"))
+                .filter(|x| variables.contains(*x))
+                .unwrap_or_else(|| {
+                    panic!("Transition variable `{z}` must be in the initial variables")
+                });
+            // Get the associated initial states.
+            let x = format!("{x}@0");
+            assert_eq!(
+                &initial.parameters()[&x].states()[&x],
+                s,
+                "Transition variable `{z}` must have the same states of `{x}`"
+            );
+        }
+
+        // Get the intra-slice edges, i.e. the edges between variables of the current slice.
+        let edges = transition.values().flat_map(|phi| {
+            // Get the target variable, without the slice suffix.
+            let y = phi.target().strip_suffix("@t").unwrap();
+            // Get the parents in the current slice, without the slice suffix.
+            phi.scope()
+                .filter(move |&z| z != phi.target())
+                .filter_map(|z| z.strip_suffix("@t"))
+                .map(move |x| (x, y))
+        });
+        // Assert the intra-slice graph is acyclic, i.e. the unrolled network is acyclic,
+        // before the unrolled network is constructed.
+        assert!(
+            DirectedDenseAdjacencyMatrixGraph::new(variables.iter().map(|x| x.as_str()), edges)
+                .is_acyclic(),
+            "Intra-slice graph must be acyclic"
+        );
+
+        Self {
+            initial,
+            transition,
+        }
+    }
+
+    /// Get the initial network $\mathcal{B}_0$.
+    #[inline]
+    pub fn initial(&self) -> &CategoricalBayesianNetwork {
+        &self.initial
+    }
+
+    /// Get the transition CPDs $\mathcal{P}(X_t \mid Pa(X_t))$.
+    #[inline]
+    pub fn transition(&self) -> &FxIndexMap<String, CategoricalCPD> {
+        &self.transition
+    }
+
+    /// Private function. It unrolls the DBN without checking acyclicity.
+    fn unrolled(&self, t: usize) -> CategoricalBayesianNetwork {
+        // Get the initial CPDs.
+        let initial = self.initial.parameters().values().cloned();
+        // Tile the transition CPDs for each following slice.
+        let transition = (1..t).flat_map(|t| {
+            self.transition.values().map(move |phi| {
+                relabel(phi, |x| match x.strip_suffix("@t-1") {
+                    Some(x) => format!("{x}@{}", t - 1),
+                    None => format!("{}@{t}", x.strip_suffix("@t").unwrap()),
+                })
+            })
+        });
+
+        CategoricalBayesianNetwork::with_parameters(initial.chain(transition))
+    }
+
+    /// Unroll the DBN over $T$ slices into a categorical Bayesian network.
+    ///
+    /// Each variable `X` is replicated for each slice `t`, labelled as `X@t`, where
+    /// the first slice is given by the initial network, while the following slices
+    /// are given by tiling the transition CPDs.
+    ///
+    /// # Panics
+    ///
+    /// The number of slices must be strictly positive.
+    ///
+    pub fn unroll(&self, t: usize) -> CategoricalBayesianNetwork {
+        // Assert the number of slices is strictly positive.
+        assert!(t > 0, "Number of slices must be strictly positive");
+
+        self.unrolled(t)
+    }
+}
@@ -157,6 +157,56 @@ impl CategoricalFactor {
     pub const fn states(&self) -> &FxIndexMap<String, FxIndexSet<String>> {
         &self.states
     }
+
+    /// Compute the factor conditioned on the evidence $\mathbf{E} = \mathbf{e}$, given by labels.
+    ///
+    /// Variables and states labels are resolved w.r.t. the factor own indexing,
+    /// and the conditioned variables are dropped from the scope. If `strict` is
+    /// `true`, the evidence variables must be in scope, otherwise the evidence
+    /// variables out of scope are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a state label is not associated to its variable, or if `strict`
+    /// is `true` and an evidence variable is not in scope.
+    pub fn condition(&self, e: &FxIndexMap<String, String>, strict: bool) -> Self {
+        // For each (variable, state) pair.
+        let e: BTreeMap<_, _> = e
+            .iter()
+            // Get variables and states indices, if present in scope.
+            .filter_map(|(x, y)| {
+                // Get variable index, if present in scope.
+                let i = self.states.get_index_of(x);
+                // Assert variable is in scope, if strict.
+                assert!(
+                    !strict || i.is_some(),
+                    "Evidence variable `{x}` must be in scope"
+                );
+
+                i.map(|i| {
+                    // Get state index.
+                    let j = self.states[i]
+                        .get_index_of(y)
+                        .unwrap_or_else(|| panic!("Failed to get state `{y}` of `{x}`"));
+
+                    (i, j)
+                })
+            })
+            // Collect to sort and deduplicate variables.
+            .collect();
+
+        // Clone states and values.
+        let (mut states, mut values) = (self.states.clone(), self.values.clone());
+        // Slice in decreasing order to ensure correctness.
+        for (x, y) in e.into_iter().rev() {
+            // Select given state and drop the axis.
+            values = values.index_axis_move(Axis(x), y);
+            // Remove associated variable, preserving the sorted order.
+            states.shift_remove_index(x);
+        }
+
+        Self { states, values }
+    }
 }
 
 impl Display for CategoricalFactor {
@@ -249,10 +299,13 @@ impl Div for CategoricalFactor {
         // Compute scope and states of factor division.
         let states = self.states;
         // Assert RHS scope is subset of LHS scope.
-        assert!(matches!(
-            iter_set::cmp(phi.states.keys(), states.keys()),
-            Some(Less) | Some(Equal)
-        ));
+        assert!(
+            matches!(
+                iter_set::cmp(phi.states.keys(), states.keys()),
+                Some(Less) | Some(Equal)
+            ),
+            "RHS scope must be a subset of LHS scope"
+        );
         // Compute broadcasting shapes.
         let rhs = states
             .keys()
@@ -274,6 +327,26 @@ impl Div for CategoricalFactor {
     }
 }
 
+impl Mul for &CategoricalFactor {
+    type Output = CategoricalFactor;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        // Compute factor product over the union of the scopes.
+        self.clone() * rhs.clone()
+    }
+}
+
+impl Div for &CategoricalFactor {
+    type Output = CategoricalFactor;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        // Compute factor division, with 0 / 0 = 0 convention.
+        self.clone() / rhs.clone()
+    }
+}
+
 impl PartialEq for CategoricalFactor {
     fn eq(&self, other: &Self) -> bool {
         self.states == other.states && self.values.relative_eq(&other.values, 1e-8, 1e-8)
@@ -601,6 +674,125 @@ impl CategoricalCPD {
     pub fn target(&self) -> &str {
         self.x.as_str()
     }
+
+    /// Compute the CPD conditioned on the evidence $\mathbf{E} = \mathbf{e}$, given by labels.
+    ///
+    /// See [`CategoricalFactor::condition`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the evidence includes the target variable $X$.
+    pub fn condition(&self, e: &FxIndexMap<String, String>, strict: bool) -> Self {
+        // Assert evidence does not include target.
+        assert!(
+            !e.contains_key(&self.x),
+            "Evidence must not include the target variable"
+        );
+        // Condition underlying factor.
+        let phi = self.phi.condition(e, strict);
+
+        Self {
+            x: self.x.clone(),
+            phi,
+        }
+    }
+
+    /// Check if the CPD is deterministic, i.e. $X$ is a function of $\mathbf{Z}$.
+    ///
+    /// A CPD is deterministic if each distribution $\mathcal{P}(X \mid \mathbf{Z} = \mathbf{z})$
+    /// assigns all the probability mass to a single state of $X$.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    ///
+    /// // Read BN from BIF.
+    /// let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+    ///
+    /// // Check `either` is the logical OR of `lung` and `tub`.
+    /// assert!(b.cpd("either").is_deterministic());
+    /// assert!(!b.cpd("dysp").is_deterministic());
+    /// ```
+    ///
+    pub fn is_deterministic(&self) -> bool {
+        // Get the target axis.
+        let i = self
+            .phi
+            .states
+            .get_index_of(&self.x)
+            .expect("Failed to get target index");
+        // Check each distribution has a single state with non-zero probability.
+        self.phi
+            .values
+            .lanes(Axis(i))
+            .into_iter()
+            .all(|p| p.iter().filter(|&&p| p > 0.).count() == 1)
+    }
+
+    /// Replace the undefined distributions with the uniform one.
+    ///
+    /// A distribution $\mathcal{P}(X \mid \mathbf{Z} = \mathbf{z})$ is undefined if
+    /// $\mathcal{P}(\mathbf{Z} = \mathbf{z}) = 0$, e.g. due to deterministic relations,
+    /// in which case [`ConditionalProbabilityDistribution::from_factor`] yields NaNs.
+    pub fn with_uniform_undefined(&self) -> Self {
+        // Get the target axis.
+        let i = self
+            .phi
+            .states
+            .get_index_of(&self.x)
+            .expect("Failed to get target index");
+        // Get the uniform probability.
+        let u = 1. / self.phi.values.len_of(Axis(i)) as f64;
+        // Clone the CPD.
+        let mut cpd = self.clone();
+        // Map the undefined values to the uniform probability.
+        cpd.phi
+            .values
+            .mapv_inplace(|x| if x.is_nan() { u } else { x });
+
+        cpd
+    }
+
+    /// Compute the CPD with temperature scaling $\mathcal{P}(X \mid \mathbf{Z})^{1 / T}$, renormalized.
+    ///
+    /// Temperatures $T < 1$ sharpen the distributions, while $T > 1$ flatten them.
+    /// As $T \to \infty$, each distribution tends to the uniform over its support,
+    /// since zero-valued probabilities are left unchanged. Values are scaled in log-space,
+    /// i.e. as the softmax of $\ln \mathcal{P}(X \mid \mathbf{Z}) / T$, to avoid underflows.
+    ///
+    /// # Panics
+    ///
+    /// The temperature $T$ must be strictly positive and finite.
+    pub fn with_temperature(&self, t: f64) -> Self {
+        // Assert temperature is strictly positive and finite.
+        assert!(
+            t > 0. && t.is_finite(),
+            "Temperature must be strictly positive and finite"
+        );
+        // Get the target axis.
+        let i = self
+            .phi
+            .states
+            .get_index_of(&self.x)
+            .expect("Failed to get target index");
+        // Clone the CPD.
+        let mut cpd = self.clone();
+        // Scale log-values by the inverse temperature, to avoid underflows.
+        cpd.phi.values.mapv_inplace(|x| x.ln() / t);
+        // Compute the maximum over the target axis, if finite.
+        let m = cpd
+            .phi
+            .values
+            .fold_axis(Axis(i), f64::NEG_INFINITY, |&a, &b| a.max(b))
+            .mapv(|m| if m.is_finite() { m } else { 0. })
+            .insert_axis(Axis(i));
+        // Subtract the maximum and exponentiate, i.e. compute the softmax numerator.
+        cpd.phi.values -= &m;
+        cpd.phi.values.mapv_inplace(f64::exp);
+        // Normalize over target axis.
+        cpd.normalize()
+    }
 }
 
 impl Display for CategoricalCPD {
@@ -624,6 +816,10 @@ impl Add for CategoricalCPD {
     }
 }
 
+/// Product of CPDs, normalized over the target of the left-hand side.
+///
+/// Unlike the product of references, which returns the unnormalized potential.
+///
 impl Mul for CategoricalCPD {
     type Output = Self;
 
@@ -636,6 +832,10 @@ impl Mul for CategoricalCPD {
     }
 }
 
+/// Division of CPDs, normalized over the target of the left-hand side.
+///
+/// Unlike the division of references, which returns the unnormalized potential.
+///
 impl Div for CategoricalCPD {
     type Output = Self;
 
@@ -648,6 +848,34 @@ impl Div for CategoricalCPD {
     }
 }
 
+/// Product of CPDs, returned as unnormalized potential over the union of the scopes.
+///
+/// Unlike the product of owned CPDs, which is normalized over the target of the left-hand side.
+///
+impl Mul for &CategoricalCPD {
+    type Output = CategoricalFactor;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        // Compute (unnormalized) potential over the union of the scopes.
+        &self.phi * &rhs.phi
+    }
+}
+
+/// Division of CPDs, returned as unnormalized potential over the union of the scopes.
+///
+/// Unlike the division of owned CPDs, which is normalized over the target of the left-hand side.
+///
+impl Div for &CategoricalCPD {
+    type Output = CategoricalFactor;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        // Compute (unnormalized) potential, with 0 / 0 = 0 convention.
+        &self.phi / &rhs.phi
+    }
+}
+
 impl From<CategoricalCPD> for CategoricalFactor {
     #[inline]
     fn from(other: CategoricalCPD) -> Self {
@@ -0,0 +1,216 @@
+use itertools::Itertools;
+use log::{debug, warn};
+use ndarray::prelude::*;
+
+use super::{CategoricalBayesianNetwork, CategoricalFactor, Factor, ProbabilisticGraphicalModel};
+use crate::types::{FxIndexMap, FxIndexSet};
+
+/// Categorical factor graph, i.e. a bipartite graph of variables and factors.
+///
+/// Each factor $\phi(\mathbf{X})$ is adjacent to the variables in its scope $\mathbf{X}$.
+#[derive(Clone, Debug)]
+pub struct FactorGraph {
+    states: FxIndexMap<String, FxIndexSet<String>>,
+    factors: Vec<CategoricalFactor>,
+    edges: Vec<(usize, usize)>,
+}
+
+impl FactorGraph {
+    /// Construct a new factor graph given the CPDs of a categorical Bayesian network.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    ///
+    /// // Read BIF file.
+    /// let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+    ///
+    /// // Build the associated factor graph.
+    /// let f = FactorGraph::from_bn(&b);
+    ///
+    /// // Check there is a factor for each variable.
+    /// assert_eq!(f.variables().len(), f.factors().len());
+    /// ```
+    ///
+    pub fn from_bn(b: &CategoricalBayesianNetwork) -> Self {
+        // Get the variables states, sorted by label.
+        let states: FxIndexMap<_, _> = b
+            .parameters()
+            .iter()
+            .map(|(x, phi)| (x.clone(), phi.states()[x].clone()))
+            .collect();
+        // Cast the CPDs to factors.
+        let factors = b
+            .parameters()
+            .values()
+            .cloned()
+            .map(CategoricalFactor::from)
+            .collect_vec();
+        // Connect each factor to the variables in its scope.
+        let edges = factors
+            .iter()
+            .enumerate()
+            .flat_map(|(f, phi)| {
+                phi.scope()
+                    .map(|x| (f, states.get_index_of(x).unwrap()))
+                    .collect_vec()
+            })
+            .collect();
+
+        Self {
+            states,
+            factors,
+            edges,
+        }
+    }
+
+    /// Get the variables states.
+    #[inline]
+    pub fn variables(&self) -> &FxIndexMap<String, FxIndexSet<String>> {
+        &self.states
+    }
+
+    /// Get the factors.
+    #[inline]
+    pub fn factors(&self) -> &[CategoricalFactor] {
+        &self.factors
+    }
+
+    /// Private function. It returns the uniform factor over the given variable.
+    fn uniform(&self, x: usize) -> CategoricalFactor {
+        // Get the variable states.
+        let (x, s) = self.states.get_index(x).unwrap();
+
+        CategoricalFactor::new([(x, s)], Array::from_elem(s.len(), 1. / s.len() as f64))
+    }
+
+    /// Run loopy belief propagation (sum-product) to approximate the marginals $P(X)$.
+    ///
+    /// Messages are updated synchronously, until either the maximum absolute change
+    /// of the factor-to-variable messages is lower than `tol`, or `max_iter` iterations
+    /// are reached. Marginals are exact on tree-structured factor graphs, while
+    /// convergence is **not** guaranteed on loopy ones, e.g. messages may oscillate;
+    /// in that case the last beliefs are returned and a warning is logged.
+    ///
+    /// # Panics
+    ///
+    /// The tolerance must be strictly positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    ///
+    /// // Read BIF file.
+    /// let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+    ///
+    /// // Approximate the marginals.
+    /// let p = FactorGraph::from_bn(&b).run_lbp(100, 1e-8);
+    ///
+    /// // Check the marginal of a root variable.
+    /// assert!((p["asia"].values()[[0]] - 0.99).abs() < 1e-8);
+    /// ```
+    ///
+    pub fn run_lbp(&self, max_iter: usize, tol: f64) -> FxIndexMap<String, CategoricalFactor> {
+        // Assert tolerance is strictly positive.
+        assert!(tol > 0., "Tolerance must be strictly positive");
+
+        // Get the incident edges for each factor and variable.
+        let mut factor_edges = vec![vec![]; self.factors.len()];
+        let mut variable_edges = vec![vec![]; self.states.len()];
+        for (i, &(f, x)) in self.edges.iter().enumerate() {
+            factor_edges[f].push(i);
+            variable_edges[x].push(i);
+        }
+
+        // Initialize the factor-to-variable messages as uniform.
+        let mut f_to_v = self
+            .edges
+            .iter()
+            .map(|&(_, x)| self.uniform(x))
+            .collect_vec();
+
+        // Initialize the convergence flag.
+        let mut converged = false;
+        // For each iteration ...
+        for t in 0..max_iter {
+            // ... compute the variable-to-factor messages, i.e. the product of the other incoming messages ...
+            let v_to_f = self
+                .edges
+                .iter()
+                .enumerate()
+                .map(|(i, &(_, x))| {
+                    variable_edges[x]
+                        .iter()
+                        .filter(|&&j| j != i)
+                        .fold(self.uniform(x), |m, &j| m * f_to_v[j].clone())
+                        .normalize()
+                })
+                .collect_vec();
+            // ... compute the factor-to-variable messages, i.e. the marginalized product ...
+            let next = self
+                .edges
+                .iter()
+                .enumerate()
+                .map(|(i, &(f, x))| {
+                    // Multiply the factor by the other incoming messages.
+                    let psi = factor_edges[f]
+                        .iter()
+                        .filter(|&&j| j != i)
+                        .fold(self.factors[f].clone(), |psi, &j| psi * v_to_f[j].clone());
+                    // Get the variables to be marginalized.
+                    let x = self.states.get_index(x).unwrap().0;
+                    let z = psi
+                        .scope()
+                        .filter(|&z| z != x)
+                        .map(String::from)
+                        .collect_vec();
+                    // Marginalize the other variables.
+                    psi.marginalize(z.iter().map(|z| z.as_str())).normalize()
+                })
+                .collect_vec();
+            // ... compute the maximum absolute change ...
+            let delta = f_to_v
+                .iter()
+                .zip(&next)
+                .flat_map(|(m, n)| {
+                    m.values()
+                        .iter()
+                        .zip(n.values())
+                        .map(|(m, n)| (m - n).abs())
+                })
+                .fold(0., f64::max);
+            // ... and update the messages.
+            f_to_v = next;
+
+            debug!("t: {}, max_iter: {}, delta: {}", t, max_iter, delta);
+
+            // Check for convergence.
+            if delta < tol {
+                converged = true;
+                break;
+            }
+        }
+
+        // Warn if messages did not converge.
+        if !converged {
+            warn!("Loopy belief propagation did not converge in {max_iter} iterations");
+        }
+
+        // Compute the beliefs, i.e. the normalized product of the incoming messages.
+        self.states
+            .keys()
+            .enumerate()
+            .map(|(x, label)| {
+                (
+                    label.clone(),
+                    variable_edges[x]
+                        .iter()
+                        .fold(self.uniform(x), |b, &j| b * f_to_v[j].clone())
+                        .normalize(),
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,165 @@
+use itertools::Itertools;
+use ndarray::prelude::*;
+use rand::{seq::SliceRandom, Rng};
+
+use super::{CategoricalBayesianNetwork, Factor, ProbabilisticGraphicalModel};
+use crate::{
+    data::{CategoricalDataMatrix, DataSet},
+    graphs::DirectedGraph,
+    prelude::{algorithms::traversal::TopologicalSort, BaseGraph},
+    Pa,
+};
+
+/// Variance reduction techniques for forward sampling.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VarianceReduction {
+    /// Plain Monte Carlo, i.e. independent uniform draws.
+    #[default]
+    MonteCarlo,
+    /// Antithetic variates, i.e. consecutive samples are paired by drawing $U$ and $1 - U$.
+    AntitheticVariates,
+    /// Stratified sampling, i.e. the $n$ uniform draws of each variable fall one
+    /// for each stratum $[i / n, (i + 1) / n)$, in random order.
+    Stratified,
+}
+
+/// Forward sampling functor.
+///
+/// Each variable is sampled in topological order by inverse CDF, i.e. by mapping a
+/// uniform draw $U$ through the cumulative distribution of $\mathcal{P}(X \mid Pa(X) = pa(x))$.
+/// Variance reduction acts on the uniform draws, hence the variables that benefit the most
+/// are the root ones, whose CDF is the same across samples, while the benefit decreases
+/// for the variables whose parents configurations vary across samples.
+#[derive(Clone, Debug)]
+pub struct ForwardSampling<'a> {
+    model: &'a CategoricalBayesianNetwork,
+    variance_reduction: VarianceReduction,
+}
+
+impl<'a> ForwardSampling<'a> {
+    /// Construct a new forward sampling functor, with plain Monte Carlo.
+    #[inline]
+    pub const fn new(model: &'a CategoricalBayesianNetwork) -> Self {
+        Self {
+            model,
+            variance_reduction: VarianceReduction::MonteCarlo,
+        }
+    }
+
+    /// Set the variance reduction technique.
+    #[inline]
+    pub fn with_variance_reduction(mut self, variance_reduction: VarianceReduction) -> Self {
+        // Set variance reduction.
+        self.variance_reduction = variance_reduction;
+
+        self
+    }
+
+    /// Private function. It draws the `n` uniform values of a variable.
+    fn uniform<R: Rng>(&self, rng: &mut R, n: usize) -> Array1<f64> {
+        match self.variance_reduction {
+            // Draw independent values.
+            VarianceReduction::MonteCarlo => Array1::from_shape_simple_fn(n, || rng.gen()),
+            // Draw paired values, leaving the last one unpaired if n is odd.
+            VarianceReduction::AntitheticVariates => {
+                let mut u = Array1::zeros(n);
+                for i in (0..n).step_by(2) {
+                    u[i] = rng.gen();
+                    if i + 1 < n {
+                        u[i + 1] = 1. - u[i];
+                    }
+                }
+
+                u
+            }
+            // Draw a value for each stratum, in random order.
+            VarianceReduction::Stratified => {
+                let mut strata = (0..n).collect_vec();
+                strata.shuffle(rng);
+
+                strata
+                    .into_iter()
+                    .map(|i| (i as f64 + rng.gen::<f64>()) / n as f64)
+                    .collect()
+            }
+        }
+    }
+
+    /// Draw `n` samples.
+    ///
+    /// The samples are reproducible under a seed for each variance reduction technique.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    /// use rand::SeedableRng;
+    /// use rand_xoshiro::Xoshiro256PlusPlus;
+    ///
+    /// // Read BIF file.
+    /// let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+    ///
+    /// // Draw stratified samples.
+    /// let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+    /// let d = ForwardSampling::new(&b)
+    ///     .with_variance_reduction(VarianceReduction::Stratified)
+    ///     .sample(&mut rng, 100);
+    ///
+    /// // Check the frequency of the root `smoke` matches its probability.
+    /// let smoke = d.data().column(5).iter().filter(|&&x| x == 0).count();
+    /// assert!((49..=51).contains(&smoke));
+    /// ```
+    ///
+    pub fn sample<R: Rng>(&self, rng: &mut R, n: usize) -> CategoricalDataMatrix {
+        // Get the associated graph.
+        let g = self.model.graph();
+        // Allocate the new data set values.
+        let mut data = Array2::<u8>::zeros((n, g.order()));
+
+        // For each vertex in topological order ...
+        for x in TopologicalSort::new(g) {
+            // Get Pa(X) U {X}, sorted as the CPD scope.
+            let scope = Pa!(g, x).chain([x]).sorted().collect_vec();
+            // Get the position of X in the scope.
+            let p = scope.iter().position(|&z| z == x).unwrap();
+            // Get P(X | Pa(X)).
+            let phi = self.model.parameters()[x].values();
+            // Draw the uniform values, unless X is a function of Pa(X), where
+            // the inverse CDF of zero yields the only state with non-zero probability.
+            let u = match self.model.parameters()[x].is_deterministic() {
+                true => Array1::zeros(n),
+                false => self.uniform(rng, n),
+            };
+
+            // For each sample ...
+            for (mut row, u) in data.rows_mut().into_iter().zip(u) {
+                // Get the P(X | Pa(X) = pa(x)) indices.
+                let mut indices = scope.iter().map(|&z| row[z] as usize).collect_vec();
+                // Invert the CDF of P(X | Pa(X) = pa(x)).
+                let mut c = 0.;
+                let k = phi.shape()[p];
+                let sample = (0..k)
+                    .position(|i| {
+                        indices[p] = i;
+                        c += phi[indices.as_slice()];
+                        u < c
+                    })
+                    // Fallback to the last state due to rounding errors.
+                    .unwrap_or(k - 1);
+                // Assign sampled value.
+                row[x] = sample as u8;
+            }
+        }
+
+        // Get the states.
+        let states = self
+            .model
+            .parameters()
+            .iter()
+            .map(|(k, v)| (k.clone(), v.states()[k].clone()))
+            .collect();
+
+        // Return sampled data set.
+        CategoricalDataMatrix::with_data_labels(data, states)
+    }
+}
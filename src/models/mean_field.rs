@@ -0,0 +1,229 @@
+use itertools::Itertools;
+use log::{debug, warn};
+use ndarray::prelude::*;
+
+use super::{CategoricalBayesianNetwork, CategoricalFactor, Factor, ProbabilisticGraphicalModel};
+use crate::{
+    graphs::DirectedGraph,
+    prelude::{algorithms::traversal::TopologicalSort, BaseGraph, FxIndexMap},
+    Ch, L, V,
+};
+
+/// Mean-Field (MF) variational inference functor.
+///
+/// The posterior $\mathcal{P}(\mathbf{X} \mid \mathbf{E} = \mathbf{e})$ is approximated
+/// by a fully factorized distribution $Q(\mathbf{X}) = \prod_i Q_i(X_i)$ that minimizes
+/// $KL(Q \parallel \mathcal{P})$, i.e. that maximizes the Evidence Lower BOund (ELBO):
+///
+/// $$ \text{ELBO}(Q) = \sum_i \mathbb{E}_Q[\log \mathcal{P}(X_i \mid Pa(X_i))] + \sum_i H(Q_i) \le \log \mathcal{P}(\mathbf{E} = \mathbf{e}) $$
+///
+#[derive(Clone, Debug)]
+pub struct MeanField<'a> {
+    model: &'a CategoricalBayesianNetwork,
+}
+
+impl<'a> MeanField<'a> {
+    /// Construct a new mean-field functor.
+    #[inline]
+    pub const fn new(model: &'a CategoricalBayesianNetwork) -> Self {
+        Self { model }
+    }
+
+    /// Private function. It computes $\mathbb{E}_Q[\log \mathcal{P}(X_f \mid Pa(X_f))]$
+    /// as a function of the states of $X_i$, or the full expectation if none is given.
+    fn expected_log(&self, q: &[Array1<f64>], f: usize, i: Option<usize>) -> Array1<f64> {
+        // Get the associated graph.
+        let g = self.model.graph();
+        // Get the scope indices, sorted as the CPD scope.
+        let scope = self.model.parameters()[f]
+            .scope()
+            .map(|x| g.get_vertex_index(x))
+            .collect_vec();
+        // Get the position of X_i in the scope, if any.
+        let p = i.map(|i| scope.iter().position(|&z| z == i).unwrap());
+
+        // Initialize the expectation.
+        let mut e = Array1::zeros(i.map_or(1, |i| q[i].len()));
+        // For each configuration of the CPD ...
+        for (idx, &phi) in self.model.parameters()[f].values().indexed_iter() {
+            // ... compute its weight w.r.t. Q, skipping X_i ...
+            let w: f64 = scope
+                .iter()
+                .enumerate()
+                .filter(|&(k, _)| Some(k) != p)
+                .map(|(k, &z)| q[z][idx[k]])
+                .product();
+            // ... and accumulate the log-probability, flooring zeros to keep it finite.
+            if w > 0. {
+                e[p.map_or(0, |p| idx[p])] += w * phi.max(f64::MIN_POSITIVE).ln();
+            }
+        }
+
+        e
+    }
+
+    /// Compute the approximate marginals $Q(X)$ given the evidence $\mathbf{E} = \mathbf{e}$, with the final ELBO.
+    ///
+    /// The factors $Q(X)$ are initialized by a forward pass in topological order,
+    /// then updated by coordinate ascent over the Markov blanket of each variable:
+    ///
+    /// $$ \log Q(X) = \mathbb{E}_{Q}[\log \mathcal{P}(X \mid Pa(X))] + \sum_{Y \in Ch(X)} \mathbb{E}_{Q}[\log \mathcal{P}(Y \mid Pa(Y))] + c $$
+    ///
+    /// until either the maximum absolute change is lower than `tol`, or `max_iter`
+    /// iterations are reached. The evidence variables are clamped to their observed states.
+    ///
+    /// Zero probabilities are floored to [`f64::MIN_POSITIVE`] to keep the updates finite.
+    /// Since $Q$ cannot represent dependencies, e.g. the ones induced by deterministic
+    /// CPDs, the marginals are biased, while the ELBO lower bounds $\log \mathcal{P}(\mathbf{E} = \mathbf{e})$.
+    ///
+    /// # Panics
+    ///
+    /// The evidence must refer to the variables and states of the network,
+    /// and the tolerance must be strictly positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    ///
+    /// // Read BIF file.
+    /// let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+    ///
+    /// // Set the evidence.
+    /// let e = FxIndexMap::from_iter([("dysp".to_string(), "yes".to_string())]);
+    ///
+    /// // Approximate the posterior marginals.
+    /// let (q, elbo) = MeanField::new(&b).query(&e, 100, 1e-8);
+    ///
+    /// // Compute the exact posterior marginal, up to normalization.
+    /// let p = VE::new(&b).call(["bronc", "dysp"]).condition(&e, true).normalize();
+    ///
+    /// // Check the approximation gap on P(bronc | dysp = yes).
+    /// assert!((q["bronc"].values()[[1]] - p.values()[[1]]).abs() < 0.1);
+    /// // Check the ELBO lower bounds the log-evidence.
+    /// let z = VE::new(&b).call(["dysp"]).values()[[1]].ln();
+    /// assert!(elbo <= z);
+    /// ```
+    ///
+    pub fn query(
+        &self,
+        evidence: &FxIndexMap<String, String>,
+        max_iter: usize,
+        tol: f64,
+    ) -> (FxIndexMap<String, CategoricalFactor>, f64) {
+        // Assert tolerance is strictly positive.
+        assert!(tol > 0., "Tolerance must be strictly positive");
+
+        // Get the associated graph.
+        let g = self.model.graph();
+        // Get the variables states.
+        let states = L!(g)
+            .map(|x| &self.model.parameters()[x].states()[x])
+            .collect_vec();
+
+        // Get the evidence indices.
+        let e: FxIndexMap<_, _> = evidence
+            .iter()
+            .map(|(x, y)| {
+                // Get variable index.
+                let i = L!(g)
+                    .position(|z| z == x.as_str())
+                    .unwrap_or_else(|| panic!("Evidence variable `{x}` must be in the network"));
+                // Get state index.
+                let j = states[i]
+                    .get_index_of(y)
+                    .unwrap_or_else(|| panic!("Failed to get state `{y}` of `{x}`"));
+
+                (i, j)
+            })
+            .collect();
+
+        // Initialize Q(X) by a forward pass in topological order.
+        let mut q = states.iter().map(|s| Array1::zeros(s.len())).collect_vec();
+        for i in TopologicalSort::new(g) {
+            q[i] = match e.get(&i) {
+                // Clamp the evidence variables.
+                Some(&j) => Array1::from_shape_fn(states[i].len(), |k| (k == j) as u8 as f64),
+                // Marginalize the parents w.r.t. Q.
+                None => {
+                    // Get the CPD scope indices.
+                    let scope = self.model.parameters()[i]
+                        .scope()
+                        .map(|x| g.get_vertex_index(x))
+                        .collect_vec();
+                    // Get the position of X in the scope.
+                    let p = scope.iter().position(|&z| z == i).unwrap();
+                    // Sum P(X | Pa(X)) Q(Pa(X)) over the parents configurations.
+                    let mut r = Array1::zeros(states[i].len());
+                    for (idx, &phi) in self.model.parameters()[i].values().indexed_iter() {
+                        r[idx[p]] += phi
+                            * scope
+                                .iter()
+                                .enumerate()
+                                .filter(|&(k, _)| k != p)
+                                .map(|(k, &z)| q[z][idx[k]])
+                                .product::<f64>();
+                    }
+
+                    r
+                }
+            };
+        }
+
+        // Initialize the convergence flag.
+        let mut converged = false;
+        // For each iteration ...
+        for t in 0..max_iter {
+            // Initialize the maximum absolute change.
+            let mut delta: f64 = 0.;
+            // For each unobserved variable ...
+            for i in V!(g).filter(|i| !e.contains_key(i)) {
+                // ... sum the expectations over its Markov blanket factors ...
+                let l = [i]
+                    .into_iter()
+                    .chain(Ch!(g, i))
+                    .map(|f| self.expected_log(&q, f, Some(i)))
+                    .fold(Array1::zeros(states[i].len()), |l, e| l + e);
+                // ... compute the normalized exponential ...
+                let m = l.fold(f64::NEG_INFINITY, |m, &x| m.max(x));
+                let r = l.mapv(|x| (x - m).exp());
+                let r = &r / r.sum();
+                // ... and update Q(X).
+                delta = (&r - &q[i]).fold(delta, |d, x| d.max(x.abs()));
+                q[i] = r;
+            }
+
+            debug!("t: {}, max_iter: {}, delta: {}", t, max_iter, delta);
+
+            // Check for convergence.
+            if delta < tol {
+                converged = true;
+                break;
+            }
+        }
+
+        // Warn if updates did not converge.
+        if !converged {
+            warn!("Mean-field updates did not converge in {max_iter} iterations");
+        }
+
+        // Compute the ELBO, i.e. the expected log-joint plus the entropy of Q.
+        let elbo = V!(g)
+            .map(|f| self.expected_log(&q, f, None)[0])
+            .sum::<f64>()
+            + q.iter()
+                .flatten()
+                .filter(|&&x| x > 0.)
+                .map(|&x| -x * x.ln())
+                .sum::<f64>();
+
+        // Cast Q(X) to factors.
+        let q = L!(g)
+            .zip(q)
+            .zip(&states)
+            .map(|((x, q), s)| (x.to_owned(), CategoricalFactor::new([(x, *s)], q)))
+            .collect();
+
+        (q, elbo)
+    }
+}
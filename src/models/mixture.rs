@@ -0,0 +1,149 @@
+use itertools::Itertools;
+use ndarray::prelude::*;
+use rand::{distributions::WeightedIndex, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use super::{CategoricalBayesianNetwork, ProbabilisticGraphicalModel};
+use crate::{
+    data::{CategoricalDataMatrix, DataSet},
+    graphs::BaseGraph,
+    L,
+};
+
+/// Mixture of categorical Bayesian networks $\sum_k w_k \mathcal{B}_k$.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CategoricalMixtureBayesianNetwork {
+    components: Vec<CategoricalBayesianNetwork>,
+    weights: Vec<f64>,
+}
+
+impl CategoricalMixtureBayesianNetwork {
+    /// Construct a new mixture given its components $\mathcal{B}_k$ and weights $w_k$.
+    ///
+    /// # Panics
+    ///
+    /// The components must be non-empty and share the same variables and states,
+    /// while the weights must be non-negative and sum to one.
+    pub fn new<I, J>(components: I, weights: J) -> Self
+    where
+        I: IntoIterator<Item = CategoricalBayesianNetwork>,
+        J: IntoIterator<Item = f64>,
+    {
+        // Collect components and weights.
+        let components = components.into_iter().collect_vec();
+        let weights = weights.into_iter().collect_vec();
+
+        // Assert components are non-empty.
+        assert!(!components.is_empty(), "Components must be non-empty");
+        // Assert components and weights have the same length.
+        assert_eq!(
+            components.len(),
+            weights.len(),
+            "Components and weights must have the same length"
+        );
+        // Assert weights are non-negative ...
+        assert!(
+            weights.iter().all(|&w| w >= 0.),
+            "Weights must be non-negative"
+        );
+        // ... and sum to one.
+        assert!(
+            (weights.iter().sum::<f64>() - 1.).abs() < 1e-8,
+            "Weights must sum to one"
+        );
+        // Assert components share the same variables and states.
+        assert!(
+            components.iter().tuple_windows().all(|(a, b)| {
+                L!(a.graph()).eq(L!(b.graph()))
+                    && a.parameters()
+                        .iter()
+                        .zip(b.parameters())
+                        .all(|((x, p), (_, q))| p.states()[x] == q.states()[x])
+            }),
+            "Components must share the same variables and states"
+        );
+
+        Self {
+            components,
+            weights,
+        }
+    }
+
+    /// Get the mixture components $\mathcal{B}_k$.
+    #[inline]
+    pub fn components(&self) -> &[CategoricalBayesianNetwork] {
+        &self.components
+    }
+
+    /// Get the mixture weights $w_k$.
+    #[inline]
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    /// Draw `n` samples, picking a component for each sample w.r.t. the weights.
+    pub fn sample<R: Rng>(&self, rng: &mut R, n: usize) -> CategoricalDataMatrix {
+        // Pick a component for each sample.
+        let k = WeightedIndex::new(&self.weights).expect("Failed to initialize weighted index");
+        let z = (0..n).map(|_| k.sample(rng)).collect_vec();
+
+        // Count the samples for each component.
+        let mut n_k = vec![0; self.components.len()];
+        z.iter().for_each(|&k| n_k[k] += 1);
+        // Draw samples from each component with non-zero count.
+        let samples = self
+            .components
+            .iter()
+            .zip(n_k)
+            .map(|(b, n)| (n > 0).then(|| b.sample(rng, n)))
+            .collect_vec();
+
+        // Allocate the new data set values.
+        let mut data = Array2::<u8>::zeros((n, self.components[0].graph().order()));
+        // Initialize the next row of each component.
+        let mut next = vec![0; self.components.len()];
+        // Assign samples w.r.t. the picked components.
+        for (mut row, k) in data.rows_mut().into_iter().zip(z) {
+            // Get the sample from the picked component.
+            let d = samples[k]
+                .as_ref()
+                .expect("Failed to get component samples");
+            // Assign sampled values.
+            row.assign(&d.data().row(next[k]));
+            // Advance to next row.
+            next[k] += 1;
+        }
+
+        // Get the states.
+        let states = self.components[0]
+            .parameters()
+            .iter()
+            .map(|(k, v)| (k.clone(), v.states()[k].clone()))
+            .collect();
+
+        // Return sampled data set.
+        CategoricalDataMatrix::with_data_labels(data, states)
+    }
+
+    /// Compute the log-density $\log \sum_k w_k \mathcal{P}_k(\mathbf{X} = \mathbf{x})$ of a given sample.
+    ///
+    /// See [`CategoricalBayesianNetwork::log_density`] for the sample encoding.
+    pub fn log_density(&self, x: ArrayView1<u8>) -> f64 {
+        // Compute the weighted log-density of each component.
+        let l = self
+            .components
+            .iter()
+            .zip(&self.weights)
+            .map(|(b, w)| w.ln() + b.log_density(x))
+            .collect_vec();
+        // Get the maximum weighted log-density.
+        let m = l.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        // Check if the sample has zero density.
+        if m == f64::NEG_INFINITY {
+            return m;
+        }
+
+        // Compute the log-sum-exp.
+        m + l.into_iter().map(|l| (l - m).exp()).sum::<f64>().ln()
+    }
+}
@@ -1,15 +1,33 @@
+mod adjustment_sets;
+pub use adjustment_sets::*;
+
 mod bayesian_network;
 pub use bayesian_network::*;
 
 /// Alias for categorical bayesian network.
 pub type CategoricalBN = CategoricalBayesianNetwork;
 
+mod cross_validation;
+pub use cross_validation::*;
+
+mod dynamic_bayesian_network;
+pub use dynamic_bayesian_network::*;
+
+/// Alias for categorical dynamic bayesian network.
+pub type CategoricalDBN = CategoricalDynamicBayesianNetwork;
+
 mod factor;
 pub use factor::*;
 
+mod factor_graph;
+pub use factor_graph::*;
+
 mod distribution_estimation;
 pub use distribution_estimation::*;
 
+mod forward_sampling;
+pub use forward_sampling::*;
+
 mod graphical_separation;
 pub use graphical_separation::*;
 
@@ -25,6 +43,18 @@ pub use kullback_leibler::*;
 /// Alias for Kullback-Leibler divergence.
 pub type KL<'a, P, Q> = KullbackLeiblerDivergence<'a, P, Q>;
 
+mod mean_field;
+pub use mean_field::*;
+
+mod mixture;
+pub use mixture::*;
+
+/// Alias for mixture of categorical bayesian networks.
+pub type CategoricalMixtureBN = CategoricalMixtureBayesianNetwork;
+
+mod most_probable_explanation;
+pub use most_probable_explanation::*;
+
 mod moral;
 pub use moral::*;
 
@@ -0,0 +1,208 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use itertools::Itertools;
+
+use super::{CategoricalBayesianNetwork, Factor, ProbabilisticGraphicalModel, VariableElimination};
+use crate::{
+    prelude::{algorithms::traversal::TopologicalSort, BaseGraph, FxIndexMap},
+    L,
+};
+
+/// Private struct. A partial assignment of the search frontier, ordered by its upper bound.
+struct Node {
+    bound: f64,
+    p: f64,
+    depth: usize,
+    assignment: Vec<usize>,
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Node {}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bound.total_cmp(&other.bound)
+    }
+}
+
+/// Compute the top-$k$ Most Probable Explanations (MPE) given the evidence $\mathbf{E} = \mathbf{e}$.
+///
+/// The unobserved variables $\mathbf{X}$ are assigned in topological order by a best-first
+/// (A*) search, where the probability of a partial assignment is bounded from above by the
+/// product of the maximum entries of the CPDs of the unassigned variables. Since the bound is
+/// admissible, complete assignments are found in descending order of probability, hence the
+/// first one is the MPE.
+///
+/// Each explanation $\mathbf{x}$ is returned as the states of the unobserved variables, sorted
+/// by label, with its posterior probability $\mathcal{P}(\mathbf{X} = \mathbf{x} \mid \mathbf{E} = \mathbf{e})$.
+/// If the evidence has zero probability, there is no explanation.
+///
+/// # Panics
+///
+/// The evidence must refer to the variables and states of the network, and $k$ must not
+/// exceed the number of configurations of the unobserved variables.
+///
+/// # Examples
+///
+/// ```
+/// use causal_hub::prelude::*;
+///
+/// // Read BIF file.
+/// let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+///
+/// // Set the evidence.
+/// let e = FxIndexMap::from_iter([("dysp".to_string(), "yes".to_string())]);
+///
+/// // Compute the top-3 explanations.
+/// let mpe = top_k_mpe(&b, &e, 3);
+///
+/// // Check the explanations are sorted by probability.
+/// assert!(mpe.windows(2).all(|w| w[0].1 >= w[1].1));
+/// // Check the most probable explanation.
+/// assert_eq!(mpe[0].0["bronc"], "yes");
+/// ```
+///
+pub fn top_k_mpe(
+    b: &CategoricalBayesianNetwork,
+    evidence: &FxIndexMap<String, String>,
+    k: usize,
+) -> Vec<(FxIndexMap<String, String>, f64)> {
+    // Get the associated graph.
+    let g = b.graph();
+    // Get the variables states.
+    let states = L!(g).map(|x| &b.parameters()[x].states()[x]).collect_vec();
+
+    // Get the evidence indices.
+    let e: FxIndexMap<_, _> = evidence
+        .iter()
+        .map(|(x, y)| {
+            // Get variable index.
+            let i = L!(g)
+                .position(|z| z == x.as_str())
+                .unwrap_or_else(|| panic!("Evidence variable `{x}` must be in the network"));
+            // Get state index.
+            let j = states[i]
+                .get_index_of(y)
+                .unwrap_or_else(|| panic!("Failed to get state `{y}` of `{x}`"));
+
+            (i, j)
+        })
+        .collect();
+
+    // Assert k does not exceed the number of configurations of the unobserved variables.
+    let size = states
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !e.contains_key(i))
+        .fold(1_usize, |n, (_, s)| n.saturating_mul(s.len()));
+    assert!(
+        k <= size,
+        "Number of explanations must not exceed the number of configurations: {size}"
+    );
+
+    // Compute the probability of the evidence P(E = e).
+    let p_e = VariableElimination::<_, false>::new(b)
+        .call(evidence.keys().map(|x| x.as_str()))
+        .condition(evidence, true)
+        .values()
+        .sum();
+    // Check if the evidence has zero probability.
+    if p_e <= 0. {
+        return vec![];
+    }
+
+    // Get the topological order.
+    let order = TopologicalSort::new(g).collect_vec();
+    // Get the CPDs scopes indices, sorted as the CPDs scopes.
+    let scopes = b
+        .parameters()
+        .values()
+        .map(|phi| phi.scope().map(|x| g.get_vertex_index(x)).collect_vec())
+        .collect_vec();
+    // Get the maximum entry of each CPD, compatible with the evidence.
+    let max = scopes
+        .iter()
+        .enumerate()
+        .map(|(i, scope)| {
+            // Get the position of X in the scope.
+            let p = scope.iter().position(|&z| z == i).unwrap();
+            b.parameters()[i]
+                .values()
+                .indexed_iter()
+                .filter(|(idx, _)| e.get(&i).map_or(true, |&j| idx[p] == j))
+                .fold(0., |m, (_, &x)| f64::max(m, x))
+        })
+        .collect_vec();
+    // Compute the upper bounds of the unassigned variables, following the topological order.
+    let mut bounds = vec![1.; order.len() + 1];
+    for d in (0..order.len()).rev() {
+        bounds[d] = bounds[d + 1] * max[order[d]];
+    }
+
+    // Initialize the explanations.
+    let mut mpe = Vec::with_capacity(k);
+    // Initialize the frontier with the empty assignment.
+    let mut frontier = BinaryHeap::from([Node {
+        bound: bounds[0],
+        p: 1.,
+        depth: 0,
+        assignment: vec![0; order.len()],
+    }]);
+    // While there are explanations to be found ...
+    while mpe.len() < k {
+        // ... pop the partial assignment with maximum upper bound ...
+        let node = match frontier.pop() {
+            Some(node) => node,
+            None => break,
+        };
+        // ... if complete, it is the next explanation ...
+        if node.depth == order.len() {
+            // Map the states indices to labels, skipping the evidence.
+            let x = L!(g)
+                .zip(&node.assignment)
+                .enumerate()
+                .filter(|(i, _)| !e.contains_key(i))
+                .map(|(i, (x, &j))| (x.to_owned(), states[i][j].clone()))
+                .collect();
+            mpe.push((x, node.p / p_e));
+            continue;
+        }
+        // ... otherwise, expand the next variable in topological order.
+        let i = order[node.depth];
+        // Get the candidate states, fixed if observed.
+        let candidates = match e.get(&i) {
+            Some(&j) => j..(j + 1),
+            None => 0..states[i].len(),
+        };
+        for j in candidates {
+            // Assign the state.
+            let mut assignment = node.assignment.clone();
+            assignment[i] = j;
+            // Get P(X = x | Pa(X) = pa(x)).
+            let indices = scopes[i].iter().map(|&z| assignment[z]).collect_vec();
+            let p = node.p * b.parameters()[i].values()[indices.as_slice()];
+            // Skip impossible assignments.
+            if p > 0. {
+                frontier.push(Node {
+                    bound: p * bounds[node.depth + 1],
+                    p,
+                    depth: node.depth + 1,
+                    assignment,
+                });
+            }
+        }
+    }
+
+    mpe
+}
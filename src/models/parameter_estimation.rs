@@ -7,6 +7,7 @@ use crate::{
     data::{CategoricalDataMatrix, DataSet},
     graphs::{structs::DirectedDenseAdjacencyMatrixGraph, BaseGraph, DirectedGraph},
     prelude::{BayesianNetwork, CategoricalCPD, ConditionalCountMatrix, MarginalCountMatrix},
+    types::{FxIndexMap, FxIndexSet},
     Pa, L, V,
 };
 
@@ -20,34 +21,174 @@ where
     fn call(d: &D, g: &G) -> M;
 }
 
-/// Maximum Likelihood Estimation (MLE) functor.
-pub struct MaximumLikelihoodEstimation<const PARALLEL: bool> {}
+/// Sufficient statistics of a categorical Bayesian network.
+///
+/// For each variable $X$, it holds the counts of $X$ given its parents $Pa(X)$,
+/// so that the parameters can be estimated multiple times, e.g. with different
+/// priors, without counting the data again.
+#[derive(Clone, Debug)]
+pub struct CategoricalSufficientStatistics {
+    graph: DirectedDenseAdjacencyMatrixGraph,
+    states: FxIndexMap<String, FxIndexSet<String>>,
+    counts: Vec<Array2<usize>>,
+}
 
-impl<const PARALLEL: bool>
-    ParameterEstimation<
-        CategoricalDataMatrix,
-        DirectedDenseAdjacencyMatrixGraph,
-        CategoricalBayesianNetwork,
-    > for MaximumLikelihoodEstimation<PARALLEL>
-{
-    fn call(
+impl CategoricalSufficientStatistics {
+    /// Private function. It computes the sufficient statistics given data and graph.
+    fn compute<const PARALLEL: bool>(
         d: &CategoricalDataMatrix,
         g: &DirectedDenseAdjacencyMatrixGraph,
-    ) -> CategoricalBayesianNetwork {
+    ) -> Self {
         // Assert dataset and graph have same labels.
         assert!(L!(g).eq(d.labels_iter()));
 
-        // Estimate parameters of a given variable.
-        let estimate = |x: usize| {
+        // Count the absolute frequencies of a given variable.
+        let count = |x: usize| {
             // Compute the parents set.
             let z = Pa!(g, x).collect_vec();
             // Compute the absolute frequencies.
-            let n = match z.is_empty() {
-                true => Array1::from(MarginalCountMatrix::new(d, x)).insert_axis(Axis(0)),
-                false => ConditionalCountMatrix::new(d, x, &z).into(),
-            };
-            // Cast to float.
-            let n = n.mapv(|n| n as f64);
+            match (z.is_empty(), PARALLEL) {
+                (true, false) => Array1::from(MarginalCountMatrix::new(d, x)).insert_axis(Axis(0)),
+                (true, true) => {
+                    Array1::from(MarginalCountMatrix::par_new(d, x)).insert_axis(Axis(0))
+                }
+                (false, false) => ConditionalCountMatrix::new(d, x, &z).into(),
+                (false, true) => ConditionalCountMatrix::par_new(d, x, &z).into(),
+            }
+        };
+
+        // Preallocate memory for counts.
+        let mut counts = Vec::with_capacity(g.order());
+
+        // Perform counting.
+        match PARALLEL {
+            true => (0..g.order())
+                .into_par_iter()
+                .map(count)
+                .collect_into_vec(&mut counts),
+            false => counts.extend(V!(g).map(count)),
+        };
+
+        Self {
+            graph: g.clone(),
+            states: d.states().clone(),
+            counts,
+        }
+    }
+
+    /// Compute the sufficient statistics given data $\mathcal{D}$ and graph $\mathcal{G}$.
+    ///
+    /// # Panics
+    ///
+    /// Panics if data and graph have different labels.
+    ///
+    #[inline]
+    pub fn new(d: &CategoricalDataMatrix, g: &DirectedDenseAdjacencyMatrixGraph) -> Self {
+        Self::compute::<false>(d, g)
+    }
+
+    /// Compute the sufficient statistics given data $\mathcal{D}$ and graph $\mathcal{G}$ in parallel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if data and graph have different labels.
+    ///
+    #[inline]
+    pub fn par_new(d: &CategoricalDataMatrix, g: &DirectedDenseAdjacencyMatrixGraph) -> Self {
+        Self::compute::<true>(d, g)
+    }
+
+    /// Private function. It folds the counts of a new batch of data into the statistics.
+    fn fold<const PARALLEL: bool>(&mut self, d: &CategoricalDataMatrix) {
+        // Assert data set and statistics have the same states.
+        assert_eq!(
+            d.states(),
+            &self.states,
+            "Data set and sufficient statistics must have the same states"
+        );
+
+        // Compute the sufficient statistics of the new batch.
+        let s = Self::compute::<PARALLEL>(d, &self.graph);
+        // Sum the counts.
+        self.counts
+            .iter_mut()
+            .zip(s.counts)
+            .for_each(|(n, m)| *n += &m);
+    }
+
+    /// Update the sufficient statistics with a new batch of data $\mathcal{D}'$.
+    ///
+    /// The counts are summed, hence the parameters can be re-estimated with either
+    /// maximum likelihood or Bayesian estimation, without counting the full history.
+    /// To align the states of the new batch, see [`CategoricalDataMatrix::recode`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if data and statistics have different labels or states.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::{prelude::*, polars::prelude::*};
+    /// use ndarray::prelude::*;
+    ///
+    /// // Read data and model from file.
+    /// let d: CategoricalDataMatrix = CsvReader::from_path("./tests/assets/asia.csv")
+    ///     .unwrap()
+    ///     .finish()
+    ///     .unwrap()
+    ///     .into();
+    /// let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+    ///
+    /// // Split the data into two batches.
+    /// let n = d.sample_size() / 2;
+    /// let split = |r: std::ops::Range<usize>| {
+    ///     CategoricalDataMatrix::with_data_labels(d.data().slice(s![r, ..]).to_owned(), d.states().clone())
+    /// };
+    /// let (d_0, d_1) = (split(0..n), split(n..d.sample_size()));
+    ///
+    /// // Count the first batch, then update with the second one.
+    /// let mut s = CategoricalSufficientStatistics::new(&d_0, b.graph());
+    /// s.update(&d_1);
+    ///
+    /// // Check the model matches the one fitted on the full data.
+    /// assert_eq!(BE::from_statistics(&s, 1.), BE::call(&d, b.graph()));
+    /// ```
+    ///
+    #[inline]
+    pub fn update(&mut self, d: &CategoricalDataMatrix) {
+        self.fold::<false>(d)
+    }
+
+    /// Update the sufficient statistics with a new batch of data $\mathcal{D}'$ in parallel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if data and statistics have different labels or states.
+    ///
+    #[inline]
+    pub fn par_update(&mut self, d: &CategoricalDataMatrix) {
+        self.fold::<true>(d)
+    }
+
+    /// Get the underlying graph.
+    #[inline]
+    pub const fn graph(&self) -> &DirectedDenseAdjacencyMatrixGraph {
+        &self.graph
+    }
+
+    /// Get the counts of each variable given its parents, with shape $(\prod_{Z \in Pa(X)} |Z|, |X|)$.
+    #[inline]
+    pub fn counts(&self) -> &[Array2<usize>] {
+        &self.counts
+    }
+
+    /// Private function. It estimates the parameters given the pseudo counts $\alpha$.
+    fn estimate<const PARALLEL: bool>(&self, alpha: f64) -> CategoricalBayesianNetwork {
+        // Estimate parameters of a given variable.
+        let estimate = |x: usize| {
+            // Add pseudo counts and cast to float. // TODO: Generalize to non-uniform distributions.
+            let n = self.counts[x].mapv(|n| n as f64 + alpha);
             // Compute marginal sums.
             let n_i = n.sum_axis(Axis(1)).insert_axis(Axis(1));
             // Check that at least one configuration for each parent set is observed.
@@ -56,90 +197,128 @@ impl<const PARALLEL: bool>
                 "At least one configuration for each parent set must be observed"
             );
             // Get target label and states.
-            let (x, y) = (g.get_vertex_by_index(x), d.states()[x].clone());
+            let (y, s) = (self.graph.get_vertex_by_index(x), self.states[x].clone());
             // Get conditioning variables labels and states.
-            let z = z
-                .into_iter()
-                .map(|z| (g.get_vertex_by_index(z), d.states()[z].clone()));
+            let z = Pa!(self.graph, x)
+                .map(|z| (self.graph.get_vertex_by_index(z), self.states[z].clone()));
             // Construct CPD from states and values.
-            CategoricalCPD::new((x, y), z, n / n_i)
+            CategoricalCPD::new((y, s), z, n / n_i)
         };
 
         // Preallocate memory for parameters.
-        let mut theta = Vec::with_capacity(g.order());
+        let mut theta = Vec::with_capacity(self.graph.order());
 
         // Perform parameters estimation.
         match PARALLEL {
-            true => (0..g.order())
+            true => (0..self.graph.order())
                 .into_par_iter()
                 .map(estimate)
                 .collect_into_vec(&mut theta),
-            false => theta.extend(V!(g).map(estimate)),
+            false => theta.extend(V!(self.graph).map(estimate)),
         };
 
-        CategoricalBayesianNetwork::new(g.clone(), theta)
+        CategoricalBayesianNetwork::new(self.graph.clone(), theta)
     }
 }
 
-/// Bayesian Estimation (BE) functor.
-pub struct BayesianEstimation<const PARALLEL: bool> {}
+/// Maximum Likelihood Estimation (MLE) functor.
+pub struct MaximumLikelihoodEstimation<const PARALLEL: bool> {}
+
+impl<const PARALLEL: bool> MaximumLikelihoodEstimation<PARALLEL> {
+    /// Construct the model $\mathcal{M}$ given precomputed sufficient statistics.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any configuration of the parents set is not observed.
+    ///
+    #[inline]
+    pub fn from_statistics(s: &CategoricalSufficientStatistics) -> CategoricalBayesianNetwork {
+        s.estimate::<PARALLEL>(0.)
+    }
+}
 
 impl<const PARALLEL: bool>
     ParameterEstimation<
         CategoricalDataMatrix,
         DirectedDenseAdjacencyMatrixGraph,
         CategoricalBayesianNetwork,
-    > for BayesianEstimation<PARALLEL>
+    > for MaximumLikelihoodEstimation<PARALLEL>
 {
     fn call(
         d: &CategoricalDataMatrix,
         g: &DirectedDenseAdjacencyMatrixGraph,
     ) -> CategoricalBayesianNetwork {
-        // Assert dataset and graph have same labels.
-        assert!(L!(g).eq(d.labels_iter()));
+        // Compute the sufficient statistics.
+        let s = CategoricalSufficientStatistics::compute::<PARALLEL>(d, g);
 
-        // Estimate parameters of a given variable.
-        let estimate = |x: usize| {
-            // Compute the parents set.
-            let z = Pa!(g, x).collect_vec();
-            // Compute the absolute frequencies.
-            let n = match z.is_empty() {
-                true => Array1::from(MarginalCountMatrix::new(d, x)).insert_axis(Axis(0)),
-                false => ConditionalCountMatrix::new(d, x, &z).into(),
-            };
-            // Add pseudo counts. // TODO: Generalize to non-uniform distributions.
-            let n = n + 1;
-            // Cast to float.
-            let n = n.mapv(|n| n as f64);
-            // Compute marginal sums.
-            let n_i = n.sum_axis(Axis(1)).insert_axis(Axis(1));
-            // Check that at least one configuration for each parent set is observed.
-            assert!(
-                n_i.iter().all(|&n_i| n_i > 0.),
-                "At least one configuration for each parent set must be observed"
-            );
-            // Get target label and states.
-            let (x, y) = (g.get_vertex_by_index(x), d.states()[x].clone());
-            // Get conditioning variables labels and states.
-            let z = z
-                .into_iter()
-                .map(|z| (g.get_vertex_by_index(z), d.states()[z].clone()));
-            // Construct CPD from states and values.
-            CategoricalCPD::new((x, y), z, n / n_i)
-        };
+        Self::from_statistics(&s)
+    }
+}
 
-        // Preallocate memory for parameters.
-        let mut theta = Vec::with_capacity(g.order());
+/// Bayesian Estimation (BE) functor.
+pub struct BayesianEstimation<const PARALLEL: bool> {}
 
-        // Perform parameters estimation.
-        match PARALLEL {
-            true => (0..g.order())
-                .into_par_iter()
-                .map(estimate)
-                .collect_into_vec(&mut theta),
-            false => theta.extend(V!(g).map(estimate)),
-        };
+impl<const PARALLEL: bool> BayesianEstimation<PARALLEL> {
+    /// Construct the model $\mathcal{M}$ given precomputed sufficient statistics and
+    /// a uniform Dirichlet prior with pseudo counts $\alpha$.
+    ///
+    /// This allows to refit the model with different priors, e.g. while tuning $\alpha$,
+    /// without counting the data again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if $\alpha$ is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::{prelude::*, polars::prelude::*};
+    ///
+    /// // Read data and model from file.
+    /// let d: CategoricalDataMatrix = CsvReader::from_path("./tests/assets/asia.csv")
+    ///     .unwrap()
+    ///     .finish()
+    ///     .unwrap()
+    ///     .into();
+    /// let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+    ///
+    /// // Count the data once.
+    /// let s = CategoricalSufficientStatistics::new(&d, b.graph());
+    ///
+    /// // Refit the model with different pseudo counts.
+    /// let c = BE::from_statistics(&s, 1.);
+    /// let _ = BE::from_statistics(&s, 10.);
+    ///
+    /// // Check unit pseudo counts match the default estimation.
+    /// assert_eq!(c, BE::call(&d, b.graph()));
+    /// ```
+    ///
+    #[inline]
+    pub fn from_statistics(
+        s: &CategoricalSufficientStatistics,
+        alpha: f64,
+    ) -> CategoricalBayesianNetwork {
+        // Assert pseudo counts are positive.
+        assert!(alpha > 0., "Pseudo counts must be positive, got {alpha}");
+
+        s.estimate::<PARALLEL>(alpha)
+    }
+}
+
+impl<const PARALLEL: bool>
+    ParameterEstimation<
+        CategoricalDataMatrix,
+        DirectedDenseAdjacencyMatrixGraph,
+        CategoricalBayesianNetwork,
+    > for BayesianEstimation<PARALLEL>
+{
+    fn call(
+        d: &CategoricalDataMatrix,
+        g: &DirectedDenseAdjacencyMatrixGraph,
+    ) -> CategoricalBayesianNetwork {
+        // Compute the sufficient statistics.
+        let s = CategoricalSufficientStatistics::compute::<PARALLEL>(d, g);
 
-        CategoricalBayesianNetwork::new(g.clone(), theta)
+        Self::from_statistics(&s, 1.)
     }
 }
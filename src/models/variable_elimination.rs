@@ -1,11 +1,16 @@
-use std::{collections::BTreeSet, ops::Mul};
+use std::{
+    collections::BTreeSet,
+    fmt::{Display, Formatter},
+    ops::Mul,
+};
 
 use itertools::Itertools;
 use rayon::prelude::*;
 use split_iter::Splittable;
 
 use super::{
-    BayesianNetwork, DistributionEstimation, DistributionProjection, ProbabilisticGraphicalModel,
+    BayesianNetwork, CategoricalFactor, DistributionEstimation, DistributionProjection,
+    ProbabilisticGraphicalModel,
 };
 use crate::{
     graphs::BaseGraph,
@@ -15,6 +20,27 @@ use crate::{
     Adj, Pa, L, V,
 };
 
+/// Inference error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InferenceError {
+    /// The evidence has zero probability, with a minimal subset of conflicting evidence.
+    ImpossibleEvidence(FxIndexMap<String, String>),
+}
+
+impl Display for InferenceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ImpossibleEvidence(e) => write!(
+                f,
+                "Evidence has zero probability, conflicting evidence: {}",
+                e.iter().map(|(x, y)| format!("`{x}` = `{y}`")).join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InferenceError {}
+
 /// Variable Elimination (VE) functor.
 #[derive(Clone, Debug)]
 pub struct VariableElimination<'a, M, const PARALLEL: bool> {
@@ -155,6 +181,106 @@ where
     }
 }
 
+impl<'a, M, const PARALLEL: bool> VariableElimination<'a, M, PARALLEL>
+where
+    M: ProbabilisticGraphicalModel<Phi = CategoricalFactor>,
+{
+    /// Compute the probability of the evidence $\mathcal{P}(\mathbf{E} = \mathbf{e})$.
+    fn evidence_probability(&self, e: &FxIndexMap<String, String>) -> f64 {
+        // Compute P(E) and select P(E = e).
+        let phi = self.call(e.keys().map(|x| x.as_str())).condition(e, true);
+
+        phi.values().sum()
+    }
+
+    /// Compute the posterior distribution $\mathcal{P}(\mathbf{X} \mid \mathbf{E} = \mathbf{e})$.
+    ///
+    /// If the evidence has zero probability, i.e. the normalization constant is zero,
+    /// an [`InferenceError::ImpossibleEvidence`] error is returned instead of a
+    /// distribution of NaNs. The reported evidence is a minimal conflicting subset,
+    /// i.e. removing any of its entries makes it possible.
+    ///
+    /// # Panics
+    ///
+    /// The query and the evidence variables must be disjoint, and the evidence
+    /// must refer to the variables and states of the model.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use causal_hub::prelude::*;
+    ///
+    /// // Read BIF file.
+    /// let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+    ///
+    /// // Set an impossible evidence, since `either` is the logical OR of `lung` and `tub`.
+    /// let e = FxIndexMap::from_iter([
+    ///     ("either".to_string(), "no".to_string()),
+    ///     ("lung".to_string(), "yes".to_string()),
+    ///     ("smoke".to_string(), "yes".to_string()),
+    /// ]);
+    ///
+    /// // Query the posterior distribution.
+    /// let p = VE::new(&b).query(["dysp"], &e);
+    ///
+    /// // Check the conflicting evidence is reported.
+    /// assert_eq!(
+    ///     p,
+    ///     Err(InferenceError::ImpossibleEvidence(FxIndexMap::from_iter([
+    ///         ("either".to_string(), "no".to_string()),
+    ///         ("lung".to_string(), "yes".to_string()),
+    ///     ])))
+    /// );
+    /// ```
+    ///
+    pub fn query<'b, X>(
+        &self,
+        x: X,
+        e: &FxIndexMap<String, String>,
+    ) -> Result<CategoricalFactor, InferenceError>
+    where
+        X: IntoIterator<Item = &'b str>,
+    {
+        // Sort and deduplicate query variables.
+        let x: BTreeSet<_> = x.into_iter().collect();
+        // Assert query and evidence variables are disjoint.
+        assert!(
+            x.iter().all(|x| !e.contains_key(*x)),
+            "Query and evidence variables must be disjoint"
+        );
+
+        // Compute P(X, E) and select P(X, E = e).
+        let phi = self
+            .call(x.into_iter().chain(e.keys().map(|x| x.as_str())))
+            .condition(e, true);
+
+        // Check if the evidence has zero probability.
+        if phi.values().sum() <= 0. {
+            // Initialize the conflicting evidence.
+            let mut conflict = e.clone();
+            // For each evidence variable ...
+            for x in e.keys() {
+                // ... remove it ...
+                let y = conflict.shift_remove(x).unwrap();
+                // ... and restore it if the remaining evidence becomes possible.
+                if self.evidence_probability(&conflict) > 0. {
+                    conflict.insert(x.clone(), y);
+                }
+            }
+            // Preserve the evidence order.
+            let conflict = e
+                .iter()
+                .filter(|(x, _)| conflict.contains_key(*x))
+                .map(|(x, y)| (x.clone(), y.clone()))
+                .collect();
+
+            return Err(InferenceError::ImpossibleEvidence(conflict));
+        }
+
+        Ok(phi.normalize())
+    }
+}
+
 impl<'a, M, const PARALLEL: bool> DistributionEstimation for VariableElimination<'a, M, PARALLEL>
 where
     M: ProbabilisticGraphicalModel,
@@ -203,8 +329,9 @@ where
                     Pa!(g_q, x).map(|z| g_q.get_vertex_by_index(z)),
                 )
             })
-            // Project P parameters onto Q structure.
-            .map(|(x, z)| self.conditional(x, z));
+            // Project P parameters onto Q structure, where zero-probability
+            // parents configurations, e.g. due to deterministic relations, are uniform.
+            .map(|(x, z)| self.conditional(x, z).with_uniform_undefined());
         // Construct projection of P given projected parameters.
         Self::Projection::with_parameters(theta)
     }
@@ -1,3 +1,5 @@
+use ndarray::prelude::*;
+
 use crate::{
     data::{CategoricalDataMatrix, GaussianDataMatrix},
     discovery::DecomposableScoringCriterion,
@@ -26,6 +28,22 @@ impl<'a, D> AkaikeInformationCriterion<'a, D> {
     }
 }
 
+impl<'a> AkaikeInformationCriterion<'a, CategoricalDataMatrix> {
+    /// Set the samples weights.
+    ///
+    /// # Panics
+    ///
+    /// See [`LogLikelihood::with_weights`].
+    ///
+    #[inline]
+    pub fn with_weights(mut self, weights: Array1<f64>) -> Self {
+        // Set the weights of the log-likelihood.
+        self.log_likelihood = self.log_likelihood.with_weights(weights);
+
+        self
+    }
+}
+
 /* Implement AIC for categorical data. */
 impl<'a, G> DecomposableScoringCriterion<CategoricalDataMatrix, G>
     for AkaikeInformationCriterion<'a, CategoricalDataMatrix>
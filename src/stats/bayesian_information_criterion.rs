@@ -1,3 +1,5 @@
+use ndarray::prelude::*;
+
 use crate::{
     data::{CategoricalDataMatrix, DataSet, GaussianDataMatrix, ZINBDataMatrix},
     discovery::DecomposableScoringCriterion,
@@ -25,6 +27,25 @@ impl<'a, D> BayesianInformationCriterion<'a, D> {
     }
 }
 
+impl<'a> BayesianInformationCriterion<'a, CategoricalDataMatrix> {
+    /// Set the samples weights.
+    ///
+    /// The log-likelihood is weighted accordingly, while the sum of the weights
+    /// is used as the effective sample size in the penalty term.
+    ///
+    /// # Panics
+    ///
+    /// See [`LogLikelihood::with_weights`].
+    ///
+    #[inline]
+    pub fn with_weights(mut self, weights: Array1<f64>) -> Self {
+        // Set the weights of the log-likelihood.
+        self.log_likelihood = self.log_likelihood.with_weights(weights);
+
+        self
+    }
+}
+
 /* Implement BIC for categorical data_set. */
 impl<'a, G> DecomposableScoringCriterion<CategoricalDataMatrix, G>
     for BayesianInformationCriterion<'a, CategoricalDataMatrix>
@@ -36,8 +57,8 @@ where
         // Compute the log-likelihood.
         let log_likelihood = DecomposableScoringCriterion::<_, G>::call(&self.log_likelihood, x, z);
 
-        // Get the sample size.
-        let n = self.log_likelihood.data_set.sample_size() as f64;
+        // Get the (effective) sample size.
+        let n = self.log_likelihood.effective_sample_size();
         // Get the cardinality.
         let cards = self.log_likelihood.data_set.cardinality();
         // Get the cardinality of vertices.
@@ -5,6 +5,7 @@ use statrs::function::gamma::gamma_lr;
 
 use crate::{
     data::{CategoricalDataMatrix, JointConditionalCountMatrix, JointCountMatrix},
+    discovery::check_significance_level,
     prelude::{ConditionalIndependenceTest, DataSet, FxIndexSet},
     utils::nan_to_zero,
 };
@@ -86,10 +87,15 @@ impl<'a> ConditionalIndependenceTest<'a> for ChiSquared<'a> {
         pval > self.alpha
     }
 
+    #[inline]
+    fn significance_level(&self) -> f64 {
+        self.alpha
+    }
+
     #[inline]
     fn with_significance_level(mut self, alpha: f64) -> Self {
         // Assert alpha in (0, 1).
-        assert!((0. ..1.).contains(&alpha));
+        check_significance_level(alpha);
         // Set significance level.
         self.alpha = alpha;
 
@@ -5,6 +5,18 @@ use ndarray_stats::CorrelationExt;
 
 use crate::data::{DataSet, GaussianDataMatrix};
 
+/// Covariance matrix estimator.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CovarianceEstimator {
+    /// (Sample) Empirical covariance matrix.
+    #[default]
+    Empirical,
+    /// Ledoit-Wolf shrinkage[^1] of the empirical covariance matrix towards a scaled identity.
+    ///
+    /// [^1]: [Ledoit, O., & Wolf, M. (2004). A well-conditioned estimator for large-dimensional covariance matrices. Journal of Multivariate Analysis, 88(2), 365-411.](https://scholar.google.com/scholar?q=A+well-conditioned+estimator+for+large-dimensional+covariance+matrices)
+    LedoitWolf,
+}
+
 /// (Sample) Covariance matrix $\Sigma$.
 #[derive(Clone, Debug)]
 pub struct CovarianceMatrix {
@@ -26,6 +38,52 @@ impl CovarianceMatrix {
 
         Self { sigma }
     }
+
+    /// Estimate the covariance matrix of a data set with a given estimator.
+    ///
+    /// The Ledoit-Wolf estimator computes the maximum likelihood covariance matrix $S$
+    /// and shrinks it towards $\mu I$, with $\mu = tr(S) / p$, as:
+    ///
+    /// $$ \Sigma = \delta \mu I + (1 - \delta) S $$
+    ///
+    /// where the shrinkage intensity $\delta \in [0, 1]$ is estimated from the data.
+    /// The result is positive definite even if $S$ is singular, e.g. if $p \ge n$.
+    ///
+    pub fn new_with_estimator(d: &GaussianDataMatrix, estimator: CovarianceEstimator) -> Self {
+        match estimator {
+            CovarianceEstimator::Empirical => Self::from(d),
+            CovarianceEstimator::LedoitWolf => {
+                // Get the sample size.
+                let n = d.sample_size() as f64;
+                // Get the number of variables.
+                let p = d.data().ncols();
+                // Center the data.
+                let x = d.data() - &d.data().mean_axis(Axis(0)).expect("Empty data set");
+                // Compute the maximum likelihood covariance matrix.
+                let s = x.t().dot(&x) / n;
+                // Compute the shrinkage target.
+                let mu = s.diag().sum() / p as f64;
+                let f = Array2::<f64>::eye(p) * mu;
+                // Compute the squared distance between the covariance matrix and the target.
+                let d2 = (&s - &f).mapv(|s| s * s).sum();
+                // Compute the squared distance between the samples outer products and the covariance matrix.
+                let b2 = (x.rows().into_iter().map(|x| x.dot(&x).powi(2)).sum::<f64>()
+                    - n * s.mapv(|s| s * s).sum())
+                    / (n * n);
+                // Compute the shrinkage intensity.
+                let delta = match d2 > 0. {
+                    true => f64::min(b2, d2) / d2,
+                    false => 0.,
+                };
+                // Shrink the covariance matrix towards the target.
+                let sigma = f * delta + s * (1. - delta);
+                // Enforce symmetry.
+                let sigma = (&sigma + &sigma.t()) / 2.;
+
+                Self { sigma }
+            }
+        }
+    }
 }
 
 impl Deref for CovarianceMatrix {
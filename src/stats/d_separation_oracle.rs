@@ -0,0 +1,99 @@
+use std::iter::Map;
+
+use crate::{
+    discovery::{check_significance_level, ConditionalIndependenceTest},
+    graphs::{structs::DirectedDenseAdjacencyMatrixGraph, BaseGraph},
+    models::{GraphicalSeparation, Independence},
+};
+
+/// d-separation oracle conditional independence test.
+///
+/// The independence statements are given by d-separation in a ground-truth graph,
+/// rather than by a statistic computed from data. Therefore, the p-value is $1$
+/// if $X$ and $Y$ are d-separated by $\mathbf{Z}$ and $0$ otherwise, for any $\alpha$.
+/// This is useful to test constraint-based discovery algorithms in isolation from
+/// sampling noise.
+///
+/// # Examples
+///
+/// ```
+/// use causal_hub::prelude::*;
+///
+/// // Build a new directed graph.
+/// let g = DiGraph::new(["A", "B", "C"], [("A", "C"), ("B", "C")]);
+///
+/// // Build the d-separation oracle.
+/// let oracle = DSeparationOracle::new(&g);
+///
+/// // Perform discovery.
+/// let h = PCStable::new(&oracle).call();
+///
+/// // Assert the v-structure is recovered.
+/// assert!(h.has_directed_edge_by_index(0, 2));
+/// assert!(h.has_directed_edge_by_index(1, 2));
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct DSeparationOracle<'a> {
+    g: &'a DirectedDenseAdjacencyMatrixGraph,
+    alpha: f64,
+}
+
+impl<'a> DSeparationOracle<'a> {
+    /// Construct d-separation oracle with $\alpha = 0.05$ .
+    #[inline]
+    pub fn new(g: &'a DirectedDenseAdjacencyMatrixGraph) -> Self {
+        Self { g, alpha: 0.05 }
+    }
+}
+
+impl<'a> From<&'a DirectedDenseAdjacencyMatrixGraph> for DSeparationOracle<'a> {
+    #[inline]
+    fn from(g: &'a DirectedDenseAdjacencyMatrixGraph) -> Self {
+        Self::new(g)
+    }
+}
+
+impl<'a> ConditionalIndependenceTest<'a> for DSeparationOracle<'a> {
+    type LabelsIter<'b> =
+        Map<indexmap::set::Iter<'b, String>, fn(&'b String) -> &'b str> where Self: 'b;
+
+    #[inline]
+    fn eval(&self, x: usize, y: usize, z: &[usize]) -> (usize, f64, f64) {
+        // Check d-separation in the ground-truth graph.
+        let pval = match GraphicalSeparation::new(self.g).is_independent(x, y, z) {
+            true => 1.,
+            false => 0.,
+        };
+
+        (0, 0., pval)
+    }
+
+    #[inline]
+    fn call(&self, x: usize, y: usize, z: &[usize]) -> bool {
+        // Compute p-value.
+        let (_, _, pval) = self.eval(x, y, z);
+
+        pval > self.alpha
+    }
+
+    #[inline]
+    fn significance_level(&self) -> f64 {
+        self.alpha
+    }
+
+    #[inline]
+    fn with_significance_level(mut self, alpha: f64) -> Self {
+        // Assert alpha in (0, 1).
+        check_significance_level(alpha);
+        // Set significance level.
+        self.alpha = alpha;
+
+        self
+    }
+
+    #[inline]
+    fn labels(&self) -> Self::LabelsIter<'_> {
+        self.g.get_vertices()
+    }
+}
@@ -0,0 +1,219 @@
+use std::fmt::{Display, Formatter};
+
+use statrs::function::gamma::gamma_lr;
+
+use crate::{
+    data::{CategoricalDataMatrix, DataSet, MarginalCountMatrix},
+    types::FxIndexMap,
+};
+
+/// Comparison of the marginal distribution of a variable across two data sets.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MarginalComparison {
+    total_variation: f64,
+    statistic: f64,
+    dof: usize,
+    p_value: f64,
+}
+
+impl MarginalComparison {
+    /// Get the total variation distance $\delta(P, Q) = \frac{1}{2} \sum_x |P(x) - Q(x)|$.
+    #[inline]
+    pub const fn total_variation(&self) -> f64 {
+        self.total_variation
+    }
+
+    /// Get the chi-squared goodness-of-fit statistic.
+    #[inline]
+    pub const fn statistic(&self) -> f64 {
+        self.statistic
+    }
+
+    /// Get the degrees of freedom of the goodness-of-fit test.
+    #[inline]
+    pub const fn dof(&self) -> usize {
+        self.dof
+    }
+
+    /// Get the p-value of the goodness-of-fit test.
+    #[inline]
+    pub const fn p_value(&self) -> f64 {
+        self.p_value
+    }
+}
+
+/// Agreement report between the empirical distributions of two categorical data sets.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DistributionComparison {
+    marginals: FxIndexMap<String, MarginalComparison>,
+}
+
+impl DistributionComparison {
+    /// Get the marginal comparisons, keyed by the shared variables.
+    #[inline]
+    pub const fn marginals(&self) -> &FxIndexMap<String, MarginalComparison> {
+        &self.marginals
+    }
+
+    /// Get the maximum total variation distance across the shared variables.
+    #[inline]
+    pub fn max_total_variation(&self) -> f64 {
+        self.marginals
+            .values()
+            .map(|m| m.total_variation)
+            .fold(0., f64::max)
+    }
+}
+
+impl Display for DistributionComparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Get the width of the variables column.
+        let w = self
+            .marginals
+            .keys()
+            .map(|x| x.len())
+            .fold("variable".len(), usize::max);
+        // Write the header.
+        writeln!(
+            f,
+            "{:<w$}  {:>15}  {:>12}  {:>5}  {:>10}",
+            "variable", "total_variation", "chi_squared", "dof", "p_value"
+        )?;
+        // Write a row for each variable.
+        for (x, m) in &self.marginals {
+            writeln!(
+                f,
+                "{:<w$}  {:>15.6}  {:>12.4}  {:>5}  {:>10.4}",
+                x, m.total_variation, m.statistic, m.dof, m.p_value
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Compare the empirical marginal distributions of two categorical data sets.
+///
+/// For each variable shared by label, it computes the total variation distance between the
+/// marginals of `real` and `synthetic`, and a chi-squared goodness-of-fit test of the counts of
+/// `synthetic` against the frequencies of `real`:
+///
+/// $$ \chi^2 = \sum_x \frac{(O(x) - E(x))^2}{E(x)} \quad \text{with} \quad E(x) = n \hat{P}(x) $$
+///
+/// where $n$ is the sample size of `synthetic`. States never observed in `real` are excluded
+/// from the degrees of freedom, while observing them in `synthetic` yields an infinite statistic.
+///
+/// This is useful to sanity check that samples drawn from a fitted model reproduce the
+/// statistics of the data.
+///
+/// # Panics
+///
+/// The data sets must be non-empty and the shared variables must have the same states.
+///
+/// # Examples
+///
+/// ```
+/// use causal_hub::{prelude::*, polars::prelude::*};
+/// use rand::SeedableRng;
+/// use rand_xoshiro::Xoshiro256PlusPlus;
+///
+/// // Read data and model from file.
+/// let d: CategoricalDataMatrix = CsvReader::from_path("./tests/assets/asia.csv")
+///     .unwrap()
+///     .finish()
+///     .unwrap()
+///     .into();
+/// let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+///
+/// // Draw samples from the model.
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+/// let s = ForwardSampling::new(&b).sample(&mut rng, d.sample_size());
+///
+/// // Compare the empirical distributions.
+/// let r = compare_distributions(&d, &s);
+///
+/// // Check every variable is compared.
+/// assert_eq!(r.marginals().len(), 8);
+/// // Check the marginals agree.
+/// assert!(r.max_total_variation() < 0.05);
+/// ```
+///
+pub fn compare_distributions(
+    real: &CategoricalDataMatrix,
+    synthetic: &CategoricalDataMatrix,
+) -> DistributionComparison {
+    // Assert data sets are non-empty.
+    assert!(
+        real.sample_size() > 0 && synthetic.sample_size() > 0,
+        "Data sets must not be empty"
+    );
+
+    // Get the synthetic sample size.
+    let n = synthetic.sample_size() as f64;
+
+    // For each variable shared by label ...
+    let marginals = real
+        .states()
+        .iter()
+        .enumerate()
+        .filter_map(|(i, (x, s))| {
+            // ... get its index in the synthetic data set ...
+            let (j, _, t) = synthetic.states().get_full(x)?;
+            // ... assert the states match ...
+            assert_eq!(
+                s, t,
+                "Variable `{x}` must have the same states in both data sets"
+            );
+
+            // Compute the real frequencies.
+            let p = MarginalCountMatrix::new(real, i)
+                .values()
+                .mapv(|c| c as f64);
+            let p = &p / p.sum();
+            // Compute the synthetic counts.
+            let o = MarginalCountMatrix::new(synthetic, j)
+                .values()
+                .mapv(|c| c as f64);
+
+            // Compute the total variation distance.
+            let total_variation = 0.5
+                * p.iter()
+                    .zip(&o)
+                    .map(|(p, o)| (p - o / n).abs())
+                    .sum::<f64>();
+
+            // Compute the goodness-of-fit statistic, with E(x) = n P(x).
+            let statistic = p
+                .iter()
+                .zip(&o)
+                .map(|(&p, &o)| match (p * n, o) {
+                    // Skip states never observed in either data set.
+                    (e, o) if e == 0. && o == 0. => 0.,
+                    // Observing an impossible state is infinitely unlikely.
+                    (e, _) if e == 0. => f64::INFINITY,
+                    (e, o) => f64::powi(o - e, 2) / e,
+                })
+                .sum::<f64>();
+            // Compute the degrees of freedom over the states observed in the real data set.
+            let dof = p.iter().filter(|&&p| p > 0.).count().saturating_sub(1);
+            // Compute the p-value, degenerate if the statistic is infinite or there are no degrees of freedom.
+            let p_value = match (dof, statistic) {
+                (_, s) if s.is_infinite() => 0.,
+                (0, _) => 1.,
+                (k, s) => 1. - gamma_lr(k as f64 * 0.5, s * 0.5 + f64::EPSILON),
+            };
+
+            Some((
+                x.clone(),
+                MarginalComparison {
+                    total_variation,
+                    statistic,
+                    dof,
+                    p_value,
+                },
+            ))
+        })
+        .collect();
+
+    DistributionComparison { marginals }
+}
@@ -8,9 +8,9 @@ use libm::erfc;
 
 use crate::{
     data::GaussianDataMatrix,
-    discovery::ConditionalIndependenceTest,
+    discovery::{check_significance_level, ConditionalIndependenceTest},
     prelude::DataSet,
-    stats::{CovarianceMatrix, PartialCorrelation},
+    stats::{CovarianceEstimator, CovarianceMatrix, PartialCorrelation},
 };
 
 /// Fisher's Z conditional independence test.
@@ -26,8 +26,14 @@ impl<'a> FisherZ {
     /// Construct Fisher's Z conditional independence test with $\alpha = 0.05$ .
     #[inline]
     pub fn new(d: &'a GaussianDataMatrix) -> Self {
+        Self::new_with_covariance(d, Default::default())
+    }
+
+    /// Construct Fisher's Z conditional independence test with $\alpha = 0.05$
+    /// given the covariance matrix estimator, e.g. shrinkage for $p$ close to $n$.
+    pub fn new_with_covariance(d: &'a GaussianDataMatrix, estimator: CovarianceEstimator) -> Self {
         // Compute covariance matrix.
-        let sigma = CovarianceMatrix::from(d);
+        let sigma = CovarianceMatrix::new_with_estimator(d, estimator);
         // Initialize partial correlation functor.
         let rho = PartialCorrelation::from(sigma);
 
@@ -83,10 +89,15 @@ impl<'a> ConditionalIndependenceTest<'a> for FisherZ {
         pval > self.alpha
     }
 
+    #[inline]
+    fn significance_level(&self) -> f64 {
+        self.alpha
+    }
+
     #[inline]
     fn with_significance_level(mut self, alpha: f64) -> Self {
         // Assert alpha in (0, 1).
-        assert!((0. ..1.).contains(&alpha));
+        check_significance_level(alpha);
         // Set significance level.
         self.alpha = alpha;
 
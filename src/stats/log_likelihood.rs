@@ -213,13 +213,86 @@ impl<'a> ConditionalLogLikelihood<'a, GaussianDataMatrix> {
 #[derive(Clone, Debug)]
 pub struct LogLikelihood<'a, D> {
     pub(crate) data_set: &'a D,
+    pub(crate) weights: Option<Array1<f64>>,
 }
 
 impl<'a, D> LogLikelihood<'a, D> {
     /// Constructor for LL functor.
     #[inline]
     pub const fn new(data_set: &'a D) -> Self {
-        Self { data_set }
+        Self {
+            data_set,
+            weights: None,
+        }
+    }
+}
+
+impl<'a> LogLikelihood<'a, CategoricalDataMatrix> {
+    /// Set the samples weights.
+    ///
+    /// Each sample log-probability is multiplied by its weight, e.g. to score
+    /// data sets after inverse probability weighting or a weighted bootstrap.
+    /// Integer weights are equivalent to repeating each sample accordingly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of weights is not equal to the sample size, or if
+    /// any weight is negative or not finite.
+    ///
+    pub fn with_weights(mut self, weights: Array1<f64>) -> Self {
+        // Assert weights match the sample size.
+        assert_eq!(
+            weights.len(),
+            self.data_set.sample_size(),
+            "Number of weights must be equal to the sample size"
+        );
+        // Assert weights are non-negative and finite.
+        assert!(
+            weights.iter().all(|w| w.is_finite() && *w >= 0.),
+            "Weights must be non-negative and finite"
+        );
+        // Set the weights.
+        self.weights = Some(weights);
+
+        self
+    }
+
+    /// Get the effective sample size, i.e. the sum of the weights, if any.
+    #[inline]
+    pub fn effective_sample_size(&self) -> f64 {
+        match &self.weights {
+            Some(w) => w.sum(),
+            None => self.data_set.sample_size() as f64,
+        }
+    }
+
+    /// Private function. It computes the weighted log-likelihood of vertex $X$ and parents $\mathbf{Z}$.
+    fn weighted_call(&self, w: &Array1<f64>, x: usize, z: &[usize]) -> f64 {
+        // Get the cardinality.
+        let cards = self.data_set.cardinality();
+        // Get the cardinality of the parents configurations.
+        let card_z = z.iter().map(|&z| cards[z] as usize).product::<usize>();
+        // Initialize the weighted contingency table.
+        let mut n_ij = Array2::<f64>::zeros((card_z, cards[x] as usize));
+        // For each sample and weight ...
+        for (s, &w) in self.data_set.data().rows().into_iter().zip(w) {
+            // ... ravel the parents configuration index ...
+            let j = z
+                .iter()
+                .fold(0, |j, &z| j * cards[z] as usize + s[z] as usize);
+            // ... and add the weight to the associated count.
+            n_ij[[j, s[x] as usize]] += w;
+        }
+
+        // Sum over states.
+        let n_j = n_ij.sum_axis(Axis(1)).insert_axis(Axis(1));
+
+        // Compute log-likelihood as n_ij * ln(n_ij  / n_i).
+        (&n_ij * (&n_ij / n_j).mapv(f64::ln))
+            // Map NaNs to zero.
+            .mapv(nan_to_zero)
+            // Sum each term.
+            .sum()
     }
 }
 
@@ -230,6 +303,11 @@ where
 {
     #[inline]
     fn call(&self, x: usize, z: &[usize]) -> f64 {
+        // Check if samples are weighted.
+        if let Some(w) = &self.weights {
+            return self.weighted_call(w, x, z);
+        }
+
         match z.is_empty() {
             true => MarginalLogLikelihood::new(self.data_set).call(x),
             false => ConditionalLogLikelihood::new(self.data_set).call(x, z),
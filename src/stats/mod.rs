@@ -25,6 +25,12 @@ pub use correlation_matrix::*;
 mod covariance_matrix;
 pub use covariance_matrix::*;
 
+mod d_separation_oracle;
+pub use d_separation_oracle::*;
+
+mod distribution_comparison;
+pub use distribution_comparison::*;
+
 mod fisher_z;
 pub use fisher_z::*;
 
@@ -34,6 +40,12 @@ pub use log_likelihood::*;
 mod partial_correlation;
 pub use partial_correlation::*;
 
+mod partial_correlation_matrix;
+pub use partial_correlation_matrix::*;
+
+mod penalized_score;
+pub use penalized_score::*;
+
 mod precision_matrix;
 pub use precision_matrix::*;
 
@@ -0,0 +1,86 @@
+use std::ops::Deref;
+
+use ndarray::prelude::*;
+
+use super::{CovarianceMatrix, PrecisionMatrix};
+
+/// Partial correlation matrix $\Rho_{\cdot}$.
+///
+/// Each off-diagonal entry is the partial correlation of $X_i$ and $X_j$ given all the
+/// other variables, derived from the precision matrix $\Omega$ as:
+///
+/// $$ \rho_{ij \cdot} = - \frac{\omega_{ij}}{\sqrt{\omega_{ii} \omega_{jj}}} $$
+///
+/// Near-zero entries suggest conditional independence, as tested by [`FisherZ`](super::FisherZ).
+///
+#[derive(Clone, Debug)]
+pub struct PartialCorrelationMatrix {
+    rho: Array2<f64>,
+}
+
+impl PartialCorrelationMatrix {
+    /// Construct a new partial correlation matrix.
+    ///
+    /// # Panics
+    ///
+    /// The matrix must be squared, symmetric and all values are in the [-1, +1] interval.
+    #[inline]
+    pub fn new(rho: Array2<f64>) -> Self {
+        // Assert Rho is square ...
+        assert!(rho.is_square(), "Partial correlation matrix must be square");
+        // ... symmetric ...
+        assert_eq!(rho, rho.t(), "Partial correlation matrix must be symmetric");
+        // ... and all values are in the [-1., +1.] interval.
+        assert!(
+            rho.iter().all(|r| (-1. ..=1.).contains(r)),
+            "Partial correlation matrix must be in the [-1, +1] interval"
+        );
+
+        Self { rho }
+    }
+}
+
+impl Deref for PartialCorrelationMatrix {
+    type Target = Array2<f64>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.rho
+    }
+}
+
+impl From<PartialCorrelationMatrix> for Array2<f64> {
+    #[inline]
+    fn from(other: PartialCorrelationMatrix) -> Self {
+        other.rho
+    }
+}
+
+impl From<PrecisionMatrix> for PartialCorrelationMatrix {
+    #[inline]
+    fn from(omega: PrecisionMatrix) -> Self {
+        // Get underlying data.
+        let omega: Array2<f64> = omega.into();
+        // Compute the scaling vector.
+        let d = omega.diag().mapv(|o| 1. / f64::sqrt(o));
+        // Cast to column vector.
+        let d = d.insert_axis(Axis(1));
+        // Compute the partial correlation matrix.
+        let mut rho = -(&d * omega * d.t());
+        // Enforce symmetry.
+        rho = (&rho + &rho.t()) / 2.;
+        // Set the diagonal to +1.
+        rho.diag_mut().fill(1.);
+        // Clip the values to [-1., +1.].
+        let rho = rho.mapv(|r| r.clamp(-1., 1.));
+
+        Self { rho }
+    }
+}
+
+impl From<CovarianceMatrix> for PartialCorrelationMatrix {
+    #[inline]
+    fn from(sigma: CovarianceMatrix) -> Self {
+        Self::from(PrecisionMatrix::from(sigma))
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{discovery::DecomposableScoringCriterion, types::FxIndexMap};
+
+/// Penalized score functor, i.e. a base decomposable score with a structure penalty.
+///
+/// $\mathcal{S}'(X, \mathbf{Z}) = \mathcal{S}(X, \mathbf{Z}) - \lambda |\mathbf{Z}| + \sum_{Z \in \mathbf{Z}} \log \pi(Z \rightarrow X)$
+///
+/// where $\lambda$ is the per-edge penalty and $\log \pi(Z \rightarrow X)$ is the log-prior
+/// of the edge, zero if not given. Since both terms depend only on the parents of $X$,
+/// the penalized score is still decomposable, encoding soft prior knowledge.
+///
+#[derive(Clone, Debug)]
+pub struct PenalizedScore<S> {
+    base: S,
+    edge_penalty: f64,
+    structure_prior: FxIndexMap<(usize, usize), f64>,
+}
+
+impl<S> PenalizedScore<S> {
+    /// Constructor for penalized score functor, without penalty.
+    #[inline]
+    pub fn new(base: S) -> Self {
+        Self {
+            base,
+            edge_penalty: 0.,
+            structure_prior: Default::default(),
+        }
+    }
+
+    /// Set the per-edge penalty $\lambda$.
+    ///
+    /// # Panics
+    ///
+    /// The penalty must be non-negative and finite.
+    ///
+    #[inline]
+    pub fn with_edge_penalty(mut self, edge_penalty: f64) -> Self {
+        // Assert penalty is non-negative and finite.
+        assert!(
+            edge_penalty >= 0. && edge_penalty.is_finite(),
+            "Edge penalty must be non-negative and finite"
+        );
+        // Set edge penalty.
+        self.edge_penalty = edge_penalty;
+
+        self
+    }
+
+    /// Set the per-edge log-prior $\log \pi(Z \rightarrow X)$, keyed by $(Z, X)$ indices.
+    ///
+    /// # Panics
+    ///
+    /// The log-priors must be finite.
+    ///
+    #[inline]
+    pub fn with_structure_prior(
+        mut self,
+        structure_prior: FxIndexMap<(usize, usize), f64>,
+    ) -> Self {
+        // Assert log-priors are finite.
+        assert!(
+            structure_prior.values().all(|p| p.is_finite()),
+            "Structure prior must be finite"
+        );
+        // Set structure prior.
+        self.structure_prior = structure_prior;
+
+        self
+    }
+
+    /// Get the base score.
+    #[inline]
+    pub const fn base(&self) -> &S {
+        &self.base
+    }
+}
+
+impl<D, G, S> DecomposableScoringCriterion<D, G> for PenalizedScore<S>
+where
+    S: DecomposableScoringCriterion<D, G>,
+{
+    #[inline]
+    fn call(&self, x: usize, z: &[usize]) -> f64 {
+        // Compute the base score.
+        let s = self.base.call(x, z);
+        // Compute the edge penalty.
+        let penalty = self.edge_penalty * z.len() as f64;
+        // Compute the log-prior of the incoming edges.
+        let prior: f64 = z
+            .iter()
+            .filter_map(|&z| self.structure_prior.get(&(z, x)))
+            .sum();
+
+        s - penalty + prior
+    }
+
+    #[inline]
+    fn max_in_degree_hint(&self) -> Option<usize> {
+        // The base hint holds only if no edge is rewarded.
+        match self.structure_prior.values().all(|&p| p <= 0.) {
+            true => self.base.max_in_degree_hint(),
+            false => None,
+        }
+    }
+}
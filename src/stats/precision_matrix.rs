@@ -1,7 +1,7 @@
 use std::ops::Deref;
 
 use ndarray::prelude::*;
-use ndarray_linalg::InverseInto;
+use ndarray_linalg::InverseCInto;
 
 use super::CovarianceMatrix;
 
@@ -49,10 +49,10 @@ impl From<CovarianceMatrix> for PrecisionMatrix {
     fn from(sigma: CovarianceMatrix) -> Self {
         // Get underlying data.
         let sigma: Array2<f64> = sigma.into();
-        // Compute the inverse of the correlation matrix. TODO: Use SVD decomposition.
+        // Compute the inverse of the covariance matrix through its Cholesky decomposition.
         let omega = sigma
-            .inv_into()
-            .expect("Failed to compute the inverse of the covariance matrix");
+            .invc_into()
+            .expect("Covariance matrix must be positive definite, consider a shrinkage estimator, e.g. `CovarianceEstimator::LedoitWolf`");
 
         Self { omega }
     }
@@ -0,0 +1,60 @@
+use itertools::Itertools;
+
+use crate::types::FxIndexSet;
+
+/// Compute the sorted intersection of two sets of labels.
+///
+/// # Examples
+///
+/// ```
+/// use causal_hub::{types::FxIndexSet, utils::labels_intersection};
+///
+/// // Initialize two sets of labels.
+/// let a: FxIndexSet<String> = ["A", "B", "C"].into_iter().map(String::from).collect();
+/// let b: FxIndexSet<String> = ["D", "C", "B"].into_iter().map(String::from).collect();
+///
+/// // The intersection is sorted.
+/// assert!(labels_intersection(&a, &b).iter().eq(["B", "C"]));
+/// ```
+///
+pub fn labels_intersection(a: &FxIndexSet<String>, b: &FxIndexSet<String>) -> FxIndexSet<String> {
+    a.intersection(b).sorted().cloned().collect()
+}
+
+/// Compute the sorted union of two sets of labels.
+///
+/// # Examples
+///
+/// ```
+/// use causal_hub::{types::FxIndexSet, utils::labels_union};
+///
+/// // Initialize two sets of labels.
+/// let a: FxIndexSet<String> = ["A", "B", "C"].into_iter().map(String::from).collect();
+/// let b: FxIndexSet<String> = ["D", "C", "B"].into_iter().map(String::from).collect();
+///
+/// // The union is sorted.
+/// assert!(labels_union(&a, &b).iter().eq(["A", "B", "C", "D"]));
+/// ```
+///
+pub fn labels_union(a: &FxIndexSet<String>, b: &FxIndexSet<String>) -> FxIndexSet<String> {
+    a.union(b).sorted().cloned().collect()
+}
+
+/// Map the position of each label in `a` to its position in `b`, if any.
+///
+/// # Examples
+///
+/// ```
+/// use causal_hub::{types::FxIndexSet, utils::labels_reindex_into};
+///
+/// // Initialize two sets of labels.
+/// let a: FxIndexSet<String> = ["A", "B", "C"].into_iter().map(String::from).collect();
+/// let b: FxIndexSet<String> = ["C", "D", "A"].into_iter().map(String::from).collect();
+///
+/// // Map the positions of `a` into `b`.
+/// assert_eq!(labels_reindex_into(&a, &b), [Some(2), None, Some(0)]);
+/// ```
+///
+pub fn labels_reindex_into(a: &FxIndexSet<String>, b: &FxIndexSet<String>) -> Vec<Option<usize>> {
+    a.iter().map(|x| b.get_index_of(x)).collect()
+}
@@ -1,6 +1,9 @@
 mod axis_chunks_size;
 pub use axis_chunks_size::*;
 
+mod labels;
+pub use labels::*;
+
 mod nan_to_zero;
 pub use nan_to_zero::*;
 
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
     use causal_hub::prelude::*;
+    use itertools::Itertools;
     use ndarray::prelude::*;
     use polars::prelude::*;
     use rustc_hash::FxHashMap;
@@ -31,6 +32,22 @@ mod tests {
         assert_eq!(n.values(), array![1, 1, 1]);
     }
 
+    #[test]
+    fn par_marginal_count_matrix() {
+        let d = CsvReader::from_path("./tests/assets/asia.csv")
+            .expect("")
+            .finish()
+            .expect("Failed to read from CSV file");
+        let d = CategoricalDataMatrix::from(d);
+
+        for x in 0..d.labels().len() {
+            assert_eq!(
+                MarginalCountMatrix::par_new(&d, x).values(),
+                MarginalCountMatrix::new(&d, x).values()
+            );
+        }
+    }
+
     #[test]
     fn conditional_count_matrix() {
         // Set in-memory sample data file.
@@ -98,6 +115,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn single_conditional_count_matrix() {
+        let d = CsvReader::from_path("./tests/assets/asia.csv")
+            .expect("")
+            .finish()
+            .expect("Failed to read from CSV file");
+        let d = CategoricalDataMatrix::from(d);
+        // Add a constant variable to force the general path.
+        let c = CategoricalDataMatrix::from_ndarray(
+            Array2::zeros((d.sample_size(), 1)),
+            [("~", vec!["c"])],
+        );
+        let d = d.hconcat(&c);
+        let c = d.labels().len() - 1;
+
+        for (x, z) in (0..c).cartesian_product(0..c).filter(|(x, z)| x != z) {
+            // Assert single parent path is identical to the general path.
+            assert_eq!(
+                ConditionalCountMatrix::new(&d, x, &[z]).values(),
+                ConditionalCountMatrix::new(&d, x, &[z, c]).values()
+            );
+            assert_eq!(
+                ConditionalCountMatrix::par_new(&d, x, &[z]).values(),
+                ConditionalCountMatrix::new(&d, x, &[z, c]).values()
+            );
+        }
+    }
+
     #[test]
     fn joint_count_matrix() {
         // Set in-memory sample data file.
@@ -7,6 +7,8 @@ mod tests {
         use itertools::Itertools;
         use ndarray::prelude::*;
         use polars::prelude::*;
+        use rand::SeedableRng;
+        use rand_xoshiro::Xoshiro256PlusPlus;
 
         #[test]
         fn from() {
@@ -183,12 +185,230 @@ mod tests {
             assert!(data_set.sample_size() < sample.sample_size());
             assert_eq!(sample.sample_size(), 4);
         }
+
+        #[test]
+        fn from_ndarray() {
+            // Initialize encoded values w.r.t. the given states.
+            let data = array![[0, 1], [1, 0], [2, 0]];
+            // Cast ndarray to datamatrix.
+            let data_set = CategoricalDataMatrix::from_ndarray(
+                data,
+                [("Y", vec!["c", "a", "b"]), ("X", vec!["B", "A"])],
+            );
+
+            // Assert columns and states are sorted.
+            assert!(data_set.labels_iter().eq(["X", "Y"]));
+            assert!(data_set.states()["X"].iter().eq(["A", "B"]));
+            assert!(data_set.states()["Y"].iter().eq(["a", "b", "c"]));
+            // Assert values are aligned w.r.t. the sorted states.
+            assert_eq!(data_set.data(), array![[0, 2], [1, 0], [1, 1]]);
+            assert_eq!(data_set.cardinality(), &vec![2, 3]);
+        }
+
+        #[test]
+        fn rows() {
+            // Read data set.
+            let data_set: CategoricalDataMatrix = CsvReader::from_path("./tests/assets/asia.csv")
+                .unwrap()
+                .finish()
+                .unwrap()
+                .into();
+
+            // Assert there is a row for each sample.
+            assert_eq!(data_set.rows().len(), data_set.sample_size());
+            // Assert rows are views over the underlying values.
+            for (row, other) in data_set.rows().zip(data_set.data().rows()) {
+                assert_eq!(row, other);
+                assert_eq!(row.len(), data_set.labels_iter().len());
+            }
+        }
+
+        #[test]
+        #[should_panic]
+        fn from_ndarray_should_panic() {
+            // Cast ndarray to datamatrix with inconsistent number of labels.
+            CategoricalDataMatrix::from_ndarray(array![[0, 1], [1, 0]], [("X", vec!["A", "B"])]);
+        }
+
+        #[test]
+        #[should_panic(expected = "found float columns: [\"Y\"]")]
+        fn from_should_panic() {
+            // Set in-memory sample data file.
+            let file = "X,Y\nA,1.5\nB,2.5\n";
+            // Initialize an file cursor over the string.
+            let file = std::io::Cursor::new(&file);
+            // Parse the CSV file into a dataframe.
+            let df = CsvReader::new(file)
+                .finish()
+                .expect("Failed to read from CSV file");
+            // Cast dataframe to datamatrix.
+            CategoricalDataMatrix::from(df);
+        }
+
+        #[test]
+        fn infer_types() {
+            // Set in-memory sample data file.
+            let file = "Y,X,Z\nA,1.5,1\nB,2.5,2\n";
+            // Initialize an file cursor over the string.
+            let file = std::io::Cursor::new(&file);
+            // Parse the CSV file into a dataframe.
+            let df = CsvReader::new(file)
+                .finish()
+                .expect("Failed to read from CSV file");
+
+            // Infer the variables types.
+            let types = causal_hub::data::infer_types(&df);
+
+            assert!(types.keys().eq(["X", "Y", "Z"]));
+            assert!(types.values().eq(&[
+                VariableType::Continuous,
+                VariableType::Categorical,
+                VariableType::Categorical
+            ]));
+        }
+
+        #[test]
+        fn recode() {
+            // Set in-memory sample data file.
+            let file = "X,Y,Z,W\nA,A,A,I\nA,B,B,J\nA,A,C,K\n";
+            // Initialize an file cursor over the string.
+            let file = std::io::Cursor::new(&file);
+            // Parse the CSV file into a dataframe.
+            let df = CsvReader::new(file)
+                .finish()
+                .expect("Failed to read from CSV file");
+            // Cast dataframe to datamatrix.
+            let data_set = CategoricalDataMatrix::from(df);
+
+            // Merge states `B` and `C` of `Z` into `Other`.
+            let mapping = FxIndexMap::from_iter([
+                ("B".to_string(), "Other".to_string()),
+                ("C".to_string(), "Other".to_string()),
+            ]);
+            let recoded = data_set.recode("Z", &mapping);
+
+            // Assert other variables are unchanged.
+            assert!(data_set.labels_iter().eq(recoded.labels_iter()));
+            assert_eq!(
+                data_set.data().slice(s![.., ..3]),
+                recoded.data().slice(s![.., ..3])
+            );
+            // Assert recoded states, cardinalities and values.
+            assert!(recoded.states()["Z"].iter().eq(["A", "Other"]));
+            assert_eq!(recoded.cardinality(), &vec![3, 1, 2, 2]);
+            assert_eq!(recoded.data().column(3), array![0, 1, 1]);
+        }
+
+        #[test]
+        fn vconcat() {
+            // Cast ndarrays to datamatrices with partially overlapping states.
+            let a = CategoricalDataMatrix::from_ndarray(
+                array![[0, 1], [1, 0]],
+                [("X", vec!["A", "C"]), ("Y", vec!["no", "yes"])],
+            );
+            let b = CategoricalDataMatrix::from_ndarray(
+                array![[0, 0], [1, 0]],
+                [("X", vec!["B", "C"]), ("Y", vec!["yes"])],
+            );
+
+            // Concatenate by rows.
+            let c = a.vconcat(&b);
+
+            // Assert states are the union of the states.
+            assert!(c.states()["X"].iter().eq(["A", "B", "C"]));
+            assert!(c.states()["Y"].iter().eq(["no", "yes"]));
+            assert_eq!(c.cardinality(), &vec![3, 2]);
+            // Assert same states are mapped to same values.
+            assert_eq!(c.data(), array![[0, 1], [2, 0], [1, 1], [2, 1]]);
+        }
+
+        #[test]
+        #[should_panic]
+        fn vconcat_should_panic() {
+            // Cast ndarrays to datamatrices with different labels.
+            let a = CategoricalDataMatrix::from_ndarray(array![[0], [1]], [("X", vec!["A", "B"])]);
+            let b = CategoricalDataMatrix::from_ndarray(array![[0], [1]], [("Y", vec!["A", "B"])]);
+
+            // Concatenate by rows.
+            a.vconcat(&b);
+        }
+
+        #[test]
+        fn hconcat() {
+            // Cast ndarrays to datamatrices with disjoint labels.
+            let a = CategoricalDataMatrix::from_ndarray(
+                array![[0, 1], [1, 0]],
+                [("X", vec!["A", "B"]), ("Z", vec!["no", "yes"])],
+            );
+            let b =
+                CategoricalDataMatrix::from_ndarray(array![[2], [0]], [("Y", vec!["A", "B", "C"])]);
+
+            // Concatenate by columns.
+            let c = a.hconcat(&b);
+
+            // Assert columns are sorted.
+            assert!(c.labels_iter().eq(["X", "Y", "Z"]));
+            assert_eq!(c.cardinality(), &vec![2, 3, 2]);
+            assert_eq!(c.data(), array![[0, 2, 1], [1, 0, 0]]);
+        }
+
+        #[test]
+        #[should_panic]
+        fn hconcat_should_panic() {
+            // Cast ndarrays to datamatrices with overlapping labels.
+            let a = CategoricalDataMatrix::from_ndarray(array![[0], [1]], [("X", vec!["A", "B"])]);
+            let b = CategoricalDataMatrix::from_ndarray(array![[0], [1]], [("X", vec!["A", "B"])]);
+
+            // Concatenate by columns.
+            a.hconcat(&b);
+        }
+
+        #[test]
+        fn corrupt() {
+            // Set in-memory sample data file.
+            let file = "X,Y,Z,W\nA,A,A,I\nA,B,B,J\nA,A,C,K\n";
+            // Initialize an file cursor over the string.
+            let file = std::io::Cursor::new(&file);
+            // Parse the CSV file into a dataframe.
+            let df = CsvReader::new(file)
+                .finish()
+                .expect("Failed to read from CSV file");
+            // Cast dataframe to datamatrix.
+            let data_set = CategoricalDataMatrix::from(df);
+
+            // Define random number generator.
+            let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+            // Assert zero rate does not corrupt the data set.
+            let sample = data_set.corrupt(&mut rng, 0.);
+            assert_eq!(data_set.data(), sample.data());
+            // Assert unit rate corrupts every value with more than one state.
+            let sample = data_set.corrupt(&mut rng, 1.);
+            assert_eq!(data_set.states(), sample.states());
+            assert!(data_set
+                .data()
+                .columns()
+                .into_iter()
+                .zip(sample.data().columns())
+                .zip(data_set.cardinality())
+                .all(|((x, y), &k)| (k < 2 && x == y) || x.iter().zip(y).all(|(x, y)| x != y)));
+
+            // Assert corruption is reproducible under a seed.
+            let mut rng_a = Xoshiro256PlusPlus::seed_from_u64(31);
+            let mut rng_b = Xoshiro256PlusPlus::seed_from_u64(31);
+            assert_eq!(
+                data_set.corrupt(&mut rng_a, 0.5).data(),
+                data_set.corrupt(&mut rng_b, 0.5).data()
+            );
+        }
     }
 
     mod continuous {
+        use approx::*;
         use causal_hub::prelude::*;
         use ndarray::prelude::*;
         use polars::prelude::*;
+        use rand::SeedableRng;
+        use rand_xoshiro::Xoshiro256PlusPlus;
 
         #[test]
         fn from() {
@@ -297,5 +517,277 @@ mod tests {
             assert!(data_set.sample_size() < sample.sample_size());
             assert_eq!(sample.sample_size(), 4);
         }
+
+        #[test]
+        fn from_ndarray() {
+            // Cast ndarray to datamatrix.
+            let data_set =
+                GaussianDataMatrix::from_ndarray(array![[1.0, 2.0], [3.0, 4.0]], ["Y", "X"]);
+
+            // Assert columns are sorted.
+            assert!(data_set.labels_iter().eq(["X", "Y"]));
+            assert_eq!(data_set.data(), array![[2.0, 1.0], [4.0, 3.0]]);
+        }
+
+        #[test]
+        fn rows() {
+            // Cast ndarray to datamatrix.
+            let data_set =
+                GaussianDataMatrix::from_ndarray(array![[1.0, 2.0], [3.0, 4.0]], ["Y", "X"]);
+
+            // Assert rows are sorted w.r.t. the variables labels.
+            assert!(data_set
+                .rows()
+                .eq([array![2.0, 1.0].view(), array![4.0, 3.0].view()]));
+        }
+
+        #[test]
+        #[should_panic]
+        fn from_ndarray_should_panic() {
+            // Cast ndarray to datamatrix with duplicated labels.
+            GaussianDataMatrix::from_ndarray(array![[1.0, 2.0], [3.0, 4.0]], ["X", "X"]);
+        }
+
+        #[test]
+        fn precision_matrix() {
+            // Read data from file.
+            let true_o = std::fs::read_to_string("./tests/assets/precision_matrix.json").unwrap();
+            let true_o: Array2<f64> = serde_json::from_str(&true_o).unwrap();
+
+            // Load data set.
+            let data_set: GaussianDataMatrix = CsvReader::from_path("./tests/assets/ecoli70.csv")
+                .unwrap()
+                .finish()
+                .unwrap()
+                .into();
+
+            // Assert it is the inverse of the covariance matrix.
+            assert_relative_eq!(true_o, data_set.precision_matrix(), max_relative = 1e-8);
+        }
+
+        #[test]
+        #[should_panic(expected = "Covariance matrix must be positive definite")]
+        fn precision_matrix_should_panic() {
+            // Cast ndarray to datamatrix with a constant column.
+            let data_set = GaussianDataMatrix::from_ndarray(
+                array![[1.0, 0.0], [2.0, 0.0], [4.0, 0.0]],
+                ["X", "Y"],
+            );
+
+            // Compute the precision matrix of a singular covariance matrix.
+            data_set.precision_matrix();
+        }
+
+        #[test]
+        fn partial_correlation_matrix() {
+            // Load data set.
+            let data_set: GaussianDataMatrix = CsvReader::from_path("./tests/assets/ecoli70.csv")
+                .unwrap()
+                .finish()
+                .unwrap()
+                .into();
+
+            // Compute the partial correlation matrix.
+            let rho = data_set.partial_correlation_matrix();
+            // Compute the precision matrix.
+            let omega = data_set.precision_matrix();
+
+            // Assert it is derived from the precision matrix.
+            assert_eq!(rho.dim(), omega.dim());
+            for ((i, j), &r) in rho.indexed_iter() {
+                match i == j {
+                    true => assert_eq!(r, 1.),
+                    false => assert_relative_eq!(
+                        r,
+                        -omega[[i, j]] / f64::sqrt(omega[[i, i]] * omega[[j, j]]),
+                        max_relative = 1e-6
+                    ),
+                }
+            }
+        }
+
+        #[test]
+        fn discretize() {
+            // Cast ndarray to datamatrix.
+            let data_set = GaussianDataMatrix::from_ndarray(
+                array![[0.0, 0.0], [1.0, 0.0], [2.0, 0.0], [3.0, 10.0]],
+                ["X", "Y"],
+            );
+
+            // Discretize with equal-width bins.
+            let method = Discretization::EqualWidth(vec![2, 2]);
+            let d = data_set.discretize(&method);
+            // Assert labels, states and values.
+            assert!(d.labels_iter().eq(["X", "Y"]));
+            assert!(d.states()["X"]
+                .iter()
+                .eq(["0: (-inf, 1.5]", "1: (1.5, inf)"]));
+            assert!(d.states()["Y"].iter().eq(["0: (-inf, 5]", "1: (5, inf)"]));
+            assert_eq!(d.data(), array![[0, 0], [0, 0], [1, 0], [1, 1]]);
+
+            // Assert breakpoints can be reused to discretize consistently.
+            let breakpoints = method.breakpoints(&data_set);
+            assert_eq!(breakpoints, vec![vec![1.5], vec![5.]]);
+            let e = data_set.discretize(&Discretization::Breakpoints(breakpoints));
+            assert_eq!(d.states(), e.states());
+            assert_eq!(d.data(), e.data());
+
+            // Discretize with equal-frequency bins.
+            let d = data_set.discretize(&Discretization::EqualFrequency(vec![2, 2]));
+            // Assert states and values.
+            assert!(d.states()["X"].iter().eq(["0: (-inf, 1]", "1: (1, inf)"]));
+            assert!(d.states()["Y"].iter().eq(["0: (-inf, 0]", "1: (0, inf)"]));
+            assert_eq!(d.data(), array![[0, 0], [0, 0], [1, 0], [1, 1]]);
+        }
+
+        #[test]
+        fn discretize_multi_digit_breakpoints() {
+            // Cast ndarray to datamatrix.
+            let data_set = GaussianDataMatrix::from_ndarray(
+                array![[25.0], [-1.0], [15.0], [5.0], [1.0]],
+                ["X"],
+            );
+
+            // Discretize with multi-digit breakpoints, which would not sort as strings.
+            let breakpoints = vec![vec![2., 10., 20.]];
+            let d = data_set.discretize(&Discretization::Breakpoints(breakpoints));
+            // Assert states sort in bins order.
+            assert!(d.states()["X"].iter().eq([
+                "0: (-inf, 2]",
+                "1: (2, 10]",
+                "2: (10, 20]",
+                "3: (20, inf)"
+            ]));
+            // Assert values are ordinal w.r.t. the bins.
+            assert_eq!(d.data(), array![[3], [0], [2], [1], [0]]);
+
+            // Discretize with more than ten bins.
+            let breakpoints = vec![(1..=10).map(|b| b as f64).collect()];
+            let d = data_set.discretize(&Discretization::Breakpoints(breakpoints));
+            // Assert the bins indices are zero-padded.
+            assert_eq!(d.states()["X"][0], "00: (-inf, 1]");
+            assert_eq!(d.states()["X"][2], "02: (2, 3]");
+            assert_eq!(d.states()["X"][10], "10: (10, inf)");
+            assert_eq!(d.data(), array![[10], [0], [10], [4], [0]]);
+        }
+
+        #[test]
+        #[should_panic(expected = "Data matrix must not contain NaN values")]
+        fn discretize_nan_should_panic() {
+            // Cast ndarray to datamatrix.
+            let data_set =
+                GaussianDataMatrix::from_ndarray(array![[1.0, 2.0], [f64::NAN, 4.0]], ["X", "Y"]);
+
+            // Discretize data with NaN values.
+            data_set.discretize(&Discretization::EqualWidth(vec![2, 2]));
+        }
+
+        #[test]
+        #[should_panic]
+        fn discretize_should_panic() {
+            // Cast ndarray to datamatrix.
+            let data_set =
+                GaussianDataMatrix::from_ndarray(array![[1.0, 2.0], [3.0, 4.0]], ["X", "Y"]);
+
+            // Discretize with non-increasing breakpoints.
+            data_set.discretize(&Discretization::Breakpoints(vec![vec![2., 1.], vec![]]));
+        }
+
+        #[test]
+        #[should_panic(expected = "Number of bins must be at most 256")]
+        fn discretize_too_many_bins_should_panic() {
+            // Cast ndarray to datamatrix.
+            let data_set =
+                GaussianDataMatrix::from_ndarray(array![[1.0, 2.0], [3.0, 4.0]], ["X", "Y"]);
+
+            // Discretize with more bins than representable states.
+            data_set.discretize(&Discretization::EqualWidth(vec![257, 2]));
+        }
+
+        #[test]
+        fn add_noise() {
+            // Set in-memory sample data file.
+            let file = "X,Y,Z\n1.0,1.0,1.0\n1.0,2.0,2.0\n1.0,1.0,3.0\n";
+            // Initialize an file cursor over the string.
+            let file = std::io::Cursor::new(&file);
+            // Parse the CSV file into a dataframe.
+            let df = CsvReader::new(file)
+                .finish()
+                .expect("Failed to read from CSV file");
+            // Cast dataframe to datamatrix.
+            let data_set = GaussianDataMatrix::from(df);
+
+            // Define random number generator.
+            let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+            // Assert zero standard deviation does not perturb the data set.
+            let sample = data_set.add_noise(&mut rng, 0.);
+            assert_relative_eq!(data_set.data(), sample.data());
+            // Assert positive standard deviation perturbs the data set.
+            let sample = data_set.add_noise(&mut rng, 1.);
+            assert!(data_set.labels_iter().eq(sample.labels_iter()));
+            assert_ne!(data_set.data(), sample.data());
+
+            // Assert noise is reproducible under a seed.
+            let mut rng_a = Xoshiro256PlusPlus::seed_from_u64(31);
+            let mut rng_b = Xoshiro256PlusPlus::seed_from_u64(31);
+            assert_eq!(
+                data_set.add_noise(&mut rng_a, 0.5).data(),
+                data_set.add_noise(&mut rng_b, 0.5).data()
+            );
+        }
+
+        #[test]
+        fn nonparanormal() {
+            // Cast ndarray to datamatrix, with a monotone transformation of X in Y.
+            let x = array![3.0, -1.0, 0.5, 10.0, 2.0, 0.5];
+            let data = Array2::from_shape_fn((x.len(), 2), |(i, j)| match j {
+                0 => x[i],
+                _ => x[i].exp(),
+            });
+            let data_set = GaussianDataMatrix::from_ndarray(data, ["X", "Y"]);
+
+            // Apply the nonparanormal transformation.
+            let d = data_set.nonparanormal();
+            // Assert labels are preserved.
+            assert!(data_set.labels_iter().eq(d.labels_iter()));
+            // Assert the transformation is invariant to monotone transformations.
+            assert_relative_eq!(d.data().column(0), d.data().column(1));
+            // Assert the ranks are preserved, with ties mapped to the same value.
+            let y = d.data().column(0);
+            assert!(y[1] < y[2] && y[2] < y[4] && y[4] < y[0] && y[0] < y[3]);
+            assert_eq!(y[2], y[5]);
+            // Assert the tails are truncated.
+            assert!(y.iter().all(|y| y.is_finite()));
+        }
+
+        #[test]
+        #[should_panic(expected = "Sample size must be greater than one")]
+        fn nonparanormal_should_panic() {
+            // Cast ndarray to datamatrix with a single sample.
+            let data_set = GaussianDataMatrix::from_ndarray(array![[1.0, 2.0]], ["X", "Y"]);
+
+            // Apply the nonparanormal transformation.
+            data_set.nonparanormal();
+        }
+
+        #[test]
+        #[should_panic]
+        fn add_noise_should_panic() {
+            // Set in-memory sample data file.
+            let file = "X,Y,Z\n1.0,1.0,1.0\n1.0,2.0,2.0\n1.0,1.0,3.0\n";
+            // Initialize an file cursor over the string.
+            let file = std::io::Cursor::new(&file);
+            // Parse the CSV file into a dataframe.
+            let df = CsvReader::new(file)
+                .finish()
+                .expect("Failed to read from CSV file");
+            // Cast dataframe to datamatrix.
+            let data_set = GaussianDataMatrix::from(df);
+
+            // Define random number generator.
+            let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+            // Add noise with negative standard deviation.
+            data_set.add_noise(&mut rng, -1.);
+        }
     }
 }
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod pdgraph {
+    use causal_hub::prelude::*;
+
+    #[test]
+    fn consensus_cpdag() {
+        // Build the CPDAGs.
+        let cpdags = [
+            PDGraph::new_pagraph(["A", "B", "C", "D"], [("C", "D")], [("A", "B"), ("B", "C")]),
+            PDGraph::new_pagraph(["A", "B", "C", "D"], [("A", "B")], [("C", "B"), ("C", "D")]),
+            PDGraph::new_pagraph(["A", "B", "C", "D"], [], [("A", "B"), ("D", "C")]),
+            PDGraph::new_pagraph(["A", "B", "C", "D"], [("A", "D")], [("A", "B"), ("B", "C")]),
+        ];
+
+        // Keep edges in at least half of the CPDAGs.
+        let pred_g = causal_hub::discovery::consensus_cpdag(&cpdags, 0.5, 0.5);
+        // A -> B is oriented by majority, B -> C by majority among the edge runs,
+        // C - D is a tie, while A - D is not frequent enough.
+        let true_g =
+            PDGraph::new_pagraph(["A", "B", "C", "D"], [("C", "D")], [("A", "B"), ("B", "C")]);
+
+        assert_eq!(pred_g, true_g);
+
+        // Require all CPDAGs to agree on the orientation.
+        let pred_g = causal_hub::discovery::consensus_cpdag(&cpdags, 0.5, 1.);
+        let true_g = PDGraph::new_pagraph(
+            ["A", "B", "C", "D"],
+            [("A", "B"), ("B", "C"), ("C", "D")],
+            [],
+        );
+
+        assert_eq!(pred_g, true_g);
+
+        // Keep only edges in all CPDAGs.
+        let pred_g = causal_hub::discovery::consensus_cpdag(&cpdags, 1., 0.5);
+        let true_g = PDGraph::new_pagraph(["A", "B", "C", "D"], [], [("A", "B")]);
+
+        assert_eq!(pred_g, true_g);
+    }
+
+    #[test]
+    #[should_panic(expected = "CPDAGs must have the same vertices")]
+    fn consensus_cpdag_should_panic() {
+        // Build the CPDAGs.
+        let cpdags = [
+            PDGraph::new_pagraph(["A", "B"], [("A", "B")], []),
+            PDGraph::new_pagraph(["A", "C"], [("A", "C")], []),
+        ];
+
+        // Compute the consensus.
+        causal_hub::discovery::consensus_cpdag(&cpdags, 0.5, 0.5);
+    }
+}
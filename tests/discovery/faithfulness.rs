@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod categorical {
+    use causal_hub::prelude::*;
+    use rand::SeedableRng;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+
+    #[test]
+    fn faithfulness_report() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+        // Sample using forward sampling.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        let d = b.sample(&mut rng, 1e4 as usize);
+
+        // Assert the d-separation oracle is faithful.
+        let oracle = DSeparationOracle::new(b.graph());
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        let report = causal_hub::discovery::faithfulness_report(b.graph(), &oracle, 100, &mut rng);
+        assert_eq!(report.len(), 100);
+        assert_eq!(report.agreements(), 100);
+        assert_eq!(report.agreement_rate(), Some(1.));
+        assert!(report.violations().is_empty());
+
+        // Check faithfulness of the data set.
+        let test = ChiSquared::new(&d);
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        let report = causal_hub::discovery::faithfulness_report(b.graph(), &test, 100, &mut rng);
+        assert_eq!(report.len(), 100);
+        assert!(report.agreement_rate().unwrap() > 0.5);
+        // Assert each violation is a disagreement between the test and d-separation.
+        for (x, y, z) in report.violations() {
+            assert!(!z.contains(x) && !z.contains(y) && x != y);
+            assert_ne!(test.call(*x, *y, z), oracle.call(*x, *y, z));
+        }
+
+        // Assert the report is reproducible under a seed.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        let other = causal_hub::discovery::faithfulness_report(b.graph(), &test, 100, &mut rng);
+        assert_eq!(report, other);
+    }
+
+    #[test]
+    #[should_panic(expected = "DAG and test must have the same variables")]
+    fn faithfulness_report_should_panic() {
+        // Build a new directed graph.
+        let g = DiGraph::new(["A", "B", "C"], [("A", "C"), ("B", "C")]);
+        let h = DiGraph::new(["A", "B"], [("A", "B")]);
+
+        // Check faithfulness w.r.t. a test over different variables.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        causal_hub::discovery::faithfulness_report(&g, &DSeparationOracle::new(&h), 10, &mut rng);
+    }
+}
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod categorical {
     use causal_hub::prelude::*;
+    use ndarray::prelude::*;
     use polars::prelude::*;
 
     #[test]
@@ -119,6 +120,170 @@ mod categorical {
 
         assert_eq!(pred_g, true_g);
     }
+
+    #[test]
+    fn with_stable_tiebreak() {
+        // Load data set.
+        let d = CsvReader::from_path("./tests/assets/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+
+        // Initialize empty prior knowledge.
+        let k = FR::new(d.labels_iter(), [], []);
+
+        // Initialize score functor.
+        let s = BIC::new(&d);
+
+        // Initialize discovery functor.
+        let hc = HC::new(&s).with_stable_tiebreak(true);
+        // Perform discovery.
+        let pred_g: DiGraph = hc.call(&d, &k);
+
+        // Assert discovery is acyclic and reproducible.
+        assert!(pred_g.is_acyclic());
+        assert_eq!(pred_g, hc.call(&d, &k));
+    }
+
+    #[test]
+    fn with_stable_tiebreak_on_ties() {
+        // Define a score where every edge scores the same, except for (A, B).
+        #[derive(Clone, Debug)]
+        struct TiedScore;
+
+        impl DecomposableScoringCriterion<CategoricalDataMatrix, DiGraph> for TiedScore {
+            fn call(&self, x: usize, z: &[usize]) -> f64 {
+                z.iter()
+                    .map(|&y| if (y, x) == (0, 1) { 2. } else { 1. })
+                    .sum()
+            }
+        }
+
+        // Set data set, which is ignored by the score.
+        let d = CategoricalDataMatrix::from_ndarray(
+            array![[0, 0, 0], [1, 1, 1]],
+            [
+                ("A", ["no", "yes"]),
+                ("B", ["no", "yes"]),
+                ("C", ["no", "yes"]),
+            ],
+        );
+
+        // Initialize empty prior knowledge.
+        let k = FR::new(d.labels_iter(), [], []);
+
+        // Initialize score functor.
+        let s = TiedScore;
+
+        // Perform two steps of discovery: the first adds (A, B) by its higher delta,
+        // the second breaks the tie between (A, C), (B, C), (C, A) and (C, B).
+        let stable_g: DiGraph = HC::new(&s)
+            .with_max_iter(2)
+            .with_stable_tiebreak(true)
+            .call(&d, &k);
+        let default_g: DiGraph = HC::new(&s).with_max_iter(2).call(&d, &k);
+
+        // Assert the stable tie-break follows the insertion order of the edge space ...
+        assert_eq!(
+            stable_g,
+            DiGraph::new(["A", "B", "C"], [("A", "B"), ("C", "B")])
+        );
+        // ... while the default tie-break follows the order induced by swap removals.
+        assert_eq!(
+            default_g,
+            DiGraph::new(["A", "B", "C"], [("A", "B"), ("C", "A")])
+        );
+        assert_ne!(stable_g, default_g);
+    }
+
+    #[test]
+    fn with_tiers() {
+        // Load data set.
+        let d = CsvReader::from_path("./tests/assets/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+
+        // Set the temporal tiers.
+        let k = Tiers::new([
+            ("asia", 0),
+            ("smoke", 0),
+            ("bronc", 1),
+            ("lung", 1),
+            ("tub", 1),
+            ("either", 2),
+            ("dysp", 3),
+            ("xray", 3),
+        ]);
+
+        // Initialize score functor.
+        let s = BIC::new(&d);
+
+        // Initialize discovery functor.
+        let hc = HC::new(&s);
+        // Perform discovery.
+        let pred_g: DiGraph = hc.call(&d, &k);
+
+        // Assert discovery is acyclic and no edge goes backward in time.
+        assert!(pred_g.is_acyclic());
+        assert!(E!(pred_g).all(|(x, y)| k.tier(x) <= k.tier(y)));
+    }
+
+    #[test]
+    fn with_initial_graph() {
+        // Load data set.
+        let d = CsvReader::from_path("./tests/assets/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+
+        // Initialize empty prior knowledge.
+        let k = FR::new(d.labels_iter(), [], []);
+
+        // Initialize score functor.
+        let s = BIC::new(&d);
+
+        // Perform discovery from the empty graph.
+        let pred_g: DiGraph = HC::new(&s).call(&d, &k);
+        // Perform discovery warm started from the previous result.
+        let warm_g: DiGraph = HC::new(&s).with_initial_graph(pred_g.clone()).call(&d, &k);
+
+        // Assert the previous result is a local optimum.
+        assert_eq!(warm_g, pred_g);
+
+        // Perform discovery warm started from a partial graph.
+        let init_g = DiGraph::new(d.labels_iter(), [("bronc", "dysp"), ("either", "dysp")]);
+        let warm_g: DiGraph = HC::new(&s).with_initial_graph(init_g.clone()).call(&d, &k);
+
+        // Assert the score does not decrease w.r.t. the initial graph.
+        assert!(ScoringCriterion::call(&s, &warm_g) >= ScoringCriterion::call(&s, &init_g));
+    }
+
+    #[test]
+    #[should_panic(expected = "Initial graph must be acyclic")]
+    fn with_cyclic_initial_graph() {
+        // Load data set.
+        let d = CsvReader::from_path("./tests/assets/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+
+        // Initialize empty prior knowledge.
+        let k = FR::new(d.labels_iter(), [], []);
+
+        // Initialize score functor.
+        let s = BIC::new(&d);
+
+        // Set a cyclic initial graph.
+        let init_g = DiGraph::new(d.labels_iter(), [("asia", "tub"), ("tub", "asia")]);
+
+        // Perform discovery.
+        let _: DiGraph = HC::new(&s).with_initial_graph(init_g).call(&d, &k);
+    }
 }
 
 #[cfg(test)]
@@ -1,2 +1,7 @@
+mod consensus;
+mod faithfulness;
 mod hill_climbing;
 mod pc_stable;
+mod prior_knowledge;
+mod scoring_criterion;
+mod skeleton;
@@ -61,6 +61,7 @@ mod categorical {
         // Perform tests
         assert_eq!(skel, par_skel);
         assert_eq!(g, par_g);
+        assert_eq!(pcs.call().par_meek_procedure_until_3(), g);
 
         assert_eq!(skel, true_skel);
         assert_eq!(g, true_g);
@@ -125,6 +126,7 @@ mod categorical {
         // Perform tests
         assert_eq!(skel, par_skel);
         assert_eq!(g, par_g);
+        assert_eq!(pcs.call().par_meek_procedure_until_3(), g);
 
         assert_eq!(skel, true_skel);
         assert_eq!(g, true_g);
@@ -183,11 +185,213 @@ mod categorical {
         // Perform tests
         assert_eq!(skel, par_skel);
         assert_eq!(g, par_g);
+        assert_eq!(pcs.call().par_meek_procedure_until_3(), g);
 
         assert_eq!(skel, true_skel);
         assert_eq!(g, true_g);
     }
 
+    #[test]
+    fn survey_cached() {
+        // Load data set.
+        let d = CsvReader::from_path(format!("{}survey.csv", BASE_PATH))
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+
+        // Create ChiSquared conditional independence test
+        let test = ChiSquared::new(&d).with_significance_level(ALPHA);
+        // Create cached ChiSquared conditional independence test
+        let cached_test = CachedCIT::new(test.clone());
+
+        // Perform discovery
+        let true_g = PCStable::new(&test).call().meek_procedure_until_3();
+        let g = PCStable::new(&cached_test).call().meek_procedure_until_3();
+
+        // Perform tests
+        assert_eq!(g, true_g);
+        assert!(cached_test.misses() > 0);
+
+        // Perform discovery again, now in parallel
+        let misses = cached_test.misses();
+        let par_g = PCStable::new(&cached_test)
+            .par_call()
+            .meek_procedure_until_3();
+
+        // Perform tests
+        assert_eq!(par_g, true_g);
+        // Assert repeated queries are served by the cache.
+        assert_eq!(cached_test.misses(), misses);
+        assert!(cached_test.hits() >= misses);
+        // Assert the hit rate is at least one half.
+        assert!(cached_test.hit_rate().unwrap() >= 0.5);
+
+        // Clear the cache.
+        cached_test.clear();
+        assert_eq!(cached_test.hit_rate(), None);
+    }
+
+    #[test]
+    fn with_precompute_marginals() {
+        // Load data set.
+        let d = CsvReader::from_path(format!("{}survey.csv", BASE_PATH))
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+
+        // Create ChiSquared conditional independence test
+        let test = ChiSquared::new(&d).with_significance_level(ALPHA);
+
+        // Create PC-Stable functors
+        let pcs = PCStable::new(&test);
+        let pre_pcs = PCStable::new(&test).with_precompute_marginals(true);
+
+        // Perform tests
+        assert_eq!(pre_pcs.call_skeleton(), pcs.call_skeleton());
+        assert_eq!(pre_pcs.par_call_skeleton(), pcs.call_skeleton());
+        assert_eq!(
+            pre_pcs.call().meek_procedure_until_3(),
+            pcs.call().meek_procedure_until_3()
+        );
+        assert_eq!(
+            pre_pcs.par_call().meek_procedure_until_3(),
+            pcs.call().meek_procedure_until_3()
+        );
+    }
+
+    #[test]
+    fn with_tiers() {
+        // Load data set.
+        let d = CsvReader::from_path(format!("{}asia.csv", BASE_PATH))
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+
+        // Set the temporal tiers.
+        let k = Tiers::new([
+            ("asia", 0),
+            ("smoke", 0),
+            ("bronc", 1),
+            ("lung", 1),
+            ("tub", 1),
+            ("either", 2),
+            ("dysp", 3),
+            ("xray", 3),
+        ]);
+
+        // Create ChiSquared conditional independence test
+        let test = ChiSquared::new(&d).with_significance_level(ALPHA);
+
+        // Create PC-Stable functors
+        let pcs = PCStable::new(&test);
+        let tiers_pcs = PCStable::new(&test).with_tiers(&k);
+
+        // Perform discovery
+        let g = tiers_pcs.call();
+        let par_g = tiers_pcs.par_call();
+
+        // Perform tests
+        assert_eq!(g, par_g);
+        assert_eq!(g.clone().to_undirected(), pcs.call_skeleton());
+        // Assert cross-tier edges are oriented forward in time.
+        for (x, y) in E!(g).filter(|&(x, y)| k.tier(x) != k.tier(y)) {
+            assert!(g.has_directed_edge_by_index(x, y) && !k.has_forbidden(x, y));
+        }
+    }
+
+    #[test]
+    fn with_single_tier() {
+        // Load data set.
+        let d = CsvReader::from_path(format!("{}asia.csv", BASE_PATH))
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+
+        // Set a single temporal tier.
+        let k = Tiers::new(d.labels_iter().map(|x| (x, 0)));
+
+        // Create ChiSquared conditional independence test
+        let test = ChiSquared::new(&d).with_significance_level(ALPHA);
+
+        // Create PC-Stable functors
+        let pcs = PCStable::new(&test);
+        let tiers_pcs = PCStable::new(&test).with_tiers(&k);
+
+        // Perform tests
+        assert_eq!(tiers_pcs.call(), pcs.call());
+        assert_eq!(tiers_pcs.par_call(), pcs.par_call());
+    }
+
+    #[test]
+    #[should_panic(expected = "Tiers and test must have the same variables")]
+    fn with_tiers_should_panic() {
+        // Load data set.
+        let d = CsvReader::from_path(format!("{}asia.csv", BASE_PATH))
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+
+        // Set the temporal tiers over a subset of the variables.
+        let k = Tiers::new([("asia", 0), ("smoke", 1)]);
+
+        // Create ChiSquared conditional independence test
+        let test = ChiSquared::new(&d).with_significance_level(ALPHA);
+
+        // Create PC-Stable functor
+        PCStable::new(&test).with_tiers(&k);
+    }
+
+    #[test]
+    fn call_alpha_path() {
+        // Load data set.
+        let d = CsvReader::from_path(format!("{}asia.csv", BASE_PATH))
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+
+        // Create ChiSquared conditional independence test
+        let test = ChiSquared::new(&d).with_significance_level(ALPHA);
+
+        // Perform skeleton discovery along the significance level path
+        let alphas = [0.001, 0.01, 0.05, 0.1];
+        let path = PCStable::new(&test).call_alpha_path(&alphas);
+
+        // Perform tests
+        assert_eq!(path.len(), alphas.len());
+        for ((alpha, skel), &true_alpha) in path.into_iter().zip(&alphas) {
+            // Perform skeleton discovery with given significance level
+            let test = test.clone().with_significance_level(true_alpha);
+            let true_skel = PCStable::new(&test).call_skeleton_with_sepsets();
+
+            assert_eq!(alpha, true_alpha);
+            assert_eq!(skel.graph(), true_skel.graph());
+            assert_eq!(skel.sepsets(), true_skel.sepsets());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Significance levels must be in the (0, 1) interval")]
+    fn call_alpha_path_zero_should_panic() {
+        // Load data set.
+        let d = CsvReader::from_path(format!("{}asia.csv", BASE_PATH))
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+
+        // Create ChiSquared conditional independence test
+        let test = ChiSquared::new(&d).with_significance_level(ALPHA);
+
+        // Perform skeleton discovery along a path with a zero significance level
+        PCStable::new(&test).call_alpha_path(&[0., 0.05]);
+    }
+
     #[test]
     fn meek_1_base_case() {
         let mut g = PDGraph::new_pagraph(vec![], vec![("1", "2")], vec![("0", "1")]);
@@ -336,4 +540,38 @@ mod categorical {
         assert!(g.has_directed_edge_by_index(7, 0));
         assert!(g.has_directed_edge_by_index(3, 0));
     }
+
+    #[test]
+    fn par_meek_procedure() {
+        let data = [
+            (
+                vec![("0", "1"), ("0", "2"), ("0", "3")],
+                vec![("1", "2"), ("3", "2")],
+            ),
+            (
+                vec![
+                    ("0", "5"),
+                    ("0", "2"),
+                    ("2", "5"),
+                    ("0", "7"),
+                    ("0", "3"),
+                    ("6", "7"),
+                    ("3", "4"),
+                ],
+                vec![("1", "0"), ("2", "1"), ("4", "1"), ("3", "7"), ("6", "3")],
+            ),
+        ];
+        for (ue, de) in data {
+            let g = PDGraph::new_pagraph(vec![], ue, de);
+            // Test parallel and serial procedures reach the same orientation
+            assert_eq!(
+                g.clone().par_meek_procedure_until_3(),
+                g.clone().meek_procedure_until_3()
+            );
+            assert_eq!(
+                g.clone().par_meek_procedure_until_4(),
+                g.meek_procedure_until_4()
+            );
+        }
+    }
 }
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tiers {
+    use causal_hub::prelude::*;
+
+    #[test]
+    fn with_prior_knowledge() {
+        // Set the user-supplied prior knowledge.
+        let fr = FR::new(["A", "B", "C"], [("B", "C")], [("A", "C")]);
+        // Merge it into the tiers.
+        let k = Tiers::new([("A", 0), ("B", 1), ("C", 1)]).with_prior_knowledge(&fr);
+
+        // Perform tests
+        assert_eq!(k.labels(), fr.labels());
+        // Assert backward edges are still forbidden.
+        assert!(k.has_forbidden(1, 0));
+        assert!(k.has_forbidden(2, 0));
+        // Assert the prior knowledge is merged.
+        assert!(k.has_forbidden(1, 2));
+        assert!(!k.has_forbidden(2, 1));
+        assert!(k.has_required(0, 2));
+        assert_eq!(k.required().len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Tiers and prior knowledge must have the same variables")]
+    fn with_prior_knowledge_labels_should_panic() {
+        // Set the user-supplied prior knowledge.
+        let fr = FR::new(["A", "B"], [], []);
+
+        // Merge it into tiers over different variables.
+        Tiers::new([("A", 0), ("B", 1), ("C", 1)]).with_prior_knowledge(&fr);
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to add edge as required since it is in the forbidden set")]
+    fn with_prior_knowledge_backward_should_panic() {
+        // Set a required edge backward in time.
+        let fr = FR::new(["A", "B"], [], [("B", "A")]);
+
+        // Merge it into the tiers.
+        Tiers::new([("A", 0), ("B", 1)]).with_prior_knowledge(&fr);
+    }
+
+    #[test]
+    fn del_forbidden() {
+        // Set the tiers.
+        let mut k = Tiers::new([("A", 0), ("B", 1), ("C", 1)]);
+
+        // Add and delete a forbidden edge within the same tier.
+        assert!(k.add_forbidden(1, 2));
+        assert!(k.del_forbidden(1, 2));
+        assert!(!k.has_forbidden(1, 2));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Failed to delete edge from the forbidden set since it goes backward in time"
+    )]
+    fn del_forbidden_should_panic() {
+        // Set the tiers.
+        let mut k = Tiers::new([("A", 0), ("B", 1)]);
+
+        // Delete an edge forbidden by the tiers.
+        k.del_forbidden(1, 0);
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod categorical {
+    use approx::*;
+    use causal_hub::prelude::*;
+    use polars::prelude::*;
+
+    #[test]
+    fn delta() {
+        // Load data set.
+        let d = CsvReader::from_path("./tests/assets/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+        // Initialize scoring criterion.
+        let s = BIC::new(&d);
+        // Set the local score function.
+        let f = |x, z: &[usize]| DecomposableScoringCriterion::<_, DiGraph>::call(&s, x, z);
+
+        // Assert the delta of adding Y to Pa(X).
+        assert_relative_eq!(
+            DecomposableScoringCriterion::<_, DiGraph>::delta_add(&s, 1, &[0, 7], 5),
+            f(1, &[0, 5, 7]) - f(1, &[0, 7])
+        );
+        // Assert the delta of removing Y from Pa(X).
+        assert_relative_eq!(
+            DecomposableScoringCriterion::<_, DiGraph>::delta_del(&s, 1, &[0, 5, 7], 5),
+            f(1, &[0, 7]) - f(1, &[0, 5, 7])
+        );
+        // Assert the delta of reversing X -> Y.
+        assert_relative_eq!(
+            DecomposableScoringCriterion::<_, DiGraph>::delta_rev(&s, 5, &[], 1, &[0, 5]),
+            (f(5, &[1]) - f(5, &[])) + (f(1, &[0]) - f(1, &[0, 5]))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Parents must not contain the added vertex")]
+    fn delta_add_existing_parent() {
+        // Load data set.
+        let d = CsvReader::from_path("./tests/assets/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+        // Initialize scoring criterion.
+        let s = BIC::new(&d);
+
+        // Add an existing parent.
+        DecomposableScoringCriterion::<_, DiGraph>::delta_add(&s, 1, &[5], 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Parents must contain the removed vertex")]
+    fn delta_del_missing_parent() {
+        // Load data set.
+        let d = CsvReader::from_path("./tests/assets/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+        // Initialize scoring criterion.
+        let s = BIC::new(&d);
+
+        // Remove a missing parent.
+        DecomposableScoringCriterion::<_, DiGraph>::delta_del(&s, 1, &[], 5);
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod categorical {
+    use causal_hub::prelude::*;
+    use polars::prelude::*;
+
+    // Set ChiSquared significance level
+    const ALPHA: f64 = 0.05;
+
+    #[test]
+    fn asia() {
+        // Load data set.
+        let d = CsvReader::from_path("./tests/assets/pc_stable/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+
+        // Create ChiSquared conditional independence test
+        let test = ChiSquared::new(&d).with_significance_level(ALPHA);
+
+        // Create PC-Stable functor
+        let pcs = PCStable::new(&test);
+
+        // Perform skeleton discovery
+        let skel = pcs.call_skeleton_with_sepsets();
+        let par_skel = pcs.par_call_skeleton_with_sepsets();
+
+        // Perform tests
+        assert_eq!(skel.graph(), &pcs.call_skeleton());
+        assert_eq!(par_skel.graph(), skel.graph());
+        assert_eq!(par_skel.statistics(), skel.statistics());
+        // Assert each separation set has the statistics of a non-rejected test.
+        let statistics = skel.statistics().unwrap();
+        assert_eq!(statistics.len(), skel.sepsets().len());
+        for (&(x, y), &(_, _, p_value)) in statistics {
+            assert!(skel.sepset(x, y).is_some());
+            assert_eq!(statistics.get(&(y, x)), Some(&statistics[&(x, y)]));
+            assert!(p_value > ALPHA);
+        }
+        // Assert separation sets are symmetric and refer to non-adjacent vertices.
+        for (&(x, y), z) in skel.sepsets() {
+            assert!(!skel.is_adjacent_by_index(x, y));
+            assert_eq!(skel.sepset(y, x), Some(z));
+        }
+        // Assert each non-adjacent couple has a separation set.
+        for x in V!(skel.graph()) {
+            for y in V!(skel.graph()).filter(|&y| y != x) {
+                assert_eq!(skel.is_adjacent_by_index(x, y), skel.sepset(x, y).is_none());
+            }
+        }
+
+        // Perform orientation
+        assert_eq!(skel.orient(), pcs.call());
+        assert_eq!(par_skel.par_orient(), pcs.call());
+        assert_eq!(
+            skel.orient().meek_procedure_until_3(),
+            pcs.call().meek_procedure_until_3()
+        );
+
+        // Decompose the skeleton.
+        let (g, sepsets, statistics) = skel.clone().into_parts();
+        assert_eq!(&g, skel.graph());
+        assert_eq!(&sepsets, skel.sepsets());
+        assert_eq!(statistics.as_ref(), skel.statistics());
+    }
+
+    #[test]
+    fn with_statistics() {
+        // Set the skeleton X - Y, Z.
+        let g = Graph::new(["X", "Y", "Z"], [("X", "Y")]);
+        // Set the separation sets.
+        let sepsets: SepSets = [((0, 2), Default::default()), ((2, 0), Default::default())]
+            .into_iter()
+            .collect();
+        // Set the statistics.
+        let statistics: CIStatistics = [((0, 2), (1, 0.5, 0.48))].into_iter().collect();
+
+        // Construct the skeleton.
+        let skel = Skeleton::new(g, sepsets).with_statistics(statistics.clone());
+
+        // Perform tests
+        assert_eq!(skel.statistics(), Some(&statistics));
+        assert_eq!(skel.sepset(0, 1), None);
+        assert_eq!(skel.sepset(0, 2), Some(&Default::default()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Separation sets must refer to non-adjacent vertices")]
+    fn new_should_panic() {
+        // Set the skeleton X - Y, Z.
+        let g = Graph::new(["X", "Y", "Z"], [("X", "Y")]);
+        // Set an invalid separation set.
+        let sepsets: SepSets = [((0, 1), Default::default())].into_iter().collect();
+
+        // Construct the skeleton.
+        Skeleton::new(g, sepsets);
+    }
+}
@@ -683,6 +683,29 @@ mod undirected {
                 }
             }
 
+            #[test]
+            fn density() {
+                // Test for ...
+                let data = [
+                    // ... zero vertices,
+                    (vec![], 0.),
+                    // ... one vertex,
+                    (vec![("0", "0")], 0.),
+                    // ... multiple edges,
+                    (vec![("0", "1"), ("1", "2"), ("2", "3")], 0.5),
+                    // ... complete graph,
+                    (vec![("0", "1"), ("0", "2"), ("1", "2")], 1.),
+                    // ... self-loops,
+                    (vec![("0", "0"), ("0", "1"), ("1", "1")], 3.),
+                ];
+
+                // Test for each scenario.
+                for (i, j) in data {
+                    let g = $G::new([], i);
+                    assert_eq!(g.density(), j);
+                }
+            }
+
             #[test]
             fn has_edge_by_index() {
                 // Test for ...
@@ -726,6 +749,39 @@ mod undirected {
                 g.has_edge_by_index(0, 0);
             }
 
+            #[test]
+            #[should_panic(expected = "No vertex with identifier `2` in a graph of order `2`")]
+            fn has_edge_by_index_foreign_index_should_panic() {
+                // Get the index of a vertex of a larger graph.
+                let h = $G::new(["A", "B", "C"], [("A", "C")]);
+                let x = h.get_vertex_index("C");
+                // Use it with a smaller graph.
+                let g = $G::new(["A", "B"], [("A", "B")]);
+                g.has_edge_by_index(0, x);
+            }
+
+            #[test]
+            #[should_panic(expected = "No vertex with identifier `2` in a graph of order `2`")]
+            fn add_edge_by_index_foreign_index_should_panic() {
+                // Get the index of a vertex of a larger graph.
+                let h = $G::new(["A", "B", "C"], [("A", "C")]);
+                let x = h.get_vertex_index("C");
+                // Use it with a smaller graph.
+                let mut g = $G::new(["A", "B"], [("A", "B")]);
+                g.add_edge_by_index(x, 0);
+            }
+
+            #[test]
+            #[should_panic(expected = "No vertex with identifier `2` in a graph of order `2`")]
+            fn del_edge_by_index_foreign_index_should_panic() {
+                // Get the index of a vertex of a larger graph.
+                let h = $G::new(["A", "B", "C"], [("A", "C")]);
+                let x = h.get_vertex_index("C");
+                // Use it with a smaller graph.
+                let mut g = $G::new(["A", "B"], [("A", "B")]);
+                g.del_edge_by_index(0, x);
+            }
+
             #[test]
             fn add_edge_by_index() {
                 // Test for ...
@@ -1644,6 +1700,29 @@ mod directed {
                 }
             }
 
+            #[test]
+            fn density() {
+                // Test for ...
+                let data = [
+                    // ... zero vertices,
+                    (vec![], 0.),
+                    // ... one vertex,
+                    (vec![("0", "0")], 0.),
+                    // ... multiple edges,
+                    (vec![("0", "1"), ("1", "2"), ("2", "3")], 0.25),
+                    // ... complete graph,
+                    (vec![("0", "1"), ("0", "2"), ("1", "2")], 0.5),
+                    // ... self-loops,
+                    (vec![("0", "0"), ("0", "1"), ("1", "1")], 1.5),
+                ];
+
+                // Test for each scenario.
+                for (i, j) in data {
+                    let g = $G::new([], i);
+                    assert_eq!(g.density(), j);
+                }
+            }
+
             #[test]
             fn has_edge_by_index() {
                 // Test for ...
@@ -1687,6 +1766,39 @@ mod directed {
                 g.has_edge_by_index(0, 0);
             }
 
+            #[test]
+            #[should_panic(expected = "No vertex with identifier `2` in a graph of order `2`")]
+            fn has_edge_by_index_foreign_index_should_panic() {
+                // Get the index of a vertex of a larger graph.
+                let h = $G::new(["A", "B", "C"], [("A", "C")]);
+                let x = h.get_vertex_index("C");
+                // Use it with a smaller graph.
+                let g = $G::new(["A", "B"], [("A", "B")]);
+                g.has_edge_by_index(0, x);
+            }
+
+            #[test]
+            #[should_panic(expected = "No vertex with identifier `2` in a graph of order `2`")]
+            fn add_edge_by_index_foreign_index_should_panic() {
+                // Get the index of a vertex of a larger graph.
+                let h = $G::new(["A", "B", "C"], [("A", "C")]);
+                let x = h.get_vertex_index("C");
+                // Use it with a smaller graph.
+                let mut g = $G::new(["A", "B"], [("A", "B")]);
+                g.add_edge_by_index(x, 0);
+            }
+
+            #[test]
+            #[should_panic(expected = "No vertex with identifier `2` in a graph of order `2`")]
+            fn del_edge_by_index_foreign_index_should_panic() {
+                // Get the index of a vertex of a larger graph.
+                let h = $G::new(["A", "B", "C"], [("A", "C")]);
+                let x = h.get_vertex_index("C");
+                // Use it with a smaller graph.
+                let mut g = $G::new(["A", "B"], [("A", "B")]);
+                g.del_edge_by_index(0, x);
+            }
+
             #[test]
             fn add_edge_by_index() {
                 // Test for ...
@@ -1916,6 +2028,109 @@ mod directed {
     mod directed_dense_matrix {
         use causal_hub::graphs::structs::DirectedDenseAdjacencyMatrixGraph;
         generic_tests!(DirectedDenseAdjacencyMatrixGraph);
+
+        use rand::SeedableRng;
+        use rand_xoshiro::Xoshiro256PlusPlus;
+
+        #[test]
+        fn random_dag() {
+            // Set vertices labels.
+            let v = ["A", "B", "C", "D", "E", "F", "G", "H"];
+
+            // Initialize random number generators.
+            let mut rng_a = Xoshiro256PlusPlus::seed_from_u64(42);
+            let mut rng_b = Xoshiro256PlusPlus::seed_from_u64(42);
+            // Generate random graphs.
+            let g = DirectedDenseAdjacencyMatrixGraph::random_dag(v, 0.5, None, &mut rng_a);
+            let h = DirectedDenseAdjacencyMatrixGraph::random_dag(v, 0.5, None, &mut rng_b);
+
+            // Assert random graph is reproducible under a seed.
+            assert_eq!(g, h);
+            // Assert random graph is acyclic over the given vertices.
+            assert!(g.is_acyclic());
+            assert!(L!(g).eq(v));
+
+            // Assert the complete random graph has maximum size.
+            let g = DirectedDenseAdjacencyMatrixGraph::random_dag(v, 1., None, &mut rng_a);
+            assert_eq!(g.size(), v.len() * (v.len() - 1) / 2);
+            // Assert the maximum in-degree is satisfied.
+            let g = DirectedDenseAdjacencyMatrixGraph::random_dag(v, 1., Some(2), &mut rng_a);
+            assert!(V!(g).all(|x| Pa!(g, x).count() <= 2));
+            assert!(g.is_acyclic());
+        }
+
+        #[test]
+        fn random_scale_free() {
+            // Set vertices labels.
+            let v = ["A", "B", "C", "D", "E", "F", "G", "H"];
+
+            // Initialize random number generators.
+            let mut rng_a = Xoshiro256PlusPlus::seed_from_u64(42);
+            let mut rng_b = Xoshiro256PlusPlus::seed_from_u64(42);
+            // Generate random graphs.
+            let g = DirectedDenseAdjacencyMatrixGraph::random_scale_free(v, 2, &mut rng_a);
+            let h = DirectedDenseAdjacencyMatrixGraph::random_scale_free(v, 2, &mut rng_b);
+
+            // Assert random graph is reproducible under a seed.
+            assert_eq!(g, h);
+            // Assert random graph is acyclic over the given vertices.
+            assert!(g.is_acyclic());
+            assert!(L!(g).eq(v));
+            // Assert each vertex attaches to at most `m` preceding vertices.
+            assert!(V!(g).all(|x| Pa!(g, x).count() <= 2));
+            assert_eq!(g.size(), 1 + 2 * (v.len() - 2));
+        }
+
+        #[test]
+        fn reversed() {
+            // Set vertices labels.
+            let v = ["A", "B", "C", "D", "E", "F", "G", "H"];
+
+            // Initialize random number generator.
+            let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+            // Generate random graph.
+            let g = DirectedDenseAdjacencyMatrixGraph::random_dag(v, 0.5, None, &mut rng);
+            // Reverse the graph.
+            let h = g.reversed();
+
+            // Assert labels and size are preserved.
+            assert!(L!(h).eq(L!(g)));
+            assert_eq!(h.size(), g.size());
+            // Assert each edge is flipped.
+            assert!(E!(g).all(|(x, y)| h.has_edge_by_index(y, x)));
+            assert!(E!(h).all(|(x, y)| g.has_edge_by_index(y, x)));
+            // Assert parents and children are swapped.
+            assert!(V!(g).all(|x| Pa!(g, x).eq(Ch!(h, x))));
+            // Assert reversing twice yields the original graph.
+            assert_eq!(h.reversed(), g);
+        }
+
+        #[test]
+        fn canonical_edge_set() {
+            // Set vertices labels.
+            let v = ["A", "B", "C", "D", "E", "F", "G", "H"];
+
+            // Initialize random number generator.
+            let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+            // Generate random graph.
+            let g = DirectedDenseAdjacencyMatrixGraph::random_dag(v, 0.5, None, &mut rng);
+            // Get the canonical edge set.
+            let e = g.canonical_edge_set();
+
+            // Assert the canonical edge set matches the edge set.
+            assert_eq!(e.len(), g.size());
+            assert!(E!(g).all(|(x, y)| e.contains(&(
+                g.get_vertex_by_index(x).to_owned(),
+                g.get_vertex_by_index(y).to_owned()
+            ))));
+            // Assert the same structure from a reversed edge list has the same canonical edge set.
+            let h = DirectedDenseAdjacencyMatrixGraph::new(
+                v,
+                e.iter().rev().map(|(x, y)| (x.as_str(), y.as_str())),
+            );
+            assert_eq!(h.canonical_edge_set(), e);
+            assert_eq!(h, g);
+        }
     }
 }
 
@@ -2734,6 +2949,29 @@ mod partially_directed {
                 }
             }
 
+            #[test]
+            fn density() {
+                // Test for ...
+                let data = [
+                    // ... zero vertices,
+                    (vec![], 0.),
+                    // ... one vertex,
+                    (vec![("0", "0")], 0.),
+                    // ... multiple edges,
+                    (vec![("0", "1"), ("1", "2"), ("2", "3")], 0.5),
+                    // ... complete graph,
+                    (vec![("0", "1"), ("0", "2"), ("1", "2")], 1.),
+                    // ... self-loops,
+                    (vec![("0", "0"), ("0", "1"), ("1", "1")], 3.),
+                ];
+
+                // Test for each scenario.
+                for (i, j) in data {
+                    let g = $G::new([], i);
+                    assert_eq!(g.density(), j);
+                }
+            }
+
             #[test]
             fn has_edge_by_index() {
                 // Test for ...
@@ -2777,6 +3015,39 @@ mod partially_directed {
                 g.has_edge_by_index(0, 0);
             }
 
+            #[test]
+            #[should_panic(expected = "No vertex with identifier `2` in a graph of order `2`")]
+            fn has_edge_by_index_foreign_index_should_panic() {
+                // Get the index of a vertex of a larger graph.
+                let h = $G::new(["A", "B", "C"], [("A", "C")]);
+                let x = h.get_vertex_index("C");
+                // Use it with a smaller graph.
+                let g = $G::new(["A", "B"], [("A", "B")]);
+                g.has_edge_by_index(0, x);
+            }
+
+            #[test]
+            #[should_panic(expected = "No vertex with identifier `2` in a graph of order `2`")]
+            fn add_edge_by_index_foreign_index_should_panic() {
+                // Get the index of a vertex of a larger graph.
+                let h = $G::new(["A", "B", "C"], [("A", "C")]);
+                let x = h.get_vertex_index("C");
+                // Use it with a smaller graph.
+                let mut g = $G::new(["A", "B"], [("A", "B")]);
+                g.add_edge_by_index(x, 0);
+            }
+
+            #[test]
+            #[should_panic(expected = "No vertex with identifier `2` in a graph of order `2`")]
+            fn del_edge_by_index_foreign_index_should_panic() {
+                // Get the index of a vertex of a larger graph.
+                let h = $G::new(["A", "B", "C"], [("A", "C")]);
+                let x = h.get_vertex_index("C");
+                // Use it with a smaller graph.
+                let mut g = $G::new(["A", "B"], [("A", "B")]);
+                g.del_edge_by_index(0, x);
+            }
+
             #[test]
             fn add_edge() {
                 // Test for ...
@@ -3003,5 +3274,36 @@ mod partially_directed {
             UndirectedDenseAdjacencyMatrixGraph,
         };
         generic_tests!(PartiallyDenseAdjacencyMatrixGraph);
+
+        #[test]
+        fn to_marked_matrix() {
+            // Initialize a partially directed graph.
+            let g = PartiallyDenseAdjacencyMatrixGraph::new_pagraph(
+                ["A", "B", "C", "D"],
+                [("A", "B")],
+                [("C", "B"), ("C", "D")],
+            );
+
+            // Assert marked matrix encoding.
+            assert_eq!(
+                g.to_marked_matrix(),
+                array![[0, 2, 0, 0], [2, 0, -1, 0], [0, 1, 0, 1], [0, 0, -1, 0]]
+            );
+            // Assert round-trip conversion.
+            assert_eq!(
+                PartiallyDenseAdjacencyMatrixGraph::from_marked_matrix(L!(g), g.to_marked_matrix()),
+                g
+            );
+        }
+
+        #[test]
+        #[should_panic]
+        fn from_marked_matrix_should_panic() {
+            // Initialize an inconsistent marked matrix.
+            PartiallyDenseAdjacencyMatrixGraph::from_marked_matrix(
+                ["A", "B"],
+                array![[0, 1], [1, 0]],
+            );
+        }
     }
 }
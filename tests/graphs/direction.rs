@@ -632,6 +632,67 @@ mod directed {
                 Ch!(g, 0);
             }
 
+            #[test]
+            #[should_panic(expected = "No vertex with identifier `2` in a graph of order `2`")]
+            fn parents_foreign_index_should_panic() {
+                // Get the index of a vertex of a larger graph.
+                let h = $G::new(["A", "B", "C"], [("A", "C")]);
+                let x = h.get_vertex_index("C");
+                // Use it with a smaller graph.
+                let g = $G::new(["A", "B"], [("A", "B")]);
+                Pa!(g, x);
+            }
+
+            #[test]
+            #[should_panic(expected = "No vertex with identifier `2` in a graph of order `2`")]
+            fn children_foreign_index_should_panic() {
+                // Get the index of a vertex of a larger graph.
+                let h = $G::new(["A", "B", "C"], [("A", "C")]);
+                let x = h.get_vertex_index("C");
+                // Use it with a smaller graph.
+                let g = $G::new(["A", "B"], [("A", "B")]);
+                Ch!(g, x);
+            }
+
+            #[test]
+            fn get_markov_blanket_by_index() {
+                // Test for ...
+                let data = [
+                    // NOTE: This would panic!
+                    // ... zero vertices,
+                    // (vec![], vec![], (0, vec![])),
+                    // ... isolated vertex,
+                    (vec!["0", "1"], vec![], (0, vec![])),
+                    // ... one self-loop, without self in the blanket,
+                    (vec![], vec![("0", "0")], (0, vec![])),
+                    // ... spouse through a shared child,
+                    (vec![], vec![("0", "2"), ("1", "2")], (0, vec![1, 2])),
+                    // ... parents, children and spouses,
+                    (
+                        vec![],
+                        vec![("0", "1"), ("1", "2"), ("3", "2"), ("2", "4")],
+                        (1, vec![0, 2, 3]),
+                    ),
+                    // ... self-loop on a child, without self in the blanket,
+                    (vec![], vec![("0", "1"), ("1", "1")], (0, vec![1])),
+                ];
+
+                // Test for each scenario.
+                for (i, j, (x, f)) in data {
+                    let g = $G::new(i, j);
+
+                    assert_eq!(Mb!(g, x), f);
+                }
+            }
+
+            #[test]
+            #[should_panic]
+            fn get_markov_blanket_by_index_should_panic() {
+                let g = $G::null();
+
+                Mb!(g, 0);
+            }
+
             #[test]
             fn is_child_by_index() {
                 // Test for ...
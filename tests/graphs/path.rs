@@ -201,6 +201,45 @@ mod directed {
                     assert_eq!(g.is_acyclic(), f, "(({:?}, {:?}), {})", v, e, f);
                 }
             }
+
+            #[test]
+            fn find_cycle() {
+                // Test for ...
+                let data = [
+                    // ... zero vertices and zero edges,
+                    (vec![], vec![], None),
+                    // ... one vertex and one edge,
+                    (vec!["0"], vec![("0", "0")], Some(vec![0])),
+                    // ... multiple vertices and multiple edges,
+                    (vec!["0", "1", "2"], vec![("0", "1"), ("1", "2")], None),
+                    (
+                        vec!["0", "1", "2"],
+                        vec![("0", "1"), ("1", "2"), ("2", "1")],
+                        Some(vec![1, 2]),
+                    ),
+                    // ... multiple vertices and multiple edges,
+                    (
+                        vec!["0", "1", "2"],
+                        vec![("0", "1"), ("1", "2"), ("2", "0")],
+                        Some(vec![0, 1, 2]),
+                    ),
+                ];
+
+                // Test for each scenario.
+                for (v, e, f) in data {
+                    let g = $G::new(v.clone(), e.clone());
+
+                    assert_eq!(g.find_cycle(), f, "(({:?}, {:?}), {:?})", v, e, f);
+                    // Assert a cycle is found iff the graph is not acyclic.
+                    assert_eq!(g.find_cycle().is_none(), g.is_acyclic());
+                }
+            }
+
+            #[test]
+            #[should_panic(expected = "Graph must be acyclic, found cycle: 0 -> 1 -> 2 -> 0")]
+            fn assert_acyclic() {
+                $G::new(["0", "1", "2"], [("0", "1"), ("1", "2"), ("2", "0")]).assert_acyclic();
+            }
         };
     }
 
@@ -108,6 +108,25 @@ mod undirected {
                 g.subgraph_by_vertices(vec![0]);
             }
 
+            #[test]
+            fn subgraph_by_labels() {
+                let g = $G::new(["A", "B", "C", "D"], [("A", "B"), ("B", "C"), ("C", "D")]);
+
+                let h = g.subgraph_by_labels(["C", "B", "A"]);
+
+                assert!(L!(h).eq(["A", "B", "C"]));
+                assert_eq!(h, g.subgraph_by_vertices([0, 1, 2]));
+                assert!(h.is_subgraph(&g));
+            }
+
+            #[test]
+            #[should_panic(expected = "No vertex with identifier `E`")]
+            fn subgraph_by_labels_should_panic() {
+                let g = $G::new(["A", "B", "C", "D"], []);
+
+                g.subgraph_by_labels(["A", "E"]);
+            }
+
             #[test]
             fn subgraph_by_edges() {
                 // Test for ...
@@ -277,6 +296,25 @@ mod directed {
                 g.subgraph_by_vertices(vec![0]);
             }
 
+            #[test]
+            fn subgraph_by_labels() {
+                let g = $G::new(["A", "B", "C", "D"], [("A", "B"), ("B", "C"), ("C", "D")]);
+
+                let h = g.subgraph_by_labels(["C", "B", "A"]);
+
+                assert!(L!(h).eq(["A", "B", "C"]));
+                assert_eq!(h, g.subgraph_by_vertices([0, 1, 2]));
+                assert!(h.is_subgraph(&g));
+            }
+
+            #[test]
+            #[should_panic(expected = "No vertex with identifier `E`")]
+            fn subgraph_by_labels_should_panic() {
+                let g = $G::new(["A", "B", "C", "D"], []);
+
+                g.subgraph_by_labels(["A", "E"]);
+            }
+
             #[test]
             fn subgraph_by_edges() {
                 // Test for ...
@@ -494,6 +532,25 @@ mod partially_directed {
                 g.subgraph_by_vertices(vec![0]);
             }
 
+            #[test]
+            fn subgraph_by_labels() {
+                let g = $G::new(["A", "B", "C", "D"], [("A", "B"), ("B", "C"), ("C", "D")]);
+
+                let h = g.subgraph_by_labels(["C", "B", "A"]);
+
+                assert!(L!(h).eq(["A", "B", "C"]));
+                assert_eq!(h, g.subgraph_by_vertices([0, 1, 2]));
+                assert!(h.is_subgraph(&g));
+            }
+
+            #[test]
+            #[should_panic(expected = "No vertex with identifier `E`")]
+            fn subgraph_by_labels_should_panic() {
+                let g = $G::new(["A", "B", "C", "D"], []);
+
+                g.subgraph_by_labels(["A", "E"]);
+            }
+
             #[test]
             fn subgraph_by_edges() {
                 // Test for ...
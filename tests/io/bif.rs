@@ -94,4 +94,394 @@ mod parser {
 
         assert_eq!(true_bif, pred_bif, "{true_bif}\n{pred_bif}");
     }
+
+    #[test]
+    fn from_bayesian_network() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("tests/assets/bif/asia.bif").unwrap().into();
+        // Get the variables in topological order.
+        let order: Vec<_> = b
+            .topological_order_labels()
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        // Cast to string.
+        let bif: String = BIF::from(b).into();
+        // Get the variables in declaration order.
+        let pred_order: Vec<_> = bif
+            .lines()
+            .filter_map(|l| l.strip_prefix("variable "))
+            .map(|l| l.trim_end_matches(" {").to_string())
+            .collect();
+
+        assert_eq!(order, pred_order);
+    }
+
+    #[test]
+    fn round_trip() {
+        use approx::*;
+
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("tests/assets/bif/asia.bif").unwrap().into();
+
+        // Cast to string with the default lossless formatting.
+        let bif: String = BIF::from(b.clone()).into();
+        // Parse the string back.
+        let c: CategoricalBN = BIF::try_from(bif).unwrap().into();
+
+        // Assert the CPDs are recovered.
+        assert!(L!(b.graph()).eq(L!(c.graph())));
+        for (phi, psi) in b.parameters().values().zip(c.parameters().values()) {
+            assert!(phi.scope().eq(psi.scope()));
+            assert_abs_diff_eq!(phi.values(), psi.values());
+        }
+    }
+
+    #[test]
+    fn to_string_with_precision() {
+        use approx::*;
+        use causal_hub::io::ToBifOptions;
+
+        // Read BIF from file.
+        let bif = BIF::read("tests/assets/bif/asia.bif").unwrap();
+
+        // Format values with two decimal digits.
+        let out = bif.to_string_with(ToBifOptions::new().with_precision(2));
+        assert!(out.contains("  table 0.99, 0.01;\n"));
+        assert!(out.contains("  (no, no) 1.00, 0.00;\n"));
+
+        // Parse the string back.
+        let c: CategoricalBN = BIF::try_from(out).unwrap().into();
+        // Assert the CPDs are recovered up to the given precision.
+        let b: CategoricalBN = bif.into();
+        for (phi, psi) in b.parameters().values().zip(c.parameters().values()) {
+            assert!(phi.values().abs_diff_eq(psi.values(), 5e-3));
+        }
+    }
+
+    #[test]
+    fn to_string_with_precision_round_trip() {
+        use approx::*;
+        use causal_hub::io::ToBifOptions;
+        use ndarray::prelude::*;
+
+        // Set a BN with values that do not terminate in decimal notation.
+        let b = CategoricalBN::with_parameters([
+            CategoricalCPD::new(("A", ["a", "b", "c"]), [], array![[1., 1., 1.]] / 3.),
+            CategoricalCPD::new(
+                ("B", ["no", "yes"]),
+                [("A", ["a", "b", "c"])],
+                array![[1., 2.], [5., 1.], [1., 1.]] / array![[3.], [6.], [2.]],
+            ),
+        ]);
+
+        // Format values with two decimal digits.
+        let out = BIF::from(b.clone()).to_string_with(ToBifOptions::new().with_precision(2));
+        assert!(out.contains("  table 0.33, 0.33, 0.33;\n"));
+        assert!(out.contains("  (b) 0.83, 0.17;\n"));
+
+        // Parse the string back.
+        let c = CategoricalBN::try_from_bif(&out).unwrap();
+        // Assert the CPDs are recovered up to the given precision, normalized.
+        for (phi, psi) in b.parameters().values().zip(c.parameters().values()) {
+            assert!(phi.scope().eq(psi.scope()));
+            assert!(phi.values().abs_diff_eq(psi.values(), 5e-3));
+            for row in psi.values().rows() {
+                assert_relative_eq!(row.sum(), 1., epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn to_string_with_compatibility() {
+        use causal_hub::io::ToBifOptions;
+
+        // Read BIF from file.
+        let bif = BIF::read("tests/assets/bif/asia.bif").unwrap();
+
+        // Assert names already in the common grammar are kept.
+        let out = bif.to_string_with(ToBifOptions::new().with_compatibility(true));
+        assert_eq!(out, bif.to_string_with(ToBifOptions::new()));
+    }
+
+    #[test]
+    fn to_string_with_compatibility_names() {
+        use causal_hub::io::ToBifOptions;
+
+        // Define BIF with names outside the common grammar.
+        let bif = concat!(
+            "network unknown {\n",
+            "}\n",
+            "variable x.1 {\n",
+            "  type discrete [ 2 ] { <=0.5, >0.5 };\n",
+            "}\n",
+            "variable x-2 {\n",
+            "  type discrete [ 2 ] { no, yes };\n",
+            "}\n",
+            "probability ( x.1 ) {\n",
+            "  table 0.3, 0.7;\n",
+            "}\n",
+            "probability ( x-2 | x.1 ) {\n",
+            "  (<=0.5) 0.9, 0.1;\n",
+            "  (>0.5) 0.2, 0.8;\n",
+            "}\n",
+        );
+        let bif = BIF::try_from(bif.to_string()).unwrap();
+
+        // Format names in compatibility mode.
+        let out = bif.to_string_with(ToBifOptions::new().with_compatibility(true));
+        assert!(out.contains("variable x_1 {\n  type discrete [ 2 ] { __0_5, _0_5 };\n}\n"));
+        assert!(out.contains("probability ( x_2 | x_1 ) {\n  (__0_5) 0.9, 0.1;\n"));
+        // Assert names only contain identifier characters.
+        assert!(out
+            .lines()
+            .filter_map(|l| l.strip_prefix("variable "))
+            .all(|l| l
+                .trim_end_matches(" {")
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_')));
+
+        // Parse the string back.
+        let b: CategoricalBN = BIF::try_from(out).unwrap().into();
+        // Assert structure and values are recovered.
+        assert!(L!(b.graph()).eq(["x_1", "x_2"]));
+        assert!(b.parameters()["x_1"].values().iter().eq(&[0.3, 0.7]));
+        assert!(b.parameters()["x_2"]
+            .values()
+            .iter()
+            .eq(&[0.9, 0.1, 0.2, 0.8]));
+    }
+
+    #[test]
+    #[should_panic(expected = "States names of `x` must be unique in compatibility mode")]
+    fn to_string_with_compatibility_should_panic() {
+        use causal_hub::io::ToBifOptions;
+
+        // Define BIF with states names colliding once replaced.
+        let bif = concat!(
+            "network unknown {\n",
+            "}\n",
+            "variable x {\n",
+            "  type discrete [ 2 ] { a.b, a-b };\n",
+            "}\n",
+            "probability ( x ) {\n",
+            "  table 0.5, 0.5;\n",
+            "}\n",
+        );
+        let bif = BIF::try_from(bif.to_string()).unwrap();
+
+        // Format names in compatibility mode.
+        bif.to_string_with(ToBifOptions::new().with_compatibility(true));
+    }
+
+    #[test_log::test]
+    fn try_from_with_properties() {
+        // Define BIF with properties and values normalized up to rounding.
+        let bif = concat!(
+            "network unknown {\n",
+            "  property;\n",
+            "}\n",
+            "variable a {\n",
+            "  property;\n",
+            "  type discrete [ 2 ] { no, yes };\n",
+            "}\n",
+            "variable b {\n",
+            "  type discrete [ 2 ] { no, yes };\n",
+            "}\n",
+            "probability ( a ) {\n",
+            "  property;\n",
+            "  table 0.4999999, 0.4999999;\n",
+            "}\n",
+            "probability ( b | a ) {\n",
+            "  (no) 0.9, 0.1;\n",
+            "  (yes) 0.2, 0.8;\n",
+            "}\n",
+        );
+
+        // Parse the string, skipping properties.
+        let bif = BIF::try_from(bif.to_string());
+        assert!(bif.is_ok(), "{:?}", bif.err());
+        let b: CategoricalBayesianNetwork = bif.unwrap().into();
+
+        // Assert values are normalized.
+        assert!(L!(b.graph()).eq(["a", "b"]));
+        assert!(b.parameters()["a"].values().iter().eq(&[0.5, 0.5]));
+    }
+
+    #[test]
+    fn try_from_with_scientific_notation() {
+        use approx::*;
+        use ndarray::array;
+
+        // Define BIF with exponents and leading-dot values.
+        let bif = concat!(
+            "network unknown {\n",
+            "}\n",
+            "variable a {\n",
+            "  type discrete [ 2 ] { no, yes };\n",
+            "}\n",
+            "variable b {\n",
+            "  type discrete [ 3 ] { low, mid, high };\n",
+            "}\n",
+            "probability ( a ) {\n",
+            "  table .25, 7.5e-1;\n",
+            "}\n",
+            "probability ( b | a ) {\n",
+            "  (no) 1.2e-3, .5, 4.988E-1;\n",
+            "  (yes) 1E-1, 25e-2, .65;\n",
+            "}\n",
+        );
+
+        // Parse the string.
+        let bif = BIF::try_from(bif.to_string());
+        assert!(bif.is_ok(), "{:?}", bif.err());
+        let b: CategoricalBN = bif.unwrap().into();
+
+        // Define the expected CPDs.
+        let p_a = CategoricalCPD::new(("a", ["no", "yes"]), [], array![[0.25, 0.75]]);
+        let p_b = CategoricalCPD::new(
+            ("b", ["low", "mid", "high"]),
+            [("a", ["no", "yes"])],
+            array![[0.0012, 0.5, 0.4988], [0.1, 0.25, 0.65]],
+        );
+
+        // Assert the CPDs are recovered.
+        for (phi, psi) in b.parameters().values().zip([p_a, p_b]) {
+            assert!(phi.scope().eq(psi.scope()));
+            assert_eq!(phi.states(), psi.states());
+            assert_abs_diff_eq!(phi.values(), psi.values(), epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn try_from_with_non_normalized_values() {
+        // Define BIF with values that do not sum to one.
+        let bif = concat!(
+            "network unknown {\n",
+            "}\n",
+            "variable a {\n",
+            "  type discrete [ 2 ] { no, yes };\n",
+            "}\n",
+            "probability ( a ) {\n",
+            "  table 1.2e-1, .5;\n",
+            "}\n",
+        );
+
+        // Parse the string.
+        let e = CategoricalBN::try_from_bif(bif).unwrap_err();
+
+        // Assert the error points to the `a` probability.
+        assert_eq!((e.line(), e.column()), (6, 15));
+        assert_eq!(
+            e.message(),
+            "Probability of `a` must sum to one, found 0.62"
+        );
+    }
+
+    /// Read the first `n` lines of the asia BIF, if any.
+    fn read_asia(n: Option<usize>) -> String {
+        // Read BIF from file.
+        let bif = std::fs::read_to_string("./tests/assets/bif/asia.bif").unwrap();
+        // Take the first `n` lines.
+        let n = n.unwrap_or(usize::MAX);
+
+        bif.lines().take(n).map(|x| format!("{x}\n")).collect()
+    }
+
+    #[test]
+    fn try_from_bif() {
+        // Parse the BIF.
+        let b = CategoricalBN::try_from_bif(&read_asia(None));
+        assert!(b.is_ok(), "{:?}", b.err());
+
+        // Assert the network is recovered.
+        let true_b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+        assert_eq!(b.unwrap().to_string(), true_b.to_string());
+    }
+
+    #[test]
+    fn try_from_bif_truncated() {
+        // Parse the BIF truncated within the `either` probability.
+        let e = CategoricalBN::try_from_bif(&read_asia(Some(47))).unwrap_err();
+
+        // Assert the error points to the end of the input.
+        assert_eq!((e.line(), e.column()), (48, 1));
+    }
+
+    #[test]
+    fn try_from_bif_missing_probability() {
+        // Parse the BIF truncated after the `either` probability.
+        let e = CategoricalBN::try_from_bif(&read_asia(Some(50))).unwrap_err();
+
+        // Assert the error points to the `xray` declaration.
+        assert_eq!((e.line(), e.column()), (21, 1));
+        assert_eq!(e.message(), "Variable `xray` has no probability");
+    }
+
+    #[test]
+    fn try_from_bif_mismatched_cardinality() {
+        // Remove the `(no)` entry of the `tub` probability.
+        let bif = read_asia(None).replacen("  (no) 0.01, 0.99;\n", "", 1);
+        let e = CategoricalBN::try_from_bif(&bif).unwrap_err();
+
+        // Assert the error points to the `tub` probability.
+        assert_eq!((e.line(), e.column()), (30, 15));
+        assert_eq!(
+            e.message(),
+            "Probability of `tub` must have 4 values, found 2"
+        );
+    }
+
+    #[test]
+    fn try_from_bif_zeros() {
+        // Set the `(yes)` entry of the `tub` probability to zeros.
+        let bif = read_asia(None).replacen("  (yes) 0.05, 0.95;\n", "  (yes) 0, 0;\n", 1);
+        let e = CategoricalBN::try_from_bif(&bif).unwrap_err();
+
+        // Assert the error points to the `tub` probability.
+        assert_eq!((e.line(), e.column()), (30, 15));
+        assert_eq!(
+            e.message(),
+            "Probability of `tub` must not have rows of zeros"
+        );
+    }
+
+    #[test]
+    fn try_from_bif_cyclic() {
+        // Define BIF with a cycle between `a` and `b`.
+        let bif = concat!(
+            "network unknown {\n",
+            "}\n",
+            "variable a {\n",
+            "  type discrete [ 2 ] { no, yes };\n",
+            "}\n",
+            "variable b {\n",
+            "  type discrete [ 2 ] { no, yes };\n",
+            "}\n",
+            "probability ( a | b ) {\n",
+            "  (no) 0.5, 0.5;\n",
+            "  (yes) 0.5, 0.5;\n",
+            "}\n",
+            "probability ( b | a ) {\n",
+            "  (no) 0.9, 0.1;\n",
+            "  (yes) 0.2, 0.8;\n",
+            "}\n",
+        );
+        let e = CategoricalBN::try_from_bif(bif).unwrap_err();
+
+        // Assert the error points to the `a` probability.
+        assert_eq!((e.line(), e.column()), (9, 15));
+        assert_eq!(
+            e.message(),
+            "Graph must be acyclic, found cycle: a -> b -> a"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "48:1:")]
+    fn from_bif_should_panic() {
+        // Parse the truncated BIF.
+        CategoricalBN::from_bif(&read_asia(Some(47)));
+    }
 }
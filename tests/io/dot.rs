@@ -41,6 +41,174 @@ mod parser {
 
         assert!(L!(g).eq(["a", "b"]));
     }
+
+    #[test]
+    fn from_dot() {
+        // Define DOT with quoted identifiers, chains and default blocks.
+        let dot = concat!(
+            "digraph {\n",
+            "  node [shape = box];\n",
+            "  edge [color = red];\n",
+            "  \"X 1\" [label = \"first\"];\n",
+            "  \"X 1\" -> B -> \"C \\\"2\\\"\" [style = dashed];\n",
+            "  B -> D;\n",
+            "}\n",
+        );
+
+        // Parse the string.
+        let g = DiGraph::from_dot(dot).unwrap();
+        // Assert quoted identifiers are unquoted and chains are expanded.
+        assert!(L!(g).eq(["B", "C \"2\"", "D", "X 1"]));
+        assert!(E!(g).eq([(0, 1), (0, 2), (3, 0)]));
+
+        // Parse the string into DOT.
+        let d = DOT::try_from(dot.to_string()).unwrap();
+        // Assert default blocks and attributes are parsed.
+        assert_eq!(d.global_attributes.vertices.len(), 1);
+        assert_eq!(d.global_attributes.edges.len(), 1);
+        assert_eq!(d.vertices["X 1"].attributes.len(), 1);
+        let e = |x: &str, y: &str| &d.edges[&(x.to_string(), y.to_string())];
+        assert_eq!(e("X 1", "B").attributes.len(), 1);
+        assert_eq!(e("B", "C \"2\"").attributes.len(), 1);
+        assert!(e("B", "D").attributes.is_empty());
+
+        // Assert formatting and parsing again is lossless.
+        let s = String::from(d);
+        assert_eq!(String::from(DOT::try_from(s.clone()).unwrap()), s);
+    }
+
+    #[test]
+    fn from_dot_round_trip() {
+        // Build a new undirected graph.
+        let g = Graph::new(["A", "B", "C"], [("A", "B"), ("B", "C")]);
+        // Assert the graph is recovered.
+        assert_eq!(Graph::from_dot(&g.to_dot()).unwrap(), g);
+
+        // Build a new directed graph.
+        let g = DiGraph::new(["A", "B", "C"], [("A", "B"), ("C", "B")]);
+        // Assert the graph is recovered.
+        assert_eq!(DiGraph::from_dot(&g.to_dot()).unwrap(), g);
+    }
+
+    #[test]
+    fn from_dot_invalid_key() {
+        use pest::error::LineColLocation;
+
+        // Define DOT with an invalid edge attribute key on the third line.
+        let dot = "digraph {\n  A -> B;\n  B -> C [shape = box];\n}\n";
+
+        // Parse the string.
+        let e = DiGraph::from_dot(dot).unwrap_err();
+        // Assert the error points to the invalid key.
+        assert!(e
+            .to_string()
+            .contains("Invalid attribute key `shape` for EdgeAttributes"));
+        assert!(matches!(e.line_col, LineColLocation::Span((3, 11), _)));
+    }
+
+    #[test]
+    fn from_dot_subgraph() {
+        use pest::error::LineColLocation;
+
+        // Parse a graph with a subgraph as source endpoint.
+        let e = DiGraph::from_dot("digraph { {A B} -> C }").unwrap_err();
+        // Assert the error points to the subgraph.
+        assert!(e.to_string().contains("Subgraphs are not supported"));
+        assert!(matches!(e.line_col, LineColLocation::Span((1, 11), _)));
+
+        // Parse a graph with a subgraph as target endpoint.
+        let e = DiGraph::from_dot("digraph { A -> {B C} }").unwrap_err();
+        // Assert the error points to the subgraph.
+        assert!(e.to_string().contains("Subgraphs are not supported"));
+        assert!(matches!(e.line_col, LineColLocation::Span((1, 16), _)));
+
+        // Parse a graph with a subgraph as statement.
+        let e = DiGraph::from_dot("digraph { A -> B; subgraph S { C } }").unwrap_err();
+        // Assert the error points to the subgraph.
+        assert!(e.to_string().contains("Subgraphs are not supported"));
+        assert!(matches!(e.line_col, LineColLocation::Span((1, 19), _)));
+    }
+
+    #[test]
+    fn from_dot_invalid_graph_type() {
+        // Parse a directed graph as undirected.
+        let e = Graph::from_dot("digraph { A -> B }").unwrap_err();
+        // Assert the error reports the graph type.
+        assert!(e.to_string().contains("DOT graph type must be `graph`"));
+    }
+
+    #[test]
+    fn to_dot_graph() {
+        // Build a new undirected graph.
+        let g = Graph::new(["A", "B", "C"], [("A", "B"), ("B", "C")]);
+
+        // Assert undirected edges are plain lines.
+        assert_eq!(
+            g.to_dot(),
+            "graph {\n\t\"A\"\n\t\"B\"\n\t\"C\"\n\t\"A\" -- \"B\"\n\t\"B\" -- \"C\"\n}\n"
+        );
+    }
+
+    #[test]
+    fn to_dot_digraph() {
+        // Build a new directed graph.
+        let g = DiGraph::new(["A", "B", "C"], [("A", "B"), ("C", "B")]);
+
+        // Assert directed edges are arrows.
+        assert_eq!(
+            g.to_dot(),
+            "digraph {\n\t\"A\"\n\t\"B\"\n\t\"C\"\n\t\"A\" -> \"B\"\n\t\"C\" -> \"B\"\n}\n"
+        );
+    }
+
+    #[test]
+    fn to_dot_pdgraph() {
+        // Build a new partially directed graph with the undirected edge B -- C.
+        let mut g = PDGraph::new(["A", "B", "C"], [("B", "C")]);
+        // Add the directed edge A -> B.
+        g.add_directed_edge_by_index(0, 1);
+
+        // Assert directed edges are arrows and undirected edges have no arrowhead.
+        assert_eq!(
+            g.to_dot(),
+            "digraph {\n\t\"A\"\n\t\"B\"\n\t\"C\"\n\t\"A\" -> \"B\"\n\t\"B\" -> \"C\" [ dir = none; ]\n}\n"
+        );
+    }
+
+    #[test]
+    fn read_write_dot() {
+        // Create a temporary directory.
+        let dir = tempfile::tempdir().unwrap();
+
+        // Build a new undirected graph.
+        let g = Graph::new(["A", "B", "C"], [("A", "B"), ("B", "C")]);
+        // Write it to file.
+        let path = dir.path().join("graph.dot");
+        g.write_dot(&path).unwrap();
+        // Assert the graph is recovered.
+        assert_eq!(Graph::read_dot(&path).unwrap(), g);
+
+        // Build a new directed graph.
+        let g = DiGraph::new(["A", "B", "C"], [("A", "B"), ("C", "B")]);
+        // Write it to file.
+        let path = dir.path().join("digraph.dot");
+        g.write_dot(&path).unwrap();
+        // Assert the graph is recovered.
+        assert_eq!(DiGraph::read_dot(&path).unwrap(), g);
+
+        // Assert the graph type is checked.
+        assert!(matches!(Graph::read_dot(&path), Err(DotIOError::Parser(_))));
+    }
+
+    #[test]
+    fn read_dot_missing_file() {
+        // Create a temporary directory.
+        let dir = tempfile::tempdir().unwrap();
+
+        // Assert I/O errors are propagated.
+        let g = DiGraph::read_dot(dir.path().join("missing.dot"));
+        assert!(matches!(g, Err(DotIOError::IO(e)) if e.kind() == std::io::ErrorKind::NotFound));
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod directed {
+    use causal_hub::{models::GSeparation, prelude::*};
+    use itertools::Itertools;
+
+    fn graph() -> DiGraph {
+        // Build the backdoor graph with a collider between the confounders.
+        DiGraph::new(
+            ["W", "X", "Y", "Z1", "Z2", "Z3"],
+            [
+                ("Z1", "X"),
+                ("Z1", "Z3"),
+                ("Z2", "Z3"),
+                ("Z2", "Y"),
+                ("Z3", "X"),
+                ("Z3", "Y"),
+                ("X", "W"),
+                ("W", "Y"),
+            ],
+        )
+    }
+
+    // Check the backdoor criterion by means of d-separation.
+    fn is_valid(g: &DiGraph, x: usize, y: usize, z: &[usize]) -> bool {
+        // Remove the edges out of X.
+        let mut h = g.clone();
+        for c in g.get_children_by_index(x).collect_vec() {
+            h.del_edge_by_index(x, c);
+        }
+
+        // Check Z contains no descendants of X and blocks the backdoor paths.
+        !z.iter()
+            .any(|&z| g.get_descendants_by_index(x).any(|d| d == z))
+            && GSeparation::from(&h).is_independent(x, y, z)
+    }
+
+    #[test]
+    fn is_backdoor_adjustment_set() {
+        // Build the graph.
+        let g = graph();
+
+        // Test for each candidate set.
+        for (z, f) in [
+            (vec![], false),
+            (vec![5], false),
+            (vec![3, 4], false),
+            (vec![3, 5], true),
+            (vec![4, 5], true),
+            (vec![3, 4, 5], true),
+            // ... with a descendant of X.
+            (vec![0, 3, 5], false),
+        ] {
+            assert_eq!(
+                causal_hub::models::is_backdoor_adjustment_set(&g, 1, 2, &z),
+                f,
+                "{z:?}"
+            );
+            assert_eq!(is_valid(&g, 1, 2, &z), f, "{z:?}");
+        }
+    }
+
+    #[test]
+    fn backdoor_adjustment_sets() {
+        // Build the graph.
+        let g = graph();
+
+        // Enumerate the minimal adjustment sets.
+        let sets = causal_hub::models::backdoor_adjustment_sets(&g, 1, 2).collect_vec();
+
+        // Assert the minimal sets.
+        assert_eq!(
+            sets,
+            [FxIndexSet::from_iter([3, 5]), FxIndexSet::from_iter([4, 5])]
+        );
+        for z in &sets {
+            let z = z.iter().copied().collect_vec();
+            // Assert each set is valid ...
+            assert!(is_valid(&g, 1, 2, &z));
+            // ... and minimal.
+            for w in z.iter().copied().combinations(z.len() - 1) {
+                assert!(!is_valid(&g, 1, 2, &w));
+            }
+        }
+    }
+
+    #[test]
+    fn backdoor_adjustment_sets_empty() {
+        // Build a graph without confounders.
+        let g = DiGraph::new(["X", "Y", "Z"], [("X", "Y"), ("Y", "Z")]);
+        // Assert the empty set is the only minimal set.
+        assert!(causal_hub::models::backdoor_adjustment_sets(&g, 0, 1).eq([FxIndexSet::default()]));
+
+        // Build a graph where Y is a parent of X.
+        let g = DiGraph::new(["X", "Y"], [("Y", "X")]);
+        // Assert there are no valid sets.
+        assert_eq!(
+            causal_hub::models::backdoor_adjustment_sets(&g, 0, 1).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn optimal_adjustment_set() {
+        // Build the graph.
+        let g = graph();
+
+        // Compute the optimal adjustment set.
+        let o = causal_hub::models::optimal_adjustment_set(&g, 1, 2);
+
+        // Assert the O-set.
+        assert_eq!(o, FxIndexSet::from_iter([4, 5]));
+        // Assert it is valid.
+        assert!(is_valid(&g, 1, 2, &o.into_iter().collect_vec()));
+
+        // Build a graph with a precision variable and a mediator.
+        let g = DiGraph::new(
+            ["M", "P", "X", "Y", "Z"],
+            [("Z", "X"), ("Z", "Y"), ("X", "M"), ("M", "Y"), ("P", "Y")],
+        );
+        // Assert the O-set includes the precision variable.
+        assert_eq!(
+            causal_hub::models::optimal_adjustment_set(&g, 2, 3),
+            FxIndexSet::from_iter([1, 4])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Y must be a descendant of X")]
+    fn optimal_adjustment_set_should_panic() {
+        // Build a graph where Y is not a descendant of X.
+        let g = DiGraph::new(["X", "Y"], [("Y", "X")]);
+        // Compute the optimal adjustment set.
+        causal_hub::models::optimal_adjustment_set(&g, 0, 1);
+    }
+}
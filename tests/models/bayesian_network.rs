@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod categorical {
+    use approx::*;
     use causal_hub::prelude::*;
     use rand::SeedableRng;
     use rand_xoshiro::Xoshiro256PlusPlus;
@@ -13,4 +14,423 @@ mod categorical {
         // Sample using forward sampling.
         true_b.sample(&mut rng, 1e3 as usize);
     }
+
+    #[test]
+    fn sample_deterministic() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+
+        // Sample using forward sampling, sequentially and in parallel.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        let d = b.sample(&mut rng, 1e3 as usize);
+        let e = b.par_sample(&mut rng, 1e3 as usize);
+
+        // Assert `either` is the logical OR of `lung` and `tub`.
+        for d in [d, e] {
+            assert!(d
+                .rows()
+                .all(|row| row[3] == (row[4] == 1 || row[6] == 1) as u8));
+        }
+    }
+
+    #[test]
+    fn log_density() {
+        // Initialize random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        // Read BN from BIF.
+        let true_b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+        // Sample using forward sampling.
+        let d = true_b.sample(&mut rng, 1e2 as usize);
+
+        // Assert sampled values have finite non-positive log-density.
+        for x in d.data().rows() {
+            let l = true_b.log_density(x);
+            assert!(l.is_finite() && l <= 0.);
+        }
+    }
+
+    #[test]
+    fn observed_log_likelihood() {
+        // Initialize random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+        // Sample using forward sampling.
+        let d = b.sample(&mut rng, 1e2 as usize);
+
+        // Assert complete data observed log-likelihood is the log-likelihood.
+        let true_ll: f64 = d.data().rows().into_iter().map(|x| b.log_density(x)).sum();
+        let ll = b.observed_log_likelihood(d.data().mapv(Some).view());
+        assert_relative_eq!(ll, true_ll, epsilon = 1e-8);
+
+        // Assert fully missing data observed log-likelihood is zero.
+        let ll = b.observed_log_likelihood(d.data().mapv(|_| None).view());
+        assert_relative_eq!(ll, 0.);
+
+        // Set the first variable as missing.
+        let mut x = d.data().mapv(Some);
+        x.column_mut(0).fill(None);
+        // Assert the missing variable is summed-out.
+        let true_ll: f64 = d
+            .data()
+            .rows()
+            .into_iter()
+            .map(|x| {
+                (0..2)
+                    .map(|s| {
+                        let mut x = x.to_owned();
+                        x[0] = s;
+                        b.log_density(x.view()).exp()
+                    })
+                    .sum::<f64>()
+                    .ln()
+            })
+            .sum();
+        let ll = b.observed_log_likelihood(x.view());
+        assert_relative_eq!(ll, true_ll, epsilon = 1e-8);
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of columns must be equal to the number of variables")]
+    fn observed_log_likelihood_should_panic() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+
+        // Compute the observed log-likelihood of a data set with wrong number of columns.
+        b.observed_log_likelihood(ndarray::Array2::from_elem((1, 2), Some(0)).view());
+    }
+
+    #[test]
+    fn log_likelihood_by_node() {
+        // Initialize random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+        // Sample using forward sampling.
+        let d = b.sample(&mut rng, 1e2 as usize);
+
+        // Compute the log-likelihood by node.
+        let ll = b.log_likelihood_by_node(&d);
+        // Assert each variable has a non-positive contribution.
+        assert!(ll.keys().eq(L!(b.graph())));
+        assert!(ll.values().all(|l| l.is_finite() && *l <= 0.));
+        // Assert the contributions sum to the log-likelihood.
+        let true_ll: f64 = d.data().rows().into_iter().map(|x| b.log_density(x)).sum();
+        assert_relative_eq!(ll.values().sum::<f64>(), true_ll, epsilon = 1e-8);
+        // Assert root contributions depend only on their own marginal counts.
+        let asia = d.data().column(0).iter().filter(|&&x| x == 1).count() as f64;
+        assert_relative_eq!(
+            ll["asia"],
+            asia * 0.01_f64.ln() + (100. - asia) * 0.99_f64.ln(),
+            epsilon = 1e-8
+        );
+    }
+
+    #[test]
+    fn serialize() {
+        // Read BN from BIF.
+        let b_a: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+        // Construct an equal BN from the parameters in reverse order.
+        let b_b = CategoricalBN::new(
+            b_a.graph().clone(),
+            b_a.parameters().values().rev().cloned(),
+        );
+
+        // Assert parameters are sorted w.r.t. the variables labels.
+        assert!(b_b.parameters().keys().eq(L!(b_b.graph())));
+        // Assert equal BNs serialize identically.
+        let s_a = serde_json::to_string(&b_a).unwrap();
+        let s_b = serde_json::to_string(&b_b).unwrap();
+        assert_eq!(s_a, s_b);
+        // Assert round-trip serialization.
+        let b_c: CategoricalBN = serde_json::from_str(&s_a).unwrap();
+        assert_eq!(b_a, b_c);
+        assert_eq!(s_a, serde_json::to_string(&b_c).unwrap());
+    }
+
+    #[test]
+    fn validate() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+
+        // Assert well-formed BN is valid.
+        assert_eq!(b.validate(), Ok(()));
+
+        // Serialize BN and drop two parameters.
+        let mut v = serde_json::to_value(&b).unwrap();
+        let theta = v["theta"].as_object_mut().unwrap();
+        theta.remove("asia");
+        theta.remove("xray");
+        // Deserialize the ill-formed BN.
+        let b: CategoricalBN = serde_json::from_value(v).unwrap();
+
+        // Assert all errors are collected.
+        assert_eq!(
+            b.validate(),
+            Err(vec![
+                ModelError::MissingParameter("asia".into()),
+                ModelError::MissingParameter("xray".into()),
+            ])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Graph must be acyclic, found cycle: A -> B -> A")]
+    fn with_parameters_should_panic() {
+        // Construct a BN with cyclic parameters P(A | B) P(B | A).
+        CategoricalBN::with_parameters([
+            CategoricalCPD::new(
+                ("A", ["no", "yes"]),
+                [("B", ["no", "yes"])],
+                ndarray::array![[0.9, 0.1], [0.2, 0.8]],
+            ),
+            CategoricalCPD::new(
+                ("B", ["no", "yes"]),
+                [("A", ["no", "yes"])],
+                ndarray::array![[0.7, 0.3], [0.4, 0.6]],
+            ),
+        ]);
+    }
+
+    #[test]
+    fn deserialize_cyclic() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+
+        // Add the edge `either -> asia`, closing the cycle `asia -> tub -> either -> asia`.
+        let mut g = b.graph().clone();
+        g.add_edge_by_index(g.get_vertex_index("either"), g.get_vertex_index("asia"));
+        // Serialize BN and replace the graph.
+        let mut v = serde_json::to_value(&b).unwrap();
+        v["graph"] = serde_json::to_value(&g).unwrap();
+
+        // Assert the cyclic BN is rejected.
+        let e = serde_json::from_value::<CategoricalBN>(v).unwrap_err();
+        assert!(e
+            .to_string()
+            .starts_with("Graph must be acyclic, found cycle: asia -> tub -> either -> asia"));
+    }
+
+    #[test]
+    fn cpd() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+
+        // Assert each CPD is the parameter of the associated variable.
+        for x in L!(b.graph()) {
+            assert_eq!(b.cpd(x), &b.parameters()[x]);
+            assert_eq!(b.cpd(x).target(), x);
+        }
+
+        // Condition the CPD of `either` on `tub = yes`.
+        let e = [("tub".to_string(), "yes".to_string())]
+            .into_iter()
+            .collect();
+        let phi = b.cpd("either").condition(&e, true);
+        // Assert the conditioned CPD is deterministic.
+        assert!(phi.scope().eq(["either", "lung"]));
+        assert!(phi.values().iter().eq(&[0., 0., 1., 1.]));
+    }
+
+    #[test]
+    #[should_panic(expected = "No variable with label `X`")]
+    fn cpd_should_panic() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+
+        // Get the CPD of a missing variable.
+        b.cpd("X");
+    }
+
+    #[test]
+    fn topological_order_labels() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+
+        // Get the variables in topological order.
+        let order = b.topological_order_labels();
+        assert_eq!(
+            order,
+            ["asia", "smoke", "tub", "bronc", "lung", "either", "dysp", "xray"]
+        );
+
+        // Assert each variable follows its parents.
+        for (i, x) in order.iter().enumerate() {
+            let x = b.graph().get_vertex_index(x);
+            assert!(Pa!(b.graph(), x)
+                .map(|z| b.graph().get_vertex_by_index(z))
+                .all(|z| order[..i].contains(&z)));
+        }
+    }
+
+    #[test]
+    fn marginal() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+        // Construct estimator.
+        let estimator = VE::new(&b);
+
+        for x in L!(b.graph()) {
+            // Compute the marginal distribution.
+            let p = b.marginal(x);
+            // Assert the marginal distribution is parentless.
+            assert_eq!(p.target(), x);
+            assert!(p.scope().eq([x]));
+            // Assert it matches variable elimination over the whole network.
+            let q: CategoricalFactor = estimator.marginal(x).into();
+            assert_relative_eq!(p.values(), q.values(), epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn predict() {
+        // Initialize random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+        // Sample using forward sampling.
+        let d = b.sample(&mut rng, 1e2 as usize);
+        // Construct estimator.
+        let estimator = VE::new(&b);
+
+        for (i, x) in L!(b.graph()).enumerate() {
+            // Predict the variable.
+            let y = b.predict(&d, x);
+            assert_eq!(y.len(), d.sample_size());
+
+            for (row, &y) in d.data().rows().into_iter().zip(&y) {
+                // Set the evidence to the other variables.
+                let e = d
+                    .states()
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .map(|(j, (z, s))| (z.clone(), s[row[j] as usize].clone()))
+                    .collect();
+                // Query the posterior distribution by variable elimination.
+                let p = estimator.query([x], &e).unwrap();
+                // Get the first state with maximum probability.
+                let max = p.values().iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                let true_y = p.values().iter().position(|&p| p == max).unwrap();
+                // Assert the prediction is the posterior mode.
+                assert_eq!(y as usize, true_y);
+            }
+        }
+
+        // Assert deterministic variables are predicted exactly.
+        assert_eq!(b.predict(&d, "either"), d.data().column(3));
+    }
+
+    #[test]
+    fn par_predict() {
+        // Initialize random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+        // Sample using forward sampling.
+        let d = b.sample(&mut rng, 1e3 as usize);
+
+        // Assert parallel and serial predictions match.
+        for x in L!(b.graph()) {
+            assert_eq!(b.par_predict(&d, x), b.predict(&d, x));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Data set and network must have the same variables")]
+    fn predict_should_panic() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+        // Construct a data set with different variables.
+        let d = CategoricalDataMatrix::from_ndarray(
+            ndarray::array![[0, 1]],
+            [("A", vec!["no", "yes"]), ("B", vec!["no", "yes"])],
+        );
+
+        // Predict a variable.
+        b.predict(&d, "asia");
+    }
+
+    #[test]
+    fn to_dot_with_cpds() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+        // Render it to DOT.
+        let dot = b.to_dot_with_cpds(2);
+
+        // Assert root vertices are labelled with their marginals.
+        assert!(dot.contains("\"asia\" [ label = \"asia\\nno: 0.99\\nyes: 0.01\"; ]"));
+        // Assert non-root vertices are labelled with their CPD tables.
+        assert!(dot.contains(concat!(
+            "\"lung\" [ label = <<table border=\"0\" cellborder=\"1\" cellspacing=\"0\">",
+            "<tr><td colspan=\"3\"><b>lung</b></td></tr>",
+            "<tr><td><i>smoke</i></td><td>no</td><td>yes</td></tr>",
+            "<tr><td>no</td><td>0.99</td><td>0.01</td></tr>",
+            "<tr><td>yes</td><td>0.90</td><td>0.10</td></tr>",
+            "</table>>; ]"
+        )));
+        // Assert the precision is respected.
+        assert!(b.to_dot_with_cpds(3).contains("<td>0.990</td>"));
+
+        // Assert the output is valid DOT and the graph is recovered.
+        assert_eq!(&DiGraph::from_dot(&dot).unwrap(), b.graph());
+    }
+
+    #[test]
+    fn shared() {
+        use std::{sync::Arc, thread};
+
+        // Assert the network can be shared across threads.
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<CategoricalBN>();
+
+        // Read BN from BIF.
+        let b: Arc<CategoricalBN> =
+            Arc::new(BIF::read("./tests/assets/bif/asia.bif").unwrap().into());
+
+        // Query the shared network from multiple threads.
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                // Share the network.
+                let b = Arc::clone(&b);
+                thread::spawn(move || {
+                    // Compute a marginal distribution.
+                    let p = VE::new(b.as_ref()).call(["dysp"]);
+                    // Sample with a per-thread seed.
+                    let mut rng = Xoshiro256PlusPlus::seed_from_u64(i);
+                    let d = b.sample(&mut rng, 10);
+
+                    (p, d)
+                })
+            })
+            .collect();
+
+        // Assert the results match the single-thread ones.
+        for (i, h) in handles.into_iter().enumerate() {
+            let (p, d) = h.join().unwrap();
+            assert_eq!(p, VE::new(b.as_ref()).call(["dysp"]));
+            let mut rng = Xoshiro256PlusPlus::seed_from_u64(i as u64);
+            assert_eq!(d.data(), b.sample(&mut rng, 10).data());
+        }
+    }
+
+    #[test]
+    fn random() {
+        // Initialize the graph.
+        let g = DiGraph::new(["A", "B", "C"], [("A", "B"), ("A", "C"), ("B", "C")]);
+
+        // Initialize random number generators.
+        let mut rng_a = Xoshiro256PlusPlus::seed_from_u64(42);
+        let mut rng_b = Xoshiro256PlusPlus::seed_from_u64(42);
+        // Construct random BNs.
+        let b_a = CategoricalBN::random(g.clone(), &[2, 3, 4], &mut rng_a, 1.);
+        let b_b = CategoricalBN::random(g.clone(), &[2, 3, 4], &mut rng_b, 1.);
+
+        // Assert random BN is reproducible under a seed.
+        assert_eq!(b_a, b_b);
+        // Assert random BN has the given structure.
+        assert_eq!(b_a.graph(), &g);
+        // Assert random BN has the given cardinalities.
+        assert_eq!(b_a.parameters()["C"].values().shape(), &[2, 3, 4]);
+    }
 }
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod categorical {
+    use causal_hub::{polars::prelude::*, prelude::*};
+    use rand::SeedableRng;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+
+    fn learn(d: &CategoricalDataMatrix) -> DiGraph {
+        // Initialize empty prior knowledge.
+        let k = FR::new(d.labels_iter(), [], []);
+        // Initialize score functor.
+        let s = BIC::new(d);
+        // Perform discovery.
+        HC::new(&s).call(d, &k)
+    }
+
+    #[test]
+    fn cross_validate() {
+        // Load data set.
+        let d = CsvReader::from_path("./tests/assets/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+
+        // Initialize random number generators.
+        let mut rng_a = Xoshiro256PlusPlus::seed_from_u64(42);
+        let mut rng_b = Xoshiro256PlusPlus::seed_from_u64(42);
+        // Compute the cross-validated log-likelihood.
+        let s_a = causal_hub::models::cross_validate::<BE, _, _>(&d, 5, learn, &mut rng_a);
+        let s_b = causal_hub::models::cross_validate::<BE, _, _>(&d, 5, learn, &mut rng_b);
+
+        // Assert a finite non-positive log-likelihood for each fold.
+        assert_eq!(s_a.len(), 5);
+        assert!(s_a.iter().all(|s| s.is_finite() && *s <= 0.));
+        // Assert cross-validation is reproducible under a seed.
+        assert_eq!(s_a, s_b);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cross_validate_should_panic() {
+        // Load data set.
+        let d = CsvReader::from_path("./tests/assets/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+
+        // Initialize random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        // Compute the cross-validated log-likelihood with a single fold.
+        causal_hub::models::cross_validate::<BE, _, _>(&d, 1, learn, &mut rng);
+    }
+}
@@ -80,4 +80,56 @@ mod variable_elimination {
                 .all(|(x, y)| { x.relative_eq(y, 1e-16, 1e-15) || (x.is_nan() && y.is_nan()) }));
         }
     }
+
+    #[test]
+    fn query() {
+        // Initialize Bayesian network.
+        let b: CategoricalBN = BIF::read("tests/assets/bif/asia.bif").unwrap().into();
+        // Set the evidence.
+        let e = FxIndexMap::from_iter([("smoke".to_string(), "yes".to_string())]);
+
+        // Construct estimator.
+        let estimator = VE::new(&b);
+
+        // Perform the query.
+        let p = estimator.query(["dysp"], &e).unwrap();
+        // Assert it matches the conditioned conditional distribution.
+        let q = estimator.conditional("dysp", ["smoke"]).condition(&e, true);
+        assert!(p.scope().eq(["dysp"]));
+        assert_relative_eq!(p.values(), q.values(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn query_impossible_evidence() {
+        // Initialize Bayesian network.
+        let b: CategoricalBN = BIF::read("tests/assets/bif/asia.bif").unwrap().into();
+        // Set an impossible evidence, since `either` is the logical OR of `lung` and `tub`.
+        let e = FxIndexMap::from_iter([
+            ("asia".to_string(), "yes".to_string()),
+            ("tub".to_string(), "yes".to_string()),
+            ("either".to_string(), "no".to_string()),
+        ]);
+
+        // Perform the query.
+        let p = VE::new(&b).query(["dysp"], &e);
+
+        // Assert the minimal conflicting evidence is reported.
+        let e = FxIndexMap::from_iter([
+            ("tub".to_string(), "yes".to_string()),
+            ("either".to_string(), "no".to_string()),
+        ]);
+        assert_eq!(p, Err(InferenceError::ImpossibleEvidence(e)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Query and evidence variables must be disjoint")]
+    fn query_non_disjoint() {
+        // Initialize Bayesian network.
+        let b: CategoricalBN = BIF::read("tests/assets/bif/asia.bif").unwrap().into();
+        // Set the evidence.
+        let e = FxIndexMap::from_iter([("dysp".to_string(), "yes".to_string())]);
+
+        // Perform the query.
+        VE::new(&b).query(["dysp"], &e).ok();
+    }
 }
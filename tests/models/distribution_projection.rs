@@ -36,4 +36,45 @@ mod variable_elimination {
                 .into()
         );
     }
+
+    #[test]
+    fn project_onto_deterministic() {
+        // Initialize P(X) P(Y | X), where P(X = yes) = 0 and Y = X.
+        let p = CategoricalBN::with_parameters([
+            CategoricalCPD::new(("X", ["no", "yes"]), [], ndarray::array![[1., 0.]]),
+            CategoricalCPD::new(
+                ("Y", ["no", "yes"]),
+                [("X", ["no", "yes"])],
+                ndarray::array![[1., 0.], [0., 1.]],
+            ),
+        ]);
+        // Initialize the reversed graph Y -> X.
+        let g = DiGraph::new(["X", "Y"], [("Y", "X")]);
+        let q = CategoricalBN::new(
+            g,
+            [
+                CategoricalCPD::new(("Y", ["no", "yes"]), [], ndarray::array![[0.5, 0.5]]),
+                CategoricalCPD::new(
+                    ("X", ["no", "yes"]),
+                    [("Y", ["no", "yes"])],
+                    ndarray::array![[0.5, 0.5], [0.5, 0.5]],
+                ),
+            ],
+        );
+
+        // Project P onto Q, where P(X | Y = yes) is undefined.
+        let r = VE::new(&p).project_onto(&q);
+
+        // Assert the undefined distribution is uniform, rather than NaN.
+        assert!(r
+            .parameters()
+            .values()
+            .all(|phi| phi.values().iter().all(|x| !x.is_nan())));
+        assert_eq!(
+            r.cpd("X").values(),
+            ndarray::array![[1., 0.5], [0., 0.5]].into_dyn()
+        );
+        // Assert Y is still a constant, while X is not a function of Y.
+        assert!(!r.cpd("X").is_deterministic() && r.cpd("Y").is_deterministic());
+    }
 }
@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod categorical {
+    use causal_hub::prelude::*;
+    use ndarray::prelude::*;
+    use rand::SeedableRng;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+
+    fn dbn() -> CategoricalDBN {
+        // Set the initial network P(A@0) P(B@0 | A@0).
+        let initial = CategoricalBN::with_parameters([
+            CategoricalCPD::new(("A@0", ["no", "yes"]), [], array![[0.5, 0.5]]),
+            CategoricalCPD::new(
+                ("B@0", ["no", "yes"]),
+                [("A@0", ["no", "yes"])],
+                array![[0.9, 0.1], [0.2, 0.8]],
+            ),
+        ]);
+        // Set the transition P(A@t | A@t-1, B@t-1) P(B@t | A@t).
+        let transition = [
+            CategoricalCPD::new(
+                ("A@t", ["no", "yes"]),
+                [("A@t-1", ["no", "yes"]), ("B@t-1", ["no", "yes"])],
+                array![[0.7, 0.3], [0.6, 0.4], [0.4, 0.6], [0.1, 0.9]],
+            ),
+            CategoricalCPD::new(
+                ("B@t", ["no", "yes"]),
+                [("A@t", ["no", "yes"])],
+                array![[0.8, 0.2], [0.3, 0.7]],
+            ),
+        ];
+
+        CategoricalDBN::new(initial, transition)
+    }
+
+    #[test]
+    fn unroll() {
+        // Initialize the DBN.
+        let dbn = dbn();
+
+        // Unroll over a single slice.
+        let b = dbn.unroll(1);
+        // Assert it is the initial network.
+        assert_eq!(&b, dbn.initial());
+
+        // Unroll over three slices.
+        let b = dbn.unroll(3);
+        // Set the true graph.
+        let true_g = DiGraph::new(
+            ["A@0", "A@1", "A@2", "B@0", "B@1", "B@2"],
+            [
+                ("A@0", "B@0"),
+                ("A@0", "A@1"),
+                ("B@0", "A@1"),
+                ("A@1", "B@1"),
+                ("A@1", "A@2"),
+                ("B@1", "A@2"),
+                ("A@2", "B@2"),
+            ],
+        );
+        // Assert intra- and inter-slice edges are wired.
+        assert_eq!(b.graph(), &true_g);
+
+        // Assert the transition CPDs are tiled.
+        for t in 1..3 {
+            // Map the transition labels to the slice labels.
+            let f = |x: &str| match x.strip_suffix("@t-1") {
+                Some(x) => format!("{x}@{}", t - 1),
+                None => x.replace("@t", &format!("@{t}")),
+            };
+            for (x, phi) in dbn.transition() {
+                let psi = &b.parameters()[&f(x)];
+                // Align the axes w.r.t. the transition scope.
+                let axes: Vec<_> = phi
+                    .scope()
+                    .map(|z| psi.states().get_index_of(&f(z)).unwrap())
+                    .collect();
+                assert_eq!(phi.values(), psi.values().clone().permuted_axes(axes));
+            }
+        }
+        // Assert the unrolled network can be sampled.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        let d = b.sample(&mut rng, 10);
+        assert!(d.labels_iter().eq(L!(b.graph())));
+    }
+
+    #[test]
+    #[should_panic(expected = "Transition CPDs targets must match the initial variables")]
+    fn new_should_panic() {
+        // Set the initial network P(A@0).
+        let initial = CategoricalBN::with_parameters([CategoricalCPD::new(
+            ("A@0", ["no", "yes"]),
+            [],
+            array![[0.5, 0.5]],
+        )]);
+        // Set the transition P(B@t).
+        let transition = [CategoricalCPD::new(
+            ("B@t", ["no", "yes"]),
+            [],
+            array![[0.5, 0.5]],
+        )];
+
+        CategoricalDBN::new(initial, transition);
+    }
+
+    #[test]
+    #[should_panic(expected = "Intra-slice graph must be acyclic")]
+    fn new_cyclic_should_panic() {
+        // Set the initial network P(A@0) P(B@0).
+        let initial = CategoricalBN::with_parameters([
+            CategoricalCPD::new(("A@0", ["no", "yes"]), [], array![[0.5, 0.5]]),
+            CategoricalCPD::new(("B@0", ["no", "yes"]), [], array![[0.5, 0.5]]),
+        ]);
+        // Set the cyclic transition P(A@t | B@t) P(B@t | A@t).
+        let transition = [
+            CategoricalCPD::new(
+                ("A@t", ["no", "yes"]),
+                [("B@t", ["no", "yes"])],
+                array![[0.7, 0.3], [0.3, 0.7]],
+            ),
+            CategoricalCPD::new(
+                ("B@t", ["no", "yes"]),
+                [("A@t", ["no", "yes"])],
+                array![[0.9, 0.1], [0.2, 0.8]],
+            ),
+        ];
+
+        CategoricalDBN::new(initial, transition);
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of slices must be strictly positive")]
+    fn unroll_should_panic() {
+        // Unroll over no slices.
+        dbn().unroll(0);
+    }
+}
@@ -226,6 +226,49 @@ mod categorical_factor {
             &array![[[0.25], [0.08]], [[0.05], [0.0]], [[0.15], [0.09]]].into_dyn()
         );
     }
+
+    #[test]
+    fn condition() {
+        // Initialize factor.
+        let phi = CategoricalFactor::new(
+            [
+                ("A", vec!["a1", "a2", "a3"]),
+                ("B", vec!["b1", "b2"]),
+                ("C", vec!["c1", "c2"]),
+            ],
+            array![0.25, 0.35, 0.08, 0.16, 0.05, 0.07, 0., 0., 0.15, 0.21, 0.09, 0.18],
+        );
+        // Initialize evidence, including a variable out of scope.
+        let e = FxIndexMap::from_iter([
+            ("D".to_string(), "d1".to_string()),
+            ("C".to_string(), "c1".to_string()),
+        ]);
+
+        // Condition factor.
+        let out = phi.condition(&e, false);
+        // Assert conditioned variable is dropped from scope.
+        assert!(out.scope().eq(["A", "B"]));
+        // Assert values of factor conditioning.
+        assert_relative_eq!(
+            out.values(),
+            &array![[0.25, 0.08], [0.05, 0.0], [0.15, 0.09]].into_dyn()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn condition_strict() {
+        // Initialize factor.
+        let phi = CategoricalFactor::new(
+            [("A", vec!["a1", "a2", "a3"]), ("B", vec!["b1", "b2"])],
+            array![0.5, 0.8, 0.1, 0., 0.3, 0.9],
+        );
+        // Initialize evidence, including a variable out of scope.
+        let e = FxIndexMap::from_iter([("D".to_string(), "d1".to_string())]);
+
+        // Condition factor.
+        phi.condition(&e, true);
+    }
 }
 
 mod categorical_cpd {
@@ -293,6 +336,117 @@ mod categorical_cpd {
         assert_relative_eq!(out.values(), cpd.values());
     }
 
+    #[test]
+    fn mul_ref() {
+        // Initialize CPDs.
+        let lhs = CategoricalCPD::new(
+            ("Grade", vec!["g0", "g1", "g2"]),
+            [
+                ("Difficulty", vec!["d0", "d1"]),
+                ("Intelligence", vec!["i0", "i1"]),
+            ],
+            array![
+                [0.3, 0.4, 0.3],
+                [0.05, 0.25, 0.7],
+                [0.9, 0.08, 0.02],
+                [0.5, 0.3, 0.2]
+            ],
+        );
+        let rhs = CategoricalCPD::new(
+            ("Intelligence", vec!["i0", "i1"]),
+            Vec::<(&str, Vec<&str>)>::new(),
+            array![[0.7, 0.3]],
+        );
+
+        // Compute potential product.
+        let out = &lhs * &rhs;
+        // Assert scope of potential product.
+        assert!(out.scope().eq(["Difficulty", "Grade", "Intelligence"]));
+        // Assert potential product is not normalized over target.
+        assert_relative_eq!(
+            out.marginalize(["Grade"]).values(),
+            &array![[0.7, 0.3], [0.7, 0.3]].into_dyn()
+        );
+    }
+
+    #[test]
+    fn mul_owned_and_ref() {
+        // Initialize CPDs.
+        let lhs = CategoricalCPD::new(
+            ("Grade", vec!["g0", "g1", "g2"]),
+            [
+                ("Difficulty", vec!["d0", "d1"]),
+                ("Intelligence", vec!["i0", "i1"]),
+            ],
+            array![
+                [0.3, 0.4, 0.3],
+                [0.05, 0.25, 0.7],
+                [0.9, 0.08, 0.02],
+                [0.5, 0.3, 0.2]
+            ],
+        );
+        let rhs = CategoricalCPD::new(
+            ("Intelligence", vec!["i0", "i1"]),
+            Vec::<(&str, Vec<&str>)>::new(),
+            array![[0.7, 0.3]],
+        );
+
+        // Compute the owned product, normalized over the target.
+        let owned = lhs.clone() * rhs.clone();
+        // Compute the product of references, unnormalized.
+        let by_ref = &lhs * &rhs;
+
+        // Assert the scopes are the same.
+        assert!(owned.scope().eq(by_ref.scope()));
+        // Assert the values differ.
+        assert_relative_ne!(owned.values(), by_ref.values());
+
+        // Get the target index.
+        let i = owned.scope().position(|x| x == "Grade").unwrap();
+        // Normalize the product of references over the target.
+        let values = by_ref.values() / &by_ref.values().sum_axis(Axis(i)).insert_axis(Axis(i));
+        // Assert the values are the same up to normalization.
+        assert_relative_eq!(owned.values(), &values);
+    }
+
+    #[test]
+    fn div_ref() {
+        // Initialize CPDs.
+        let lhs = CategoricalCPD::new(
+            ("Grade", vec!["g0", "g1", "g2"]),
+            [
+                ("Difficulty", vec!["d0", "d1"]),
+                ("Intelligence", vec!["i0", "i1"]),
+            ],
+            array![
+                [0.3, 0.4, 0.3],
+                [0.05, 0.25, 0.7],
+                [0.9, 0.08, 0.02],
+                [0.5, 0.3, 0.2]
+            ],
+        );
+        let rhs = CategoricalCPD::new(
+            ("Intelligence", vec!["i0", "i1"]),
+            Vec::<(&str, Vec<&str>)>::new(),
+            array![[1., 0.]],
+        );
+
+        // Compute potential product.
+        let phi = &lhs * &rhs;
+        // Compute potential division.
+        let out = &phi / &rhs.into();
+
+        // Assert zero-valued entries are mapped to zero.
+        assert_relative_eq!(
+            out.values(),
+            &array![
+                [[0.3, 0.], [0.4, 0.], [0.3, 0.]],
+                [[0.05, 0.], [0.25, 0.], [0.7, 0.]]
+            ]
+            .into_dyn()
+        );
+    }
+
     #[test]
     fn normalize() {
         // Initialize CPD.
@@ -358,4 +512,131 @@ mod categorical_cpd {
             &array![[[0.3], [0.4], [0.3]], [[0.05], [0.25], [0.7]]].into_dyn()
         );
     }
+
+    #[test]
+    fn condition() {
+        // Initialize CPD.
+        let cpd = CategoricalCPD::new(
+            ("Grade", vec!["g0", "g1", "g2"]),
+            [
+                ("Difficulty", vec!["d0", "d1"]),
+                ("Intelligence", vec!["i0", "i1"]),
+            ],
+            array![
+                [0.3, 0.4, 0.3],
+                [0.05, 0.25, 0.7],
+                [0.9, 0.08, 0.02],
+                [0.5, 0.3, 0.2]
+            ],
+        );
+        // Initialize evidence.
+        let e = FxIndexMap::from_iter([("Intelligence".to_string(), "i1".to_string())]);
+
+        // Condition CPD.
+        let out = cpd.condition(&e, true);
+        // Assert conditioned variable is dropped from scope.
+        assert!(out.scope().eq(["Difficulty", "Grade"]));
+        // Assert values of CPD conditioning.
+        assert_relative_eq!(
+            out.values(),
+            &array![[0.9, 0.08, 0.02], [0.5, 0.3, 0.2]].into_dyn()
+        );
+    }
+
+    #[test]
+    fn with_temperature() {
+        // Initialize CPD.
+        let cpd = CategoricalCPD::new(
+            ("Grade", vec!["g0", "g1", "g2"]),
+            [("Difficulty", vec!["d0", "d1"])],
+            array![[0.2, 0.8, 0.], [0.25, 0.25, 0.5]],
+        );
+
+        // Assert unit temperature is the identity.
+        assert_relative_eq!(cpd.with_temperature(1.).values(), cpd.values());
+        // Assert low temperature sharpens the distributions.
+        assert_relative_eq!(
+            cpd.with_temperature(0.5).values(),
+            &array![[1. / 17., 16. / 17., 0.], [1. / 6., 1. / 6., 4. / 6.]].into_dyn(),
+            epsilon = 1e-8
+        );
+        // Assert high temperature flattens the distributions over their support.
+        assert_relative_eq!(
+            cpd.with_temperature(1e12).values(),
+            &array![[0.5, 0.5, 0.], [1. / 3., 1. / 3., 1. / 3.]].into_dyn(),
+            epsilon = 1e-8
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_zero_temperature() {
+        // Initialize CPD.
+        let cpd = CategoricalCPD::new(
+            ("Grade", vec!["g0", "g1", "g2"]),
+            [("Difficulty", vec!["d0", "d1"])],
+            array![[0.2, 0.8, 0.], [0.25, 0.25, 0.5]],
+        );
+
+        // Scale CPD with zero temperature.
+        cpd.with_temperature(0.);
+    }
+
+    #[test]
+    fn with_small_temperature() {
+        // Initialize CPD.
+        let cpd = CategoricalCPD::new(
+            ("Grade", vec!["g0", "g1", "g2"]),
+            [("Difficulty", vec!["d0", "d1"])],
+            array![[0.3, 0.3, 0.4], [0.25, 0.5, 0.25]],
+        );
+
+        // Scale CPD with small temperature.
+        let out = cpd.with_temperature(1e-3);
+        // Assert values are finite.
+        assert!(out.values().iter().all(|x| x.is_finite()));
+        // Assert the mass is on the argmax states.
+        assert_relative_eq!(
+            out.values(),
+            &array![[0., 0., 1.], [0., 1., 0.]].into_dyn(),
+            epsilon = 1e-8
+        );
+    }
+
+    #[test]
+    fn is_deterministic() {
+        // Initialize a deterministic CPD, i.e. Y = NOT X.
+        let cpd = CategoricalCPD::new(
+            ("Y", vec!["no", "yes"]),
+            [("X", vec!["no", "yes"])],
+            array![[0., 1.], [1., 0.]],
+        );
+        assert!(cpd.is_deterministic());
+
+        // Initialize a stochastic CPD.
+        let cpd = CategoricalCPD::new(
+            ("Y", vec!["no", "yes"]),
+            [("X", vec!["no", "yes"])],
+            array![[0., 1.], [0.5, 0.5]],
+        );
+        assert!(!cpd.is_deterministic());
+    }
+
+    #[test]
+    fn with_uniform_undefined() {
+        // Initialize the joint P(X, Y), where P(X = yes) = 0 and Y = X.
+        let phi = CategoricalFactor::new(
+            [("X", vec!["no", "yes"]), ("Y", vec!["no", "yes"])],
+            array![[1., 0.], [0., 0.]].into_dyn(),
+        );
+        // Compute P(Y | X), undefined for X = yes.
+        let cpd = CategoricalCPD::from_factor("Y", phi);
+        assert!(cpd.values().iter().any(|x| x.is_nan()));
+
+        // Assert undefined distributions are uniform.
+        assert_relative_eq!(
+            cpd.with_uniform_undefined().values(),
+            &array![[1., 0.5], [0., 0.5]].into_dyn()
+        );
+    }
 }
@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod categorical {
+    use approx::*;
+    use causal_hub::prelude::*;
+
+    #[test]
+    fn from_bn() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+        // Build the factor graph.
+        let f = FactorGraph::from_bn(&b);
+
+        // Assert variables and factors match the network.
+        assert!(f.variables().keys().eq(L!(b.graph())));
+        assert_eq!(f.factors().len(), b.parameters().len());
+        for (phi, psi) in f.factors().iter().zip(b.parameters().values()) {
+            assert!(phi.scope().eq(psi.scope()));
+        }
+    }
+
+    #[test]
+    fn run_lbp() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+        // Run loopy belief propagation.
+        let p = FactorGraph::from_bn(&b).run_lbp(100, 1e-12);
+
+        // Assert the marginals match variable elimination.
+        let estimator = VE::new(&b);
+        for x in L!(b.graph()) {
+            let q = estimator.call([x]).normalize();
+            assert!(p[x].scope().eq([x]));
+            assert_relative_eq!(p[x].values(), q.values(), epsilon = 1e-8);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Tolerance must be strictly positive")]
+    fn run_lbp_non_positive_tolerance() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+        // Run loopy belief propagation.
+        FactorGraph::from_bn(&b).run_lbp(100, 0.);
+    }
+}
@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod categorical {
+    use causal_hub::prelude::*;
+    use rand::SeedableRng;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+
+    const VARIANCE_REDUCTIONS: [VarianceReduction; 3] = [
+        VarianceReduction::MonteCarlo,
+        VarianceReduction::AntitheticVariates,
+        VarianceReduction::Stratified,
+    ];
+
+    #[test]
+    fn sample() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+
+        // Assert plain Monte Carlo is the default.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        let d = ForwardSampling::new(&b).sample(&mut rng, 100);
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        let e = ForwardSampling::new(&b)
+            .with_variance_reduction(VarianceReduction::MonteCarlo)
+            .sample(&mut rng, 100);
+        assert_eq!(d.data(), e.data());
+
+        // For each variance reduction technique ...
+        for v in VARIANCE_REDUCTIONS {
+            // ... draw samples twice under the same seed ...
+            let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+            let d = ForwardSampling::new(&b)
+                .with_variance_reduction(v)
+                .sample(&mut rng, 101);
+            let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+            let e = ForwardSampling::new(&b)
+                .with_variance_reduction(v)
+                .sample(&mut rng, 101);
+            // ... and assert they are reproducible.
+            assert_eq!(d.data().nrows(), 101);
+            assert!(d.labels_iter().eq(L!(b.graph())));
+            assert_eq!(d.data(), e.data());
+        }
+    }
+
+    #[test]
+    fn sample_antithetic_variates() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+
+        // Draw antithetic samples.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        let d = ForwardSampling::new(&b)
+            .with_variance_reduction(VarianceReduction::AntitheticVariates)
+            .sample(&mut rng, 100);
+
+        // Assert each pair has opposite states of the uniform root `smoke`.
+        let smoke = d.data().column(5).to_vec();
+        assert!(smoke.chunks(2).all(|s| s[0] != s[1]));
+    }
+
+    #[test]
+    fn sample_stratified() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+
+        // Draw stratified samples.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        let d = ForwardSampling::new(&b)
+            .with_variance_reduction(VarianceReduction::Stratified)
+            .sample(&mut rng, 1000);
+
+        // Assert the roots frequencies match their probabilities, up to one sample.
+        for (x, p) in [(0, 0.99), (5, 0.5)] {
+            let n = d.data().column(x).iter().filter(|&&x| x == 0).count() as f64;
+            assert!((n - 1000. * p).abs() <= 1.);
+        }
+    }
+
+    #[test]
+    fn sample_deterministic() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+
+        // For each variance reduction technique ...
+        for v in VARIANCE_REDUCTIONS {
+            // ... draw samples ...
+            let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+            let d = ForwardSampling::new(&b)
+                .with_variance_reduction(v)
+                .sample(&mut rng, 1000);
+            // ... and assert `either` is the logical OR of `lung` and `tub`.
+            assert!(d
+                .rows()
+                .all(|row| row[3] == (row[4] == 1 || row[6] == 1) as u8));
+        }
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod categorical {
+    use approx::*;
+    use causal_hub::prelude::*;
+    use ndarray::prelude::*;
+
+    #[test]
+    fn query_independent() {
+        // Set the network P(A) P(B), for which the mean-field is exact.
+        let b = CategoricalBN::with_parameters([
+            CategoricalCPD::new(("A", ["no", "yes"]), [], array![[0.3, 0.7]]),
+            CategoricalCPD::new(("B", ["no", "yes"]), [], array![[0.6, 0.4]]),
+        ]);
+        // Set the evidence.
+        let e = FxIndexMap::from_iter([("A".to_string(), "yes".to_string())]);
+
+        // Approximate the posterior marginals.
+        let (q, elbo) = MeanField::new(&b).query(&e, 100, 1e-8);
+
+        // Assert the evidence is clamped.
+        assert_relative_eq!(q["A"].values(), &array![0., 1.].into_dyn());
+        // Assert the marginals are exact.
+        assert_relative_eq!(q["B"].values(), &array![0.6, 0.4].into_dyn());
+        // Assert the ELBO is the log-evidence.
+        assert_relative_eq!(elbo, 0.7_f64.ln(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn query() {
+        // Set the network P(A) P(B | A) P(C | B) with weak dependencies.
+        let b = CategoricalBN::with_parameters([
+            CategoricalCPD::new(("A", ["no", "yes"]), [], array![[0.6, 0.4]]),
+            CategoricalCPD::new(
+                ("B", ["no", "yes"]),
+                [("A", ["no", "yes"])],
+                array![[0.7, 0.3], [0.6, 0.4]],
+            ),
+            CategoricalCPD::new(
+                ("C", ["no", "yes"]),
+                [("B", ["no", "yes"])],
+                array![[0.8, 0.2], [0.7, 0.3]],
+            ),
+        ]);
+        // Set the evidence.
+        let e = FxIndexMap::from_iter([("C".to_string(), "yes".to_string())]);
+
+        // Approximate the posterior marginals.
+        let (q, elbo) = MeanField::new(&b).query(&e, 100, 1e-8);
+
+        // Assert the marginals are close to the exact ones computed by variable elimination.
+        for x in ["A", "B"] {
+            let p = VE::new(&b).query([x], &e).unwrap();
+            assert!(q[x].scope().eq([x]));
+            assert_relative_eq!(q[x].values(), p.values(), epsilon = 1e-2);
+        }
+        // Assert the ELBO lower bounds the log-evidence, with a small gap.
+        let z = VE::new(&b).call(["C"]).values()[[1]].ln();
+        assert!(elbo <= z);
+        assert_relative_eq!(elbo, z, epsilon = 1e-2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Evidence variable `X` must be in the network")]
+    fn query_unknown_evidence() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+        // Set the evidence.
+        let e = FxIndexMap::from_iter([("X".to_string(), "yes".to_string())]);
+
+        // Approximate the posterior marginals.
+        MeanField::new(&b).query(&e, 100, 1e-8);
+    }
+}
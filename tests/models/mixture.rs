@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod categorical {
+    use approx::*;
+    use causal_hub::prelude::*;
+    use rand::SeedableRng;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+
+    #[test]
+    fn sample() {
+        // Initialize random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+        // Initialize the mixture.
+        let m = CategoricalMixtureBN::new([b.clone(), b], [0.3, 0.7]);
+        // Sample from the mixture.
+        let d = m.sample(&mut rng, 1e3 as usize);
+
+        // Assert sample size and labels.
+        assert_eq!(d.sample_size(), 1e3 as usize);
+        assert!(d.labels_iter().eq(L!(m.components()[0].graph())));
+    }
+
+    #[test]
+    fn log_density() {
+        // Initialize random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+        // Sample from the BN.
+        let d = b.sample(&mut rng, 10);
+        // Initialize the mixture of identical components.
+        let m = CategoricalMixtureBN::new([b.clone(), b.clone()], [0.5, 0.5]);
+
+        // Assert mixture density is equal to component density.
+        for x in d.data().rows() {
+            assert_relative_eq!(m.log_density(x), b.log_density(x), epsilon = 1e-8);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_invalid_weights() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+        // Initialize the mixture with invalid weights.
+        CategoricalMixtureBN::new([b.clone(), b], [0.5, 0.6]);
+    }
+}
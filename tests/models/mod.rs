@@ -1,7 +1,15 @@
+mod adjustment_sets;
 mod bayesian_network;
+mod cross_validation;
 mod distribution_estimation;
 mod distribution_projection;
+mod dynamic_bayesian_network;
 mod factor;
+mod factor_graph;
+mod forward_sampling;
 mod graphical_separation;
 mod kullback_leibler;
+mod mean_field;
+mod mixture;
+mod most_probable_explanation;
 mod parameter_estimation;
@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod categorical {
+    use approx::*;
+    use causal_hub::prelude::*;
+    use itertools::Itertools;
+    use ndarray::prelude::*;
+
+    fn densities(b: &CategoricalBN) -> Vec<(Vec<u8>, f64)> {
+        // Enumerate the joint state space.
+        L!(b.graph())
+            .map(|x| 0..(b.parameters()[x].states()[x].len() as u8))
+            .multi_cartesian_product()
+            // Compute the density of each configuration.
+            .map(|x| {
+                let p = b.log_density(Array1::from_vec(x.clone()).view()).exp();
+                (x, p)
+            })
+            // Sort by descending density.
+            .sorted_by(|(_, p), (_, q)| q.total_cmp(p))
+            .collect()
+    }
+
+    #[test]
+    fn top_k_mpe() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+
+        // Compute the top-5 explanations.
+        let mpe = causal_hub::models::top_k_mpe(&b, &FxIndexMap::default(), 5);
+
+        // Assert the explanations match the brute force enumeration.
+        assert_eq!(mpe.len(), 5);
+        for ((x, p), (y, q)) in mpe.iter().zip(densities(&b)) {
+            // Map the states labels to indices.
+            let x = x
+                .iter()
+                .map(|(x, s)| b.parameters()[x].states()[x].get_index_of(s).unwrap() as u8)
+                .collect_vec();
+            assert_eq!(x, y);
+            assert_relative_eq!(*p, q, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn top_k_mpe_with_evidence() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+        // Set the evidence.
+        let e = FxIndexMap::from_iter([("dysp".to_string(), "yes".to_string())]);
+
+        // Compute the top-3 explanations.
+        let mpe = causal_hub::models::top_k_mpe(&b, &e, 3);
+
+        // Compute the posterior densities by brute force enumeration.
+        let (i, j) = (2, 1);
+        let d = densities(&b)
+            .into_iter()
+            .filter(|(x, _)| x[i] == j)
+            .collect_vec();
+        let z: f64 = d.iter().map(|(_, p)| p).sum();
+
+        // Assert the explanations match, skipping the evidence.
+        assert_eq!(mpe.len(), 3);
+        for ((x, p), (_, q)) in mpe.iter().zip(d) {
+            assert!(!x.contains_key("dysp"));
+            assert_relative_eq!(*p, q / z, epsilon = 1e-12);
+        }
+        // Assert the single MPE is the first one.
+        assert_eq!(causal_hub::models::top_k_mpe(&b, &e, 1)[0], mpe[0]);
+    }
+
+    #[test]
+    fn top_k_mpe_impossible_evidence() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+        // Set an impossible evidence.
+        let e = FxIndexMap::from_iter([
+            ("tub".to_string(), "yes".to_string()),
+            ("either".to_string(), "no".to_string()),
+        ]);
+
+        // Assert there is no explanation.
+        assert!(causal_hub::models::top_k_mpe(&b, &e, 1).is_empty());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Number of explanations must not exceed the number of configurations: 256"
+    )]
+    fn top_k_mpe_too_many() {
+        // Read BN from BIF.
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+
+        // Compute more explanations than configurations.
+        causal_hub::models::top_k_mpe(&b, &FxIndexMap::default(), 257);
+    }
+}
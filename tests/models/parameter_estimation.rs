@@ -372,4 +372,120 @@ mod bayesian_estimation {
             assert_relative_eq!(phi, psi.values());
         }
     }
+
+    #[test]
+    fn from_statistics() {
+        // Read data.
+        let d: CategoricalDataMatrix = CsvReader::from_path("tests/assets/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap()
+            .into();
+        // Read Bayesian network.
+        let b: CategoricalBayesianNetwork = BIF::read("tests/assets/bif/asia.bif").unwrap().into();
+
+        // Compute the sufficient statistics.
+        let s = CategoricalSufficientStatistics::new(&d, b.graph());
+        let par_s = CategoricalSufficientStatistics::par_new(&d, b.graph());
+
+        // Check statistics are consistent with the graph and data.
+        assert_eq!(s.graph(), b.graph());
+        assert_eq!(s.counts(), par_s.counts());
+        assert!(s.counts().iter().all(|n| n.sum() == d.sample_size()));
+
+        // Check unit pseudo counts match the Bayesian estimation.
+        assert_eq!(BE::from_statistics(&s, 1.), BE::call(&d, b.graph()));
+        assert_eq!(ParallelBE::from_statistics(&s, 1.), BE::call(&d, b.graph()));
+        // Check zero pseudo counts match the maximum likelihood estimation.
+        assert_eq!(MLE::from_statistics(&s), MLE::call(&d, b.graph()));
+
+        // Check greater pseudo counts smooth the parameters toward uniform.
+        let c = BE::from_statistics(&s, 1.);
+        let e = BE::from_statistics(&s, 1e3);
+        for (phi, psi) in c.parameters().values().zip(e.parameters().values()) {
+            // Compute the distance from the uniform distribution.
+            let u = 1. / phi.states()[phi.target()].len() as f64;
+            let dist = |x: &ArrayD<f64>| x.iter().map(|x| (x - u).abs()).sum::<f64>();
+            assert!(dist(psi.values()) <= dist(phi.values()));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Pseudo counts must be positive, got 0")]
+    fn from_statistics_should_panic() {
+        // Read data.
+        let d: CategoricalDataMatrix = CsvReader::from_path("tests/assets/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap()
+            .into();
+        // Read Bayesian network.
+        let b: CategoricalBayesianNetwork = BIF::read("tests/assets/bif/asia.bif").unwrap().into();
+
+        // Compute the sufficient statistics.
+        let s = CategoricalSufficientStatistics::new(&d, b.graph());
+        // Fit with invalid pseudo counts.
+        BE::from_statistics(&s, 0.);
+    }
+
+    #[test]
+    fn update() {
+        // Read data.
+        let d: CategoricalDataMatrix = CsvReader::from_path("tests/assets/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap()
+            .into();
+        // Read Bayesian network.
+        let b: CategoricalBayesianNetwork = BIF::read("tests/assets/bif/asia.bif").unwrap().into();
+
+        // Split the data into two batches.
+        let n = d.sample_size() / 3;
+        let split = |r: std::ops::Range<usize>| {
+            CategoricalDataMatrix::with_data_labels(
+                d.data().slice(s![r, ..]).to_owned(),
+                d.states().clone(),
+            )
+        };
+        let (d_0, d_1) = (split(0..n), split(n..d.sample_size()));
+
+        // Compute the sufficient statistics of the first batch, then update them.
+        let mut s = CategoricalSufficientStatistics::new(&d_0, b.graph());
+        s.update(&d_1);
+        let mut par_s = CategoricalSufficientStatistics::par_new(&d_0, b.graph());
+        par_s.par_update(&d_1);
+
+        // Check updated statistics match the ones of the full data.
+        let t = CategoricalSufficientStatistics::new(&d, b.graph());
+        assert_eq!(s.counts(), t.counts());
+        assert_eq!(par_s.counts(), t.counts());
+
+        // Check the re-estimated models match the ones of the full data.
+        assert_eq!(MLE::from_statistics(&s), MLE::call(&d, b.graph()));
+        assert_eq!(BE::from_statistics(&s, 1.), BE::call(&d, b.graph()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Data set and sufficient statistics must have the same states")]
+    fn update_should_panic() {
+        // Read data.
+        let d: CategoricalDataMatrix = CsvReader::from_path("tests/assets/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap()
+            .into();
+        // Read Bayesian network.
+        let b: CategoricalBayesianNetwork = BIF::read("tests/assets/bif/asia.bif").unwrap().into();
+
+        // Compute the sufficient statistics.
+        let mut s = CategoricalSufficientStatistics::new(&d, b.graph());
+
+        // Rename the states of a variable.
+        let mut states = d.states().clone();
+        states["asia"] = FxIndexSet::from_iter(["n".to_string(), "y".to_string()]);
+        let e = CategoricalDataMatrix::with_data_labels(d.data().clone(), states);
+
+        // Update with mismatching states.
+        s.update(&e);
+    }
 }
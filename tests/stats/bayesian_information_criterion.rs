@@ -87,6 +87,68 @@ mod categorical {
             );
         }
     }
+
+    #[test]
+    fn weighted_bayesian_information_criterion() {
+        use ndarray::prelude::*;
+        use rand::SeedableRng;
+        use rand_xoshiro::Xoshiro256PlusPlus;
+
+        // Load the data set from file.
+        let d = CsvReader::from_path("./tests/assets/asia.csv")
+            .expect("Failed to read the data from file")
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+
+        // Initialize the random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        // Draw a weighted bootstrap sample.
+        let (w_d, w) = d.weighted_bootstrap(&mut rng);
+        // Expand the weighted sample by repeating each sample accordingly.
+        let indices: Vec<_> = w
+            .indexed_iter()
+            .flat_map(|(i, &w)| std::iter::repeat(i).take(w))
+            .collect();
+        let e_d = CategoricalDataMatrix::with_data_labels(
+            w_d.data().select(Axis(0), &indices),
+            w_d.labels().clone(),
+        );
+
+        // Build an empty the graph.
+        let g = DiGraph::empty(d.labels_iter());
+
+        // Initialize the weighted and the expanded scoring criteria.
+        let w_s = BIC::new(&w_d).with_weights(w.mapv(|w| w as f64));
+        let e_s = BIC::new(&e_d);
+
+        // Compare the scores for each vertex given the other vertices.
+        for x in V!(g) {
+            let z: Vec<_> = V!(g).filter(|&z| z != x).take(2).collect();
+
+            assert_relative_eq!(
+                DecomposableScoringCriterion::<_, DiGraph>::call(&w_s, x, &z),
+                DecomposableScoringCriterion::<_, DiGraph>::call(&e_s, x, &z),
+                max_relative = 1e-8
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of weights must be equal to the sample size")]
+    fn weighted_bayesian_information_criterion_should_panic() {
+        use ndarray::prelude::*;
+
+        // Load the data set from file.
+        let d = CsvReader::from_path("./tests/assets/asia.csv")
+            .expect("Failed to read the data from file")
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+
+        // Initialize the scoring criterion with too few weights.
+        BIC::new(&d).with_weights(Array1::ones(1));
+    }
 }
 
 #[cfg(test)]
@@ -106,4 +106,28 @@ mod tests {
 
         assert_relative_eq!(true_s, pred_s.into(), max_relative = 1e-8);
     }
+
+    #[test]
+    fn new_with_estimator() {
+        use ndarray_linalg::{EigValsh, UPLO};
+
+        // Construct a data set with more variables than samples.
+        let d = Array2::from_shape_fn((5, 8), |(i, j)| f64::sin((i * 8 + j) as f64).powi(3));
+        let d = GaussianDataMatrix::from_ndarray(d, (0..8).map(|i| i.to_string()));
+
+        // Construct the empirical covariance matrix.
+        let s = CovarianceMatrix::new_with_estimator(&d, CovarianceEstimator::Empirical);
+        // Assert the empirical estimator is the default one.
+        assert_relative_eq!(s.deref(), CovarianceMatrix::from(&d).deref());
+        // Assert the empirical covariance matrix is singular.
+        let lambda = s.eigvalsh(UPLO::Lower).unwrap();
+        assert!(lambda.iter().any(|l| l.abs() < 1e-10));
+
+        // Construct the shrinkage covariance matrix.
+        let s = CovarianceMatrix::new_with_estimator(&d, CovarianceEstimator::LedoitWolf);
+        // Assert the shrinkage covariance matrix is symmetric and positive definite.
+        assert_eq!(s.deref(), s.t());
+        let lambda = s.eigvalsh(UPLO::Lower).unwrap();
+        assert!(lambda.iter().all(|&l| l > 1e-6));
+    }
 }
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use causal_hub::prelude::*;
+    use ndarray::prelude::*;
+
+    #[test]
+    fn call() {
+        // Set true graph.
+        let g = DiGraph::new(
+            [
+                "asia", "bronc", "dysp", "either", "lung", "smoke", "tub", "xray",
+            ],
+            [
+                ("asia", "tub"),
+                ("bronc", "dysp"),
+                ("either", "dysp"),
+                ("either", "xray"),
+                ("lung", "either"),
+                ("smoke", "bronc"),
+                ("smoke", "lung"),
+                ("tub", "either"),
+            ],
+        );
+        // Build the d-separation oracle.
+        let oracle = DSeparationOracle::new(&g);
+
+        // Assert independence statements follow d-separation.
+        assert!(oracle.call(0, 5, &[]));
+        assert!(!oracle.call(6, 4, &[3]));
+        assert!(oracle.call(7, 2, &[3]));
+        assert_eq!(oracle.eval(0, 1, &[]), (0, 0., 1.));
+        assert_eq!(oracle.eval(0, 6, &[]), (0, 0., 0.));
+
+        // Set true CPDAG.
+        let mut m = Array2::<i8>::zeros((8, 8));
+        for (x, y) in [(0, 6), (1, 5), (4, 5)] {
+            (m[[x, y]], m[[y, x]]) = (2, 2);
+        }
+        for (x, y) in [(1, 2), (3, 2), (3, 7), (4, 3), (6, 3)] {
+            (m[[x, y]], m[[y, x]]) = (1, -1);
+        }
+        let true_g = PDGraph::from_marked_matrix(L!(g), m);
+
+        // Perform discovery.
+        let pcs = PCStable::new(&oracle);
+        let pred_g = pcs.call().meek_procedure_until_3();
+        let par_pred_g = pcs.par_call().meek_procedure_until_3();
+
+        // Assert the true CPDAG is recovered exactly.
+        assert_eq!(pred_g, true_g);
+        assert_eq!(par_pred_g, true_g);
+    }
+}
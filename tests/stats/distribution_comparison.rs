@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod categorical {
+    use approx::*;
+    use causal_hub::prelude::*;
+    use ndarray::prelude::*;
+    use polars::prelude::*;
+    use rand::SeedableRng;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+
+    #[test]
+    fn call() {
+        // Load data set.
+        let d = CsvReader::from_path("./tests/assets/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+
+        // Compare the data set with itself.
+        let r = compare_distributions(&d, &d);
+
+        // Assert the marginals are identical.
+        assert!(r.marginals().keys().eq(d.labels_iter()));
+        for m in r.marginals().values() {
+            assert_relative_eq!(m.total_variation(), 0.);
+            assert_relative_eq!(m.statistic(), 0.);
+            assert_eq!(m.dof(), 1);
+            assert_relative_eq!(m.p_value(), 1., epsilon = 1e-8);
+        }
+        assert_relative_eq!(r.max_total_variation(), 0.);
+    }
+
+    #[test]
+    fn call_with_samples() {
+        // Load data set and model.
+        let d = CsvReader::from_path("./tests/assets/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+        let b: CategoricalBN = BIF::read("./tests/assets/bif/asia.bif").unwrap().into();
+
+        // Draw samples from the model.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        let s = ForwardSampling::new(&b).sample(&mut rng, d.sample_size());
+
+        // Compare the data set with the samples.
+        let r = compare_distributions(&d, &s);
+
+        // Assert the report is consistent.
+        assert_eq!(r.marginals().len(), 8);
+        for m in r.marginals().values() {
+            assert!(m.total_variation() >= 0. && m.total_variation() <= r.max_total_variation());
+            assert!(m.statistic() >= 0.);
+            assert!((0. ..=1.).contains(&m.p_value()));
+        }
+        // Assert the marginals agree.
+        assert!(r.max_total_variation() < 0.05);
+    }
+
+    #[test]
+    fn call_with_shared_columns() {
+        // Load data set.
+        let d = CsvReader::from_path("./tests/assets/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+        // Select a subset of the columns.
+        let states = d
+            .states()
+            .iter()
+            .filter(|(x, _)| ["bronc", "smoke"].contains(&x.as_str()))
+            .map(|(x, s)| (x.clone(), s.clone()))
+            .collect();
+        let e = CategoricalDataMatrix::with_data_labels(d.data().select(Axis(1), &[1, 5]), states);
+
+        // Compare the data set with the subset.
+        let r = compare_distributions(&d, &e);
+
+        // Assert only the shared columns are compared.
+        assert!(r.marginals().keys().eq(["bronc", "smoke"]));
+        assert_relative_eq!(r.max_total_variation(), 0.);
+    }
+
+    #[test]
+    fn display() {
+        // Load data set.
+        let d = CsvReader::from_path("./tests/assets/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+
+        // Compare the data set with itself.
+        let r = compare_distributions(&d, &d).to_string();
+
+        // Assert there is a header and a row for each variable.
+        let lines: Vec<_> = r.lines().collect();
+        assert_eq!(lines.len(), 9);
+        assert!(lines[0].starts_with("variable"));
+        assert!(lines[1].starts_with("asia"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Variable `asia` must have the same states in both data sets")]
+    fn call_should_panic() {
+        // Load data set.
+        let d = CsvReader::from_path("./tests/assets/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+        // Rename the states of a variable.
+        let mut states = d.states().clone();
+        states["asia"] = FxIndexSet::from_iter(["n".to_string(), "y".to_string()]);
+        let e = CategoricalDataMatrix::with_data_labels(d.data().clone(), states);
+
+        // Compare data sets with mismatching states.
+        compare_distributions(&d, &e);
+    }
+}
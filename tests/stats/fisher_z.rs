@@ -3,6 +3,7 @@ mod tests {
     use approx::*;
     use causal_hub::prelude::*;
     use polars::prelude::*;
+    use std::panic::AssertUnwindSafe;
 
     #[test]
     fn eval() {
@@ -67,4 +68,57 @@ mod tests {
             assert_eq!(pred_call, true_pval > 0.05);
         }
     }
+
+    #[test]
+    fn new_with_covariance() {
+        // Load the data set from file.
+        let d = CsvReader::from_path("./tests/assets/ecoli70.csv")
+            .expect("Failed to read the data from file")
+            .finish()
+            .unwrap();
+        let d = GaussianDataMatrix::from(d);
+
+        // Initialize conditional independence tests.
+        let test = FisherZ::new(&d);
+        let empirical = FisherZ::new_with_covariance(&d, CovarianceEstimator::Empirical);
+        let shrinkage = FisherZ::new_with_covariance(&d, CovarianceEstimator::LedoitWolf);
+
+        // Assert the empirical estimator is the default one.
+        assert_eq!(test.eval(0, 1, &[2, 3]), empirical.eval(0, 1, &[2, 3]));
+        // Assert the shrinkage estimator yields a valid p-value.
+        let (_, stat, pval) = shrinkage.eval(0, 1, &[2, 3]);
+        assert!(stat.is_finite());
+        assert!((0. ..=1.).contains(&pval));
+    }
+
+    #[test]
+    fn with_significance_level() {
+        // Load the data set from file.
+        let d = CsvReader::from_path("./tests/assets/ecoli70.csv")
+            .expect("Failed to read the data from file")
+            .finish()
+            .unwrap();
+        let d = GaussianDataMatrix::from(d);
+
+        // Initialize conditional independence test.
+        let test = FisherZ::from(&d);
+
+        // Assert default significance level.
+        assert_eq!(test.significance_level(), 0.05);
+        // Assert significance level is set.
+        let test = test.with_significance_level(0.01);
+        assert_eq!(test.significance_level(), 0.01);
+
+        // Assert invalid significance levels are rejected.
+        for alpha in [0., 1., f64::NAN, -0.1, 1.5] {
+            let err = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                FisherZ::from(&d).with_significance_level(alpha)
+            }))
+            .expect_err("Invalid significance level must panic");
+            assert_eq!(
+                err.downcast_ref::<String>().unwrap(),
+                &format!("Significance level must be in the (0, 1) interval, got {alpha}")
+            );
+        }
+    }
 }
@@ -4,8 +4,12 @@ mod chi_squared;
 mod confusion_matrix;
 mod correlation_matrix;
 mod covariance_matrix;
+mod d_separation_oracle;
+mod distribution_comparison;
 mod fisher_z;
 mod log_likelihood;
 mod partial_correlation;
+mod partial_correlation_matrix;
+mod penalized_score;
 mod precision_matrix;
 mod students_t;
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use approx::*;
+    use causal_hub::prelude::*;
+    use ndarray::prelude::*;
+    use polars::prelude::*;
+
+    #[test]
+    fn new() {
+        // Construct a new partial correlation matrix.
+        PartialCorrelationMatrix::new(array![[1., -0.5], [-0.5, 1.]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_should_panic_non_square() {
+        // Construct a new partial correlation matrix.
+        PartialCorrelationMatrix::new(array![[1., -0.5]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_should_panic_non_symmetric() {
+        // Construct a new partial correlation matrix.
+        PartialCorrelationMatrix::new(array![[1., -0.5], [0.5, 1.]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_should_panic_out_of_range() {
+        // Construct a new partial correlation matrix.
+        PartialCorrelationMatrix::new(array![[1., -1.5], [-1.5, 1.]]);
+    }
+
+    #[test]
+    fn from_precision() {
+        // Construct a new precision matrix.
+        let omega = PrecisionMatrix::new(array![[4., -2.], [-2., 4.]]);
+        // Compute the partial correlation matrix.
+        let rho: Array2<f64> = PartialCorrelationMatrix::from(omega).into();
+
+        assert_relative_eq!(rho, array![[1., 0.5], [0.5, 1.]]);
+    }
+
+    #[test]
+    fn from_covariance() {
+        // Load data set.
+        let d = CsvReader::from_path("./tests/assets/ecoli70.csv")
+            .expect("Failed to read the data from file")
+            .finish()
+            .unwrap();
+        let d = GaussianDataMatrix::from(d);
+
+        // Compute covariance matrix.
+        let sigma = CovarianceMatrix::from(&d);
+        // Initialize partial correlation.
+        let pcorr = PartialCorrelation::from(sigma.clone());
+        // Compute the partial correlation matrix.
+        let rho = PartialCorrelationMatrix::from(sigma);
+
+        // Assert each value is the partial correlation given all the other variables.
+        let n = rho.nrows();
+        for x in 0..n {
+            for y in (x + 1)..n {
+                // Get the other variables.
+                let z: Vec<_> = (0..n).filter(|&z| z != x && z != y).collect();
+
+                assert_relative_eq!(rho[[x, y]], pcorr.call(x, y, &z), max_relative = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn from_covariance_with_shrinkage() {
+        // Cast ndarray to datamatrix with a constant column.
+        let d = GaussianDataMatrix::from_ndarray(
+            array![[1., 0., 2.], [2., 0., 1.], [4., 0., 5.], [3., 0., 3.]],
+            ["X", "Y", "Z"],
+        );
+
+        // Compute the shrunk covariance matrix.
+        let sigma = CovarianceMatrix::new_with_estimator(&d, CovarianceEstimator::LedoitWolf);
+        // Compute the partial correlation matrix.
+        let rho = PartialCorrelationMatrix::from(sigma);
+
+        // Assert the constant column is uncorrelated.
+        assert!(rho.iter().all(|r| r.is_finite()));
+        assert_abs_diff_eq!(rho[[0, 1]], 0., epsilon = 1e-12);
+        assert_abs_diff_eq!(rho[[1, 2]], 0., epsilon = 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "Covariance matrix must be positive definite")]
+    fn from_covariance_should_panic() {
+        // Cast ndarray to datamatrix with a constant column.
+        let d = GaussianDataMatrix::from_ndarray(
+            array![[1., 0., 2.], [2., 0., 1.], [4., 0., 5.], [3., 0., 3.]],
+            ["X", "Y", "Z"],
+        );
+
+        // Compute the partial correlation matrix of a singular covariance matrix.
+        PartialCorrelationMatrix::from(CovarianceMatrix::from(&d));
+    }
+}
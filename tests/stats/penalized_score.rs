@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod categorical {
+    use approx::*;
+    use causal_hub::prelude::*;
+    use polars::prelude::*;
+
+    #[test]
+    fn call() {
+        // Load data set.
+        let d = CsvReader::from_path("./tests/assets/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+        // Initialize the penalized scoring criterion.
+        let prior = FxIndexMap::from_iter([((0, 1), -2.), ((5, 1), 3.)]);
+        let s = PenalizedScore::new(BIC::new(&d))
+            .with_edge_penalty(1.5)
+            .with_structure_prior(prior);
+
+        // Assert the local score is penalized w.r.t. the base score.
+        let f = |s: &BIC<CategoricalDataMatrix>, x, z: &[usize]| {
+            DecomposableScoringCriterion::<_, DiGraph>::call(s, x, z)
+        };
+        let g = |x, z: &[usize]| DecomposableScoringCriterion::<_, DiGraph>::call(&s, x, z);
+        assert_relative_eq!(g(1, &[]), f(s.base(), 1, &[]));
+        assert_relative_eq!(g(1, &[0, 5, 7]), f(s.base(), 1, &[0, 5, 7]) - 4.5 - 2. + 3.);
+        assert_relative_eq!(g(5, &[1]), f(s.base(), 5, &[1]) - 1.5);
+
+        // Assert the delta is local.
+        assert_relative_eq!(
+            DecomposableScoringCriterion::<_, DiGraph>::delta_add(&s, 1, &[0], 5),
+            DecomposableScoringCriterion::<_, DiGraph>::delta_add(s.base(), 1, &[0], 5) - 1.5 + 3.
+        );
+
+        // Assert the base hint is dropped, since an edge is rewarded.
+        assert_eq!(
+            DecomposableScoringCriterion::<_, DiGraph>::max_in_degree_hint(&s),
+            None
+        );
+    }
+
+    #[test]
+    fn with_edge_penalty() {
+        // Load data set.
+        let d = CsvReader::from_path("./tests/assets/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+        // Initialize empty prior knowledge.
+        let k = FR::new(d.labels_iter(), [], []);
+
+        // Initialize scoring criteria.
+        let s = BIC::new(&d);
+        let t = PenalizedScore::new(BIC::new(&d)).with_edge_penalty(1e12);
+
+        // Assert a large penalty yields a sparser graph.
+        let g: DiGraph = HC::new(&s).call(&d, &k);
+        let h: DiGraph = HC::new(&t).call(&d, &k);
+        assert!(g.size() > 0);
+        assert_eq!(h.size(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Edge penalty must be non-negative and finite")]
+    fn with_negative_edge_penalty() {
+        // Load data set.
+        let d = CsvReader::from_path("./tests/assets/asia.csv")
+            .unwrap()
+            .finish()
+            .unwrap();
+        let d = CategoricalDataMatrix::from(d);
+
+        // Set a negative penalty.
+        PenalizedScore::new(BIC::new(&d)).with_edge_penalty(-1.);
+    }
+}
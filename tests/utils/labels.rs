@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod tests {
+    use causal_hub::{types::FxIndexSet, utils::*};
+
+    fn labels(x: &[&str]) -> FxIndexSet<String> {
+        x.iter().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn intersection() {
+        let (a, b) = (labels(&["A", "B", "C"]), labels(&["D", "C", "B"]));
+
+        // Assert intersection is sorted and symmetric.
+        assert!(labels_intersection(&a, &b).iter().eq(["B", "C"]));
+        assert_eq!(labels_intersection(&a, &b), labels_intersection(&b, &a));
+        // Assert intersection with empty set is empty.
+        assert!(labels_intersection(&a, &labels(&[])).is_empty());
+    }
+
+    #[test]
+    fn union() {
+        let (a, b) = (labels(&["A", "B", "C"]), labels(&["D", "C", "B"]));
+
+        // Assert union is sorted and symmetric.
+        assert!(labels_union(&a, &b).iter().eq(["A", "B", "C", "D"]));
+        assert_eq!(labels_union(&a, &b), labels_union(&b, &a));
+        // Assert union with empty set is the set itself.
+        assert_eq!(labels_union(&a, &labels(&[])), a);
+    }
+
+    #[test]
+    fn reindex_into() {
+        let (a, b) = (labels(&["A", "B", "C"]), labels(&["C", "D", "A"]));
+
+        // Assert positions are mapped.
+        assert_eq!(labels_reindex_into(&a, &b), [Some(2), None, Some(0)]);
+        assert_eq!(labels_reindex_into(&b, &a), [Some(2), None, Some(0)]);
+        // Assert identity mapping.
+        assert_eq!(labels_reindex_into(&a, &a), [Some(0), Some(1), Some(2)]);
+    }
+}
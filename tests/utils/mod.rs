@@ -1 +1,2 @@
+mod labels;
 mod union_find;